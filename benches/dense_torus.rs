@@ -0,0 +1,54 @@
+//! Compares the sparse and dense engines on a 1024x1024 torus.
+//!
+//! Run with `cargo bench --bench dense_torus`.
+
+use std::time::{Duration, Instant};
+
+use gameoflife::{DenseGrid, Engine, GameOfLife, Topology};
+
+const SIZE: i32 = 1024;
+const GENERATIONS: u64 = 20;
+
+fn soup() -> GameOfLife {
+    let mut game = GameOfLife::with_topology(Topology::Torus {
+        width: SIZE,
+        height: SIZE,
+    });
+    game.set_history_limit(0);
+    let mut seed = 0x9e37_79b9_7f4a_7c15_u64;
+    game.set_cells(
+        (0..SIZE)
+            .flat_map(|y| (0..SIZE).map(move |x| (x, y)))
+            .filter(|_| {
+                seed ^= seed << 13;
+                seed ^= seed >> 7;
+                seed ^= seed << 17;
+                seed.is_multiple_of(3)
+            }),
+    );
+    game
+}
+
+fn time(engine: &mut dyn Engine) -> Duration {
+    let start = Instant::now();
+    engine.advance(GENERATIONS);
+    start.elapsed()
+}
+
+fn main() {
+    let mut sparse = soup();
+    let mut dense = DenseGrid::from_game(&sparse).unwrap();
+
+    let sparse_time = time(&mut sparse);
+    let dense_time = time(&mut dense);
+    assert_eq!(dense.live_cells(), Engine::live_cells(&sparse));
+
+    let per_generation = |elapsed: Duration| elapsed / GENERATIONS as u32;
+    println!("{SIZE}x{SIZE} torus, {GENERATIONS} generations");
+    println!("sparse: {:?}/generation", per_generation(sparse_time));
+    println!("dense:  {:?}/generation", per_generation(dense_time));
+    println!(
+        "speedup: {:.1}x",
+        sparse_time.as_secs_f64() / dense_time.as_secs_f64()
+    );
+}
@@ -0,0 +1,58 @@
+/*
+ * C API for the gameoflife crate, built with `cargo build --release --features ffi`.
+ * Link against the cdylib (libgameoflife.so, libgameoflife.dylib, or gameoflife.dll).
+ * Documentation for each function is in src/ffi.rs.
+ */
+#ifndef GAMEOFLIFE_H
+#define GAMEOFLIFE_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* An opaque board. Not thread-safe. */
+typedef struct GolBoard GolBoard;
+
+/* Creates an empty infinite board under Conway's rules. */
+GolBoard *gol_new(void);
+
+/* Creates an empty width by height torus, or returns NULL if either size is not positive. */
+GolBoard *gol_new_torus(int32_t width, int32_t height);
+
+/* Releases a board. NULL is ignored. */
+void gol_free(GolBoard *game);
+
+/* Brings the cell at (x, y) to life if alive is true, or kills it otherwise. */
+void gol_set(GolBoard *game, int32_t x, int32_t y, bool alive);
+
+/* Returns whether the cell at (x, y) is alive. */
+bool gol_get(const GolBoard *game, int32_t x, int32_t y);
+
+/* Advances the board by the given number of generations. */
+void gol_tick(GolBoard *game, uint32_t generations);
+
+/* Returns the number of generations computed so far. */
+uint64_t gol_generation(const GolBoard *game);
+
+/* Returns the number of live cells. */
+uint64_t gol_population(const GolBoard *game);
+
+/* Parses a rulestring such as "B36/S23" and evolves under it from now on. Returns false and
+ * leaves the rule unchanged if it is invalid. */
+bool gol_set_rule(GolBoard *game, const char *rule);
+
+/* Copies the width by height viewport starting at (origin_x, origin_y) into buffer, one byte per
+ * cell (1 alive, 0 dead), row by row. Returns the number of bytes written, or -1 without writing
+ * anything if a size is negative or len is smaller than width * height. */
+int64_t gol_cells_at(const GolBoard *game, int32_t origin_x, int32_t origin_y, int32_t width,
+                     int32_t height, uint8_t *buffer, size_t len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* GAMEOFLIFE_H */
@@ -0,0 +1,166 @@
+//! Headless benchmark mode: times a backend over many generations on a real workload.
+//!
+//! Memory is measured by wrapping the system allocator, so the figures cover every heap
+//! allocation the process makes rather than an estimate from each engine's internals.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use gameoflife::{Backend, GameOfLife};
+
+#[global_allocator]
+static ALLOCATOR: TrackingAllocator = TrackingAllocator::new();
+
+/// The system allocator, counting live bytes and their high-water mark.
+struct TrackingAllocator {
+    current: AtomicUsize,
+    peak: AtomicUsize,
+}
+
+impl TrackingAllocator {
+    const fn new() -> Self {
+        Self {
+            current: AtomicUsize::new(0),
+            peak: AtomicUsize::new(0),
+        }
+    }
+
+    fn added(&self, size: usize) {
+        let current = self.current.fetch_add(size, Ordering::Relaxed) + size;
+        self.peak.fetch_max(current, Ordering::Relaxed);
+    }
+
+    fn removed(&self, size: usize) {
+        self.current.fetch_sub(size, Ordering::Relaxed);
+    }
+
+    /// Restarts the high-water mark from the bytes allocated right now.
+    fn reset_peak(&self) {
+        self.peak
+            .store(self.current.load(Ordering::Relaxed), Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for TrackingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        // SAFETY: forwarded unchanged to the system allocator under the caller's guarantees.
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            self.added(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        // SAFETY: as for `alloc`.
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            self.added(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        // SAFETY: `ptr` came from `alloc` or `realloc` above with this layout.
+        unsafe { System.dealloc(ptr, layout) };
+        self.removed(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        // SAFETY: as for `dealloc`; the caller guarantees `new_size` is valid for `layout`.
+        let new = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new.is_null() {
+            self.removed(layout.size());
+            self.added(new_size);
+        }
+        new
+    }
+}
+
+/// Measurements from one benchmark run.
+pub struct Report {
+    backend: Backend,
+    generations: u64,
+    elapsed: Duration,
+    initial_population: usize,
+    final_population: usize,
+    peak_population: usize,
+    /// Heap bytes held before the engine was built.
+    baseline_memory: usize,
+    /// Most heap bytes held at once while building and running the engine.
+    peak_memory: usize,
+}
+
+/// Copies `game` into `backend` and advances it one generation at a time, so the peak population
+/// is exact.
+pub fn run(game: &GameOfLife, backend: Backend, generations: u64) -> Result<Report, String> {
+    let baseline_memory = ALLOCATOR.current.load(Ordering::Relaxed);
+    ALLOCATOR.reset_peak();
+
+    let start = Instant::now();
+    let mut engine = backend.build(game).ok_or_else(|| {
+        format!("the {backend:?} backend cannot run this board's topology or rule")
+    })?;
+    let initial_population = engine.population();
+    let mut peak_population = initial_population;
+    for _ in 0..generations {
+        engine.advance(1);
+        peak_population = peak_population.max(engine.population());
+    }
+    let elapsed = start.elapsed();
+
+    Ok(Report {
+        backend,
+        generations,
+        elapsed,
+        initial_population,
+        final_population: engine.population(),
+        peak_population,
+        baseline_memory,
+        peak_memory: ALLOCATOR.peak.load(Ordering::Relaxed),
+    })
+}
+
+/// Formats a byte count in binary units.
+fn bytes(count: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = count as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{count} B")
+    } else {
+        format!("{value:.1} {}", UNITS[unit])
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let seconds = self.elapsed.as_secs_f64();
+        let rate = if seconds > 0.0 {
+            self.generations as f64 / seconds
+        } else {
+            f64::INFINITY
+        };
+        writeln!(f, "backend:     {:?}", self.backend)?;
+        writeln!(f, "generations: {}", self.generations)?;
+        writeln!(f, "elapsed:     {seconds:.3} s")?;
+        writeln!(f, "rate:        {rate:.1} generations/s")?;
+        writeln!(
+            f,
+            "population:  {} initial, {} final, {} peak",
+            self.initial_population, self.final_population, self.peak_population
+        )?;
+        writeln!(
+            f,
+            "memory:      {} peak heap ({} above baseline)",
+            bytes(self.peak_memory),
+            bytes(self.peak_memory.saturating_sub(self.baseline_memory))
+        )
+    }
+}
@@ -0,0 +1,65 @@
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+/// Inclusive extents of a set of cells.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BoundingBox {
+    pub min_x: i32,
+    pub min_y: i32,
+    pub max_x: i32,
+    pub max_y: i32,
+}
+
+impl BoundingBox {
+    /// Returns the smallest box containing every cell, or `None` when there are none.
+    pub fn of(cells: impl IntoIterator<Item = (i32, i32)>) -> Option<Self> {
+        let mut cells = cells.into_iter();
+        let (x, y) = cells.next()?;
+        Some(cells.fold(
+            Self {
+                min_x: x,
+                min_y: y,
+                max_x: x,
+                max_y: y,
+            },
+            |bounds, (x, y)| Self {
+                min_x: bounds.min_x.min(x),
+                min_y: bounds.min_y.min(y),
+                max_x: bounds.max_x.max(x),
+                max_y: bounds.max_y.max(y),
+            },
+        ))
+    }
+
+    /// Builds a box from inclusive ranges, accepting either endpoint order.
+    pub fn from_ranges(
+        x_range: &core::ops::RangeInclusive<i32>,
+        y_range: &core::ops::RangeInclusive<i32>,
+    ) -> Self {
+        let (x0, x1) = (*x_range.start(), *x_range.end());
+        let (y0, y1) = (*y_range.start(), *y_range.end());
+        Self {
+            min_x: x0.min(x1),
+            min_y: y0.min(y1),
+            max_x: x0.max(x1),
+            max_y: y0.max(y1),
+        }
+    }
+
+    /// Number of columns covered.
+    pub fn width(&self) -> u32 {
+        self.max_x.abs_diff(self.min_x) + 1
+    }
+
+    /// Number of rows covered.
+    pub fn height(&self) -> u32 {
+        self.max_y.abs_diff(self.min_y) + 1
+    }
+
+    /// Returns whether `(x, y)` lies inside the box, edges included.
+    pub fn contains(&self, x: i32, y: i32) -> bool {
+        (self.min_x..=self.max_x).contains(&x) && (self.min_y..=self.max_y).contains(&y)
+    }
+}
@@ -0,0 +1,112 @@
+use crate::{Backend, CoordLimit, Engine, GameOfLife, Pattern, Rule, Topology, patterns};
+use alloc::boxed::Box;
+
+/// Collects a board's configuration before creating it, as an alternative to calling setters on a
+/// fresh `GameOfLife`. Start one with `GameOfLife::builder()`.
+#[derive(Debug, Clone, Default)]
+pub struct GameOfLifeBuilder {
+    rule: Option<Rule>,
+    topology: Topology,
+    backend: Backend,
+    history_limit: Option<usize>,
+    coord_limit: Option<CoordLimit>,
+    pattern: Option<Pattern>,
+    soup: Option<(u32, u32, f64)>,
+    seed: u64,
+}
+
+impl GameOfLifeBuilder {
+    /// Starts from the defaults of `GameOfLife::new()`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the rule. Without one, the initial pattern's rule is used, or else Conway's.
+    pub fn with_rule(mut self, rule: Rule) -> Self {
+        self.rule = Some(rule);
+        self
+    }
+
+    pub fn with_topology(mut self, topology: Topology) -> Self {
+        self.topology = topology;
+        self
+    }
+
+    /// Sets the engine `build_engine` creates. `build` always creates a sparse `GameOfLife`.
+    pub fn with_backend(mut self, backend: Backend) -> Self {
+        self.backend = backend;
+        self
+    }
+
+    /// Sets how many undo snapshots the board keeps, as `GameOfLife::set_history_limit` does.
+    pub fn with_history_limit(mut self, limit: usize) -> Self {
+        self.history_limit = Some(limit);
+        self
+    }
+
+    pub fn with_coord_limit(mut self, limit: CoordLimit) -> Self {
+        self.coord_limit = Some(limit);
+        self
+    }
+
+    /// Places `pattern`'s cells at their own coordinates, over any soup.
+    pub fn with_pattern(mut self, pattern: Pattern) -> Self {
+        self.pattern = Some(pattern);
+        self
+    }
+
+    /// Fills the region from `(0, 0)` to `(width - 1, height - 1)` with a random soup, as
+    /// `GameOfLife::random_soup` does, using the builder's seed.
+    pub fn with_soup(mut self, width: u32, height: u32, density: f64) -> Self {
+        self.soup = Some((width, height, density));
+        self
+    }
+
+    /// Sets the seed for the random soup. Defaults to 0, so builds are reproducible.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Creates the board at generation 0, with no undo history.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a bounded or toroidal topology has a non-positive width or height.
+    pub fn build(&self) -> GameOfLife {
+        let mut game = GameOfLife::with_topology(self.topology);
+        let rule = self
+            .rule
+            .or_else(|| self.pattern.as_ref().and_then(Pattern::rule));
+        game.set_rule(rule.unwrap_or_default());
+        if let Some(limit) = self.history_limit {
+            game.set_history_limit(limit);
+        }
+        game.set_coord_limit(self.coord_limit);
+
+        let soup = self
+            .soup
+            .map(|(width, height, density)| patterns::soup(width, height, density, self.seed));
+        for pattern in soup.iter().chain(&self.pattern) {
+            for &(x, y) in pattern.cells() {
+                if self.coord_limit.is_none_or(|limit| limit.contains(x, y)) {
+                    game.place(x, y);
+                }
+            }
+        }
+        game
+    }
+
+    /// Creates the board with the chosen backend, or returns `None` if that backend cannot
+    /// represent the topology or rule.
+    pub fn build_engine(&self) -> Option<Box<dyn Engine>> {
+        self.backend.build(&self.build())
+    }
+}
+
+impl GameOfLife {
+    /// Returns a builder for configuring a new board.
+    pub fn builder() -> GameOfLifeBuilder {
+        GameOfLifeBuilder::new()
+    }
+}
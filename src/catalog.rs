@@ -0,0 +1,272 @@
+//! A searchable collection of patterns with metadata, for pattern pickers and lookups.
+//!
+//! `builtin()` lists every shape in `patterns` along with who found it and how it behaves.
+//! User-loaded patterns can be added to a `Catalog` alongside them.
+
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+
+use crate::{BoundingBox, Pattern, patterns};
+
+/// How a catalogued pattern behaves when run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Kind {
+    /// Never changes.
+    StillLife,
+    /// Returns to its starting state in place.
+    Oscillator,
+    /// Returns to its starting state shifted across the board.
+    Spaceship,
+    /// A small pattern that takes a long time to settle.
+    Methuselah,
+    /// Periodically emits spaceships.
+    Gun,
+    /// Anything else, including patterns whose behavior is unknown.
+    Other,
+}
+
+/// How far a spaceship travels per period, such as `c/4` for the glider.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Speed {
+    /// Columns moved per period.
+    pub dx: i32,
+    /// Rows moved per period.
+    pub dy: i32,
+    /// Generations per period.
+    pub period: u64,
+}
+
+impl fmt::Display for Speed {
+    /// Writes the speed in the usual `c` notation, reduced, followed by the direction of travel.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let distance = u64::from(self.dx.unsigned_abs().max(self.dy.unsigned_abs()));
+        let divisor = gcd(distance, self.period).max(1);
+        let (distance, period) = (distance / divisor, self.period / divisor);
+        match distance {
+            1 => write!(f, "c")?,
+            distance => write!(f, "{distance}c")?,
+        }
+        if period != 1 {
+            write!(f, "/{period}")?;
+        }
+        let direction = if self.dx == 0 || self.dy == 0 {
+            "orthogonal"
+        } else if self.dx.abs() == self.dy.abs() {
+            "diagonal"
+        } else {
+            "oblique"
+        };
+        write!(f, " {direction}")
+    }
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+/// A pattern with the metadata a catalog can search on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Entry {
+    pattern: Pattern,
+    kind: Kind,
+    discoverer: Option<String>,
+    period: Option<u64>,
+    displacement: (i32, i32),
+}
+
+impl Entry {
+    /// Wraps `pattern` with no metadata beyond its name and bounding box.
+    pub fn new(pattern: Pattern) -> Self {
+        Self {
+            pattern,
+            kind: Kind::Other,
+            discoverer: None,
+            period: None,
+            displacement: (0, 0),
+        }
+    }
+
+    /// Sets what kind of pattern this is.
+    pub fn with_kind(mut self, kind: Kind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Sets who discovered the pattern.
+    pub fn with_discoverer(mut self, discoverer: impl Into<String>) -> Self {
+        self.discoverer = Some(discoverer.into());
+        self
+    }
+
+    /// Sets the number of generations before the pattern repeats; 1 for still lifes.
+    pub fn with_period(mut self, period: u64) -> Self {
+        self.period = Some(period);
+        self
+    }
+
+    /// Sets how far the pattern moves each period, for spaceships.
+    pub fn with_displacement(mut self, dx: i32, dy: i32) -> Self {
+        self.displacement = (dx, dy);
+        self
+    }
+
+    /// Returns the pattern's cells.
+    pub fn pattern(&self) -> &Pattern {
+        &self.pattern
+    }
+
+    /// Returns the pattern's name, or an empty string if it has none.
+    pub fn name(&self) -> &str {
+        self.pattern.name().unwrap_or_default()
+    }
+
+    pub fn kind(&self) -> Kind {
+        self.kind
+    }
+
+    pub fn discoverer(&self) -> Option<&str> {
+        self.discoverer.as_deref()
+    }
+
+    /// Returns the period, if the pattern repeats and it is known.
+    pub fn period(&self) -> Option<u64> {
+        self.period
+    }
+
+    /// Returns the speed of a pattern that moves, or `None` for one that stays in place.
+    pub fn speed(&self) -> Option<Speed> {
+        let (dx, dy) = self.displacement;
+        let period = self.period?;
+        ((dx, dy) != (0, 0)).then_some(Speed { dx, dy, period })
+    }
+
+    /// Returns the extents of the pattern's cells, or `None` if it is empty.
+    pub fn bounding_box(&self) -> Option<BoundingBox> {
+        self.pattern.bounding_box()
+    }
+
+    fn matches(&self, query: &str) -> bool {
+        self.name().to_lowercase().contains(query)
+    }
+}
+
+/// A list of catalogued patterns that can be searched by name, kind, or period.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Catalog {
+    entries: Vec<Entry>,
+}
+
+impl Catalog {
+    /// Creates an empty catalog.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends an entry, such as a pattern loaded from a file.
+    pub fn add(&mut self, entry: Entry) {
+        self.entries.push(entry);
+    }
+
+    /// Returns the entries in the order they were added.
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    /// Returns the entry named `query`, ignoring case, or else the first whose name contains it.
+    pub fn find(&self, query: &str) -> Option<&Entry> {
+        let query = query.trim().to_lowercase();
+        self.entries
+            .iter()
+            .find(|entry| entry.name().to_lowercase() == query)
+            .or_else(|| self.entries.iter().find(|entry| entry.matches(&query)))
+    }
+
+    /// Iterates the entries whose names contain `query`, ignoring case.
+    pub fn search(&self, query: &str) -> impl Iterator<Item = &Entry> {
+        let query = query.trim().to_lowercase();
+        self.entries
+            .iter()
+            .filter(move |entry| entry.matches(&query))
+    }
+
+    /// Iterates the entries of the given kind.
+    pub fn of_kind(&self, kind: Kind) -> impl Iterator<Item = &Entry> {
+        self.entries.iter().filter(move |entry| entry.kind == kind)
+    }
+
+    /// Iterates the entries that repeat with exactly `period` generations.
+    pub fn with_period(&self, period: u64) -> impl Iterator<Item = &Entry> {
+        self.entries
+            .iter()
+            .filter(move |entry| entry.period == Some(period))
+    }
+}
+
+impl FromIterator<Entry> for Catalog {
+    fn from_iter<I: IntoIterator<Item = Entry>>(entries: I) -> Self {
+        Self {
+            entries: entries.into_iter().collect(),
+        }
+    }
+}
+
+impl Extend<Entry> for Catalog {
+    fn extend<I: IntoIterator<Item = Entry>>(&mut self, entries: I) {
+        self.entries.extend(entries);
+    }
+}
+
+/// Returns a catalog of every built-in pattern in `patterns`, excluding random soups.
+pub fn builtin() -> Catalog {
+    let still_life = |pattern| {
+        Entry::new(pattern)
+            .with_kind(Kind::StillLife)
+            .with_period(1)
+    };
+    let oscillator = |pattern, period| {
+        Entry::new(pattern)
+            .with_kind(Kind::Oscillator)
+            .with_period(period)
+    };
+    let methuselah = |pattern| Entry::new(pattern).with_kind(Kind::Methuselah);
+    [
+        still_life(patterns::block()).with_discoverer("John Conway"),
+        still_life(patterns::beehive()).with_discoverer("John Conway"),
+        still_life(patterns::loaf()),
+        still_life(patterns::boat()),
+        still_life(patterns::tub()),
+        oscillator(patterns::blinker(), 2).with_discoverer("John Conway"),
+        oscillator(patterns::toad(), 2).with_discoverer("Simon Norton"),
+        oscillator(patterns::beacon(), 2).with_discoverer("John Conway"),
+        oscillator(patterns::pulsar(), 3).with_discoverer("John Conway"),
+        oscillator(patterns::pentadecathlon(), 15).with_discoverer("John Conway"),
+        Entry::new(patterns::glider())
+            .with_kind(Kind::Spaceship)
+            .with_period(4)
+            .with_displacement(1, 1)
+            .with_discoverer("Richard K. Guy"),
+        Entry::new(patterns::lwss())
+            .with_kind(Kind::Spaceship)
+            .with_period(4)
+            .with_displacement(-2, 0)
+            .with_discoverer("John Conway"),
+        methuselah(patterns::r_pentomino()).with_discoverer("John Conway"),
+        methuselah(patterns::diehard()),
+        methuselah(patterns::acorn()).with_discoverer("Charles Corderman"),
+        Entry::new(patterns::gosper_glider_gun())
+            .with_kind(Kind::Gun)
+            .with_period(30)
+            .with_discoverer("Bill Gosper"),
+    ]
+    .into_iter()
+    .collect()
+}
+
+/// Looks up a built-in pattern by name, as `Catalog::find` does on `builtin()`.
+pub fn find(query: &str) -> Option<Entry> {
+    builtin().find(query).cloned()
+}
@@ -0,0 +1,82 @@
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+use crate::GameOfLife;
+use alloc::vec::Vec;
+
+/// A reusable row-major viewport buffer, filled in place by `GameOfLife::fill_cells_at`.
+///
+/// Each byte is `1` for a live cell and `0` otherwise, matching `cells_at`. Frontends can keep one
+/// buffer across frames so that redrawing does not allocate.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CellBuffer {
+    cells: Vec<u8>,
+    width: i32,
+    height: i32,
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+impl CellBuffer {
+    /// Creates an empty buffer; the first fill sizes it.
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen(constructor))]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Width of the most recent fill.
+    pub fn width(&self) -> i32 {
+        self.width
+    }
+
+    /// Height of the most recent fill.
+    pub fn height(&self) -> i32 {
+        self.height
+    }
+
+    /// Number of cells held, `width * height`.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Returns whether the buffer holds no cells.
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Address of the first cell in wasm linear memory.
+    ///
+    /// JS can view the cells without copying via `new Uint8Array(memory.buffer, ptr, len)`. The view
+    /// is invalidated by the next fill and by any growth of wasm memory, so rebuild it each frame.
+    pub fn ptr(&self) -> *const u8 {
+        self.cells.as_ptr()
+    }
+}
+
+impl CellBuffer {
+    /// Returns the cells of the most recent fill.
+    pub fn as_slice(&self) -> &[u8] {
+        &self.cells
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+impl GameOfLife {
+    /// Writes the same bytes as `cells_at` into `buffer`, reusing its allocation.
+    pub fn fill_cells_at(
+        &self,
+        buffer: &mut CellBuffer,
+        width: i32,
+        height: i32,
+        origin_x: i32,
+        origin_y: i32,
+    ) {
+        assert!(
+            width >= 0 && height >= 0,
+            "width and height must be non-negative"
+        );
+        buffer.width = width;
+        buffer.height = height;
+        self.scan_cells_into(&mut buffer.cells, width, height, origin_x, origin_y);
+    }
+}
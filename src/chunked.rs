@@ -0,0 +1,305 @@
+use alloc::{boxed::Box, vec::Vec};
+use hashbrown::{HashMap, HashSet};
+
+use crate::{CoordLimit, Engine, GameOfLife, Rule, Topology};
+
+/// Side length of a chunk, one `u64` row of bits.
+const SIZE: i32 = 64;
+
+/// Chunk coordinates covering the `i32` plane exactly, since `i32::MIN` is a multiple of 64.
+const MIN_CHUNK: i32 = i32::MIN / SIZE;
+const MAX_CHUNK: i32 = i32::MAX / SIZE;
+
+/// A 64 by 64 tile: bit `x % 64` of `rows[y % 64]` holds cell `(x, y)`.
+type Chunk = Box<[u64; SIZE as usize]>;
+
+/// The infinite plane stored as 64 by 64 bitmap chunks, keyed by chunk coordinate.
+///
+/// Only chunks holding live cells are kept, so a pattern spread over a huge area costs about 512
+/// bytes per occupied chunk rather than a hash entry per cell. Each generation counts neighbors
+/// with bit-sliced adders over whole rows, 64 cells at a time, visiting occupied chunks and the
+/// neighbors their edge cells can reach.
+///
+/// Like `HashLife`, it simulates the infinite plane under a single two-state rule; rule regions of
+/// a `GameOfLife` are ignored by `from_game`, but its coordinate limit is kept.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChunkedGrid {
+    rule: Rule,
+    chunks: HashMap<(i32, i32), Chunk>,
+    generation: u64,
+    limit: Option<CoordLimit>,
+}
+
+impl ChunkedGrid {
+    /// Creates an empty plane evolving under `rule`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rule` births cells with zero live neighbors (B0), which would fill the infinite
+    /// plane, is a Generations rule, since each cell is a single bit, or counts a neighborhood other
+    /// than the 8-cell Moore one.
+    pub fn new(rule: Rule) -> Self {
+        assert!(
+            !rule.next_state(false, 0),
+            "ChunkedGrid cannot simulate B0 rules on the infinite plane"
+        );
+        assert_eq!(
+            rule.states(),
+            2,
+            "ChunkedGrid cannot simulate Generations rules"
+        );
+        assert!(
+            rule.is_moore(),
+            "ChunkedGrid only simulates rules on the 8-cell Moore neighborhood"
+        );
+        Self {
+            rule,
+            chunks: HashMap::new(),
+            generation: 0,
+            limit: None,
+        }
+    }
+
+    /// Copies the live cells, rule, coordinate limit, and generation of `game`, or returns `None`
+    /// on a bounded or toroidal board or under a rule `new` rejects.
+    pub fn from_game(game: &GameOfLife) -> Option<Self> {
+        let rule = game.rule();
+        if game.topology() != Topology::Infinite
+            || rule.next_state(false, 0)
+            || rule.states() > 2
+            || !rule.is_moore()
+        {
+            return None;
+        }
+        let mut grid = Self::new(rule);
+        grid.limit = game.coord_limit();
+        for (x, y) in game.iter_live() {
+            grid.write(x, y, true);
+        }
+        grid.generation = game.generation();
+        Some(grid)
+    }
+
+    /// Builds a `GameOfLife` with the same live cells, rule, coordinate limit, and generation.
+    pub fn to_game(&self) -> GameOfLife {
+        let mut game = GameOfLife::new();
+        game.set_rule(self.rule);
+        game.set_coord_limit(self.limit);
+        for (x, y) in self.live_cells() {
+            game.place(x, y);
+        }
+        game.set_generation(self.generation);
+        game
+    }
+
+    /// Returns the rule the plane evolves under.
+    pub fn rule(&self) -> Rule {
+        self.rule
+    }
+
+    /// Returns the number of chunks currently allocated.
+    pub fn chunk_count(&self) -> usize {
+        self.chunks.len()
+    }
+
+    /// Advances one generation.
+    pub fn step(&mut self) {
+        let births: Vec<bool> = (0..=8).map(|n| self.rule.next_state(false, n)).collect();
+        let survivals: Vec<bool> = (0..=8).map(|n| self.rule.next_state(true, n)).collect();
+
+        let mut next = HashMap::with_capacity(self.chunks.len());
+        for key in self.candidates() {
+            let mut chunk: Chunk = Box::new([0; SIZE as usize]);
+            for (row, out) in chunk.iter_mut().enumerate() {
+                *out = self.next_row(key, row as i32, &births, &survivals);
+            }
+            self.clip(key, &mut chunk);
+            if chunk.iter().any(|&row| row != 0) {
+                next.insert(key, chunk);
+            }
+        }
+        self.chunks = next;
+        self.generation += 1;
+    }
+
+    /// Clears the cells of chunk `(cx, cy)` outside the coordinate limit.
+    fn clip(&self, (cx, cy): (i32, i32), chunk: &mut Chunk) {
+        let Some(limit) = self.limit else {
+            return;
+        };
+        let extent = i64::from(limit.extent());
+        let (left, top) = (i64::from(cx) * 64, i64::from(cy) * 64);
+        let first = (-extent - left).clamp(0, 64);
+        let end = (extent + 1 - left).clamp(0, 64);
+        let columns = if first < end {
+            u64::MAX >> (64 - (end - first)) << first
+        } else {
+            0
+        };
+        for (row, word) in chunk.iter_mut().enumerate() {
+            if (top + row as i64).abs() > extent {
+                *word = 0;
+            } else {
+                *word &= columns;
+            }
+        }
+    }
+
+    /// Occupied chunks plus the neighboring chunks their edge cells touch.
+    fn candidates(&self) -> HashSet<(i32, i32)> {
+        let mut candidates = HashSet::with_capacity(self.chunks.len() * 2);
+        for (&(cx, cy), chunk) in &self.chunks {
+            candidates.insert((cx, cy));
+            for dy in -1..=1 {
+                for dx in -1..=1 {
+                    if (dx, dy) == (0, 0) || !reaches(chunk, dx, dy) {
+                        continue;
+                    }
+                    let (nx, ny) = (cx + dx, cy + dy);
+                    if (MIN_CHUNK..=MAX_CHUNK).contains(&nx)
+                        && (MIN_CHUNK..=MAX_CHUNK).contains(&ny)
+                    {
+                        candidates.insert((nx, ny));
+                    }
+                }
+            }
+        }
+        candidates
+    }
+
+    /// Row `row` of chunk `(cx, cy)`, reaching into the chunks above and below for rows -1 and 64.
+    fn word(&self, (cx, cy): (i32, i32), row: i32) -> u64 {
+        let (cy, row) = match row {
+            -1 => (cy.wrapping_sub(1), SIZE - 1),
+            SIZE => (cy.wrapping_add(1), 0),
+            row => (cy, row),
+        };
+        self.chunks
+            .get(&(cx, cy))
+            .map_or(0, |chunk| chunk[row as usize])
+    }
+
+    /// Computes row `row` of chunk `key` in the next generation.
+    fn next_row(&self, key: (i32, i32), row: i32, births: &[bool], survivals: &[bool]) -> u64 {
+        let (cx, cy) = key;
+        let mut planes = [0u64; 8];
+        let mut plane = 0;
+        for dy in -1..=1 {
+            let west = self.word((cx.wrapping_sub(1), cy), row + dy);
+            let center = self.word(key, row + dy);
+            let east = self.word((cx.wrapping_add(1), cy), row + dy);
+            // Bit x of each plane holds the neighbor at x - 1, x, or x + 1 in this row.
+            planes[plane] = center << 1 | west >> 63;
+            planes[plane + 1] = center >> 1 | east << 63;
+            plane += 2;
+            if dy != 0 {
+                planes[plane] = center;
+                plane += 1;
+            }
+        }
+
+        // Bit-sliced binary counter: bit planes s0..s3 hold each cell's neighbor count.
+        let (mut s0, mut s1, mut s2, mut s3) = (0u64, 0u64, 0u64, 0u64);
+        for p in planes {
+            let c0 = s0 & p;
+            s0 ^= p;
+            let c1 = s1 & c0;
+            s1 ^= c0;
+            let c2 = s2 & c1;
+            s2 ^= c1;
+            s3 |= c2;
+        }
+
+        let alive = self.word(key, row);
+        let mut result = 0;
+        for count in 0..=8usize {
+            let bit = |plane: u64, b: usize| if count >> b & 1 == 1 { plane } else { !plane };
+            let matches = bit(s0, 0) & bit(s1, 1) & bit(s2, 2) & bit(s3, 3);
+            let born = if births[count] { !alive } else { 0 };
+            let kept = if survivals[count] { alive } else { 0 };
+            result |= matches & (born | kept);
+        }
+        result
+    }
+
+    fn write(&mut self, x: i32, y: i32, alive: bool) {
+        let key = (x.div_euclid(SIZE), y.div_euclid(SIZE));
+        let (row, mask) = (y.rem_euclid(SIZE) as usize, 1 << x.rem_euclid(SIZE));
+        if alive {
+            self.chunks
+                .entry(key)
+                .or_insert_with(|| Box::new([0; SIZE as usize]))[row] |= mask;
+        } else if let Some(chunk) = self.chunks.get_mut(&key) {
+            chunk[row] &= !mask;
+            if chunk.iter().all(|&row| row == 0) {
+                self.chunks.remove(&key);
+            }
+        }
+    }
+}
+
+/// Returns whether `chunk` has live cells on the edge facing its neighbor at `(dx, dy)`.
+fn reaches(chunk: &Chunk, dx: i32, dy: i32) -> bool {
+    let rows = match dy {
+        -1 => &chunk[..1],
+        1 => &chunk[SIZE as usize - 1..],
+        _ => &chunk[..],
+    };
+    let mask = match dx {
+        -1 => 1,
+        1 => 1 << 63,
+        _ => u64::MAX,
+    };
+    rows.iter().any(|&row| row & mask != 0)
+}
+
+impl Engine for ChunkedGrid {
+    fn get(&self, x: i32, y: i32) -> bool {
+        let key = (x.div_euclid(SIZE), y.div_euclid(SIZE));
+        self.chunks
+            .get(&key)
+            .is_some_and(|chunk| chunk[y.rem_euclid(SIZE) as usize] >> x.rem_euclid(SIZE) & 1 == 1)
+    }
+
+    fn set(&mut self, x: i32, y: i32) {
+        self.write(x, y, true);
+    }
+
+    fn unset(&mut self, x: i32, y: i32) {
+        self.write(x, y, false);
+    }
+
+    fn advance(&mut self, generations: u64) {
+        for _ in 0..generations {
+            self.step();
+        }
+    }
+
+    fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    fn population(&self) -> usize {
+        self.chunks
+            .values()
+            .flat_map(|chunk| chunk.iter())
+            .map(|row| row.count_ones() as usize)
+            .sum()
+    }
+
+    fn live_cells(&self) -> Vec<(i32, i32)> {
+        let mut cells = Vec::with_capacity(self.population());
+        for (&(cx, cy), chunk) in &self.chunks {
+            for (row, &word) in chunk.iter().enumerate() {
+                let mut bits = word;
+                while bits != 0 {
+                    let x = cx * SIZE + bits.trailing_zeros() as i32;
+                    cells.push((x, cy * SIZE + row as i32));
+                    bits &= bits - 1;
+                }
+            }
+        }
+        cells.sort_unstable();
+        cells
+    }
+}
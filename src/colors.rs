@@ -0,0 +1,153 @@
+use alloc::vec::Vec;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+use hashbrown::HashSet;
+
+use crate::GameOfLife;
+
+/// Multi-color variants of Life, in which every live cell carries one of several colors.
+///
+/// Colors never affect which cells are born or die, so a colored board evolves exactly like an
+/// uncolored one. A newborn cell takes the color most common among its live neighbors; under
+/// QuadLife, a cell born to three neighbors of three different colors takes the fourth. Any other
+/// tie goes to the lowest tied color. Survivors keep their color.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ColorMode {
+    /// No colors; every live cell has color 0.
+    #[default]
+    Mono,
+    /// Two colors, 0 and 1.
+    Immigration,
+    /// Four colors, 0 to 3.
+    QuadLife,
+}
+
+impl ColorMode {
+    /// Returns how many colors cells can have.
+    pub fn colors(self) -> u8 {
+        match self {
+            ColorMode::Mono => 1,
+            ColorMode::Immigration => 2,
+            ColorMode::QuadLife => 4,
+        }
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+impl GameOfLife {
+    /// Returns the color variant the board runs.
+    pub fn color_mode(&self) -> ColorMode {
+        self.color_mode
+    }
+
+    /// Switches color variant. Cells whose color the new mode lacks take color 0; switching to
+    /// `ColorMode::Mono` discards every color.
+    pub fn set_color_mode(&mut self, mode: ColorMode) {
+        self.invalidate();
+        self.color_mode = mode;
+        self.colors.truncate(usize::from(mode.colors()) - 1);
+    }
+
+    /// Brings the cell at `(x, y)` to life with `color`, or recolors it if it is already alive.
+    /// Colors beyond the board's color mode are clamped to its last color. Cells outside a bounded
+    /// board are ignored.
+    pub fn set_colored(&mut self, x: i32, y: i32, color: u8) {
+        let Some(cell) = self.topology.normalize(x, y) else {
+            return;
+        };
+        let color = color.min(self.color_mode.colors() - 1);
+        if self.state.contains(&cell) && self.color_of(cell) == color {
+            return;
+        }
+        self.snapshot();
+        self.place(cell.0, cell.1);
+        self.set_color_of(cell, color);
+    }
+
+    /// Serializes a viewport like `cells_at`, except that live cells hold one more than their
+    /// color: `0` is dead, `1` is color 0, `2` is color 1, and so on.
+    pub fn colors_at(&self, width: i32, height: i32, origin_x: i32, origin_y: i32) -> Vec<u8> {
+        let mut cells = self.cells_at(width, height, origin_x, origin_y);
+        if self.colors.is_empty() {
+            return cells;
+        }
+        for (index, cell) in cells.iter_mut().enumerate() {
+            if *cell == 1 {
+                let index = index as i32;
+                let (x, y) = (origin_x + index % width, origin_y + index / width);
+                *cell += self.color(x, y).unwrap_or(0);
+            }
+        }
+        cells
+    }
+}
+
+impl GameOfLife {
+    /// Returns the color of the live cell at `(x, y)`, or `None` if it is not alive.
+    pub fn color(&self, x: i32, y: i32) -> Option<u8> {
+        let cell = self.topology.normalize(x, y)?;
+        self.state.contains(&cell).then(|| self.color_of(cell))
+    }
+
+    /// Color recorded for a normalized cell, assumed alive.
+    pub(crate) fn color_of(&self, cell: (i32, i32)) -> u8 {
+        self.colors
+            .iter()
+            .position(|layer| layer.contains(&cell))
+            .map_or(0, |index| index as u8 + 1)
+    }
+
+    /// Records the color of a normalized cell, replacing any earlier one.
+    pub(crate) fn set_color_of(&mut self, cell: (i32, i32), color: u8) {
+        for layer in &mut self.colors {
+            layer.remove(&cell);
+        }
+        if color > 0 {
+            let index = usize::from(color) - 1;
+            if self.colors.len() <= index {
+                self.colors.resize_with(index + 1, HashSet::new);
+            }
+            self.colors[index].insert(cell);
+        }
+    }
+
+    /// Returns the color a cell born at the normalized `cell` takes from its live neighbors.
+    pub(crate) fn birth_color(&self, cell: (i32, i32)) -> u8 {
+        let mut counts = [0u8; 4];
+        for neighbor in self.get_neighbors(cell.0, cell.1) {
+            if self.state.contains(&neighbor) {
+                counts[usize::from(self.color_of(neighbor))] += 1;
+            }
+        }
+        if self.color_mode == ColorMode::QuadLife
+            && counts.iter().sum::<u8>() == 3
+            && counts.iter().all(|&count| count <= 1)
+        {
+            return counts.iter().position(|&count| count == 0).unwrap_or(0) as u8;
+        }
+        let most = counts.iter().copied().max().unwrap_or(0);
+        counts.iter().position(|&count| count == most).unwrap_or(0) as u8
+    }
+
+    /// Forgets the colors of `removed` cells and records those of `born` ones.
+    pub(crate) fn recolor(&mut self, removed: &[(i32, i32)], born: Vec<((i32, i32), u8)>) {
+        if self.color_mode == ColorMode::Mono {
+            return;
+        }
+        for layer in &mut self.colors {
+            for cell in removed {
+                layer.remove(cell);
+            }
+        }
+        for (cell, color) in born {
+            self.set_color_of(cell, color);
+        }
+        while self.colors.last().is_some_and(HashSet::is_empty) {
+            self.colors.pop();
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use crate::GameOfLife;
+
+/// A repeating sequence of boards found by `GameOfLife::detect_cycle`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cycle {
+    /// Generations (counted from the board that was checked) before the cycle is entered.
+    pub offset: u64,
+    /// Generations between repeats: 1 for a still life, 2 for a blinker, and so on.
+    pub period: u64,
+}
+
+impl Cycle {
+    /// Returns whether the board has settled into a still life (including an empty board).
+    pub fn is_still_life(&self) -> bool {
+        self.period == 1
+    }
+}
+
+impl GameOfLife {
+    /// Looks for a still life or oscillator within `max_steps` generations of the current board.
+    ///
+    /// Uses Brent's algorithm, so only two extra boards are kept in memory regardless of how many
+    /// generations are simulated, and boards are compared exactly rather than by hash. Patterns
+    /// that repeat at a different position, such as spaceships, are not reported. The board itself
+    /// is left untouched.
+    pub fn detect_cycle(&self, max_steps: u64) -> Option<Cycle> {
+        let mut start = self.clone();
+        start.set_history_limit(0);
+
+        let mut power = 1;
+        let mut period = 1;
+        let mut tortoise = start.clone();
+        let mut hare = start.clone();
+        hare.step();
+        let mut steps = 1;
+        while tortoise != hare {
+            if steps >= max_steps {
+                return None;
+            }
+            if power == period {
+                tortoise = hare.clone();
+                power *= 2;
+                period = 0;
+            }
+            hare.step();
+            period += 1;
+            steps += 1;
+        }
+
+        let mut tortoise = start.clone();
+        let mut hare = start;
+        for _ in 0..period {
+            hare.step();
+        }
+        let mut offset = 0;
+        while tortoise != hare {
+            tortoise.step();
+            hare.step();
+            offset += 1;
+        }
+
+        Some(Cycle { offset, period })
+    }
+}
@@ -0,0 +1,282 @@
+use crate::{CoordLimit, Engine, GameOfLife, Rule, Topology};
+use alloc::{vec, vec::Vec};
+
+/// A bounded or toroidal board stored as packed bit rows, ticked 64 cells at a time.
+///
+/// Bit `x % 64` of word `x / 64` in row `y` holds cell `(x, y)`. Each generation counts neighbors
+/// with bit-sliced adders over whole words, which beats hashing every live cell once a fixed-size
+/// board is more than sparsely populated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DenseGrid {
+    topology: Topology,
+    rule: Rule,
+    width: usize,
+    height: usize,
+    words_per_row: usize,
+    rows: Vec<u64>,
+    generation: u64,
+    limit: Option<CoordLimit>,
+}
+
+impl DenseGrid {
+    /// Creates an empty grid for a bounded or toroidal topology.
+    ///
+    /// # Panics
+    ///
+    /// Panics on `Topology::Infinite`, which has no fixed size to allocate, on Generations rules,
+    /// since each cell is a single bit, and on rules counting a neighborhood other than the 8-cell
+    /// Moore one.
+    pub fn new(topology: Topology, rule: Rule) -> Self {
+        assert_eq!(
+            rule.states(),
+            2,
+            "DenseGrid cannot simulate Generations rules"
+        );
+        assert!(
+            rule.is_moore(),
+            "DenseGrid only simulates rules on the 8-cell Moore neighborhood"
+        );
+        let (width, height) = topology
+            .size()
+            .expect("DenseGrid needs a bounded or toroidal topology");
+        assert!(
+            width > 0 && height > 0,
+            "bounded and toroidal boards need a positive width and height"
+        );
+        let (width, height) = (width as usize, height as usize);
+        let words_per_row = width.div_ceil(64);
+        Self {
+            topology,
+            rule,
+            width,
+            height,
+            words_per_row,
+            rows: vec![0; words_per_row * height],
+            generation: 0,
+            limit: None,
+        }
+    }
+
+    /// Copies the live cells, rule, coordinate limit, and generation of `game`, or returns `None`
+    /// on an infinite board or under a rule `new` rejects.
+    pub fn from_game(game: &GameOfLife) -> Option<Self> {
+        game.topology().size()?;
+        if game.rule().states() > 2 || !game.rule().is_moore() {
+            return None;
+        }
+        let mut grid = Self::new(game.topology(), game.rule());
+        grid.limit = game.coord_limit();
+        for (x, y) in game.iter_live() {
+            grid.set(x, y);
+        }
+        grid.generation = game.generation();
+        Some(grid)
+    }
+
+    /// Builds a `GameOfLife` with the same topology, rule, coordinate limit, live cells, and
+    /// generation.
+    pub fn to_game(&self) -> GameOfLife {
+        let mut game = GameOfLife::with_topology(self.topology);
+        game.set_rule(self.rule);
+        game.set_coord_limit(self.limit);
+        for (x, y) in self.live_cells() {
+            game.place(x, y);
+        }
+        game.set_generation(self.generation);
+        game
+    }
+
+    /// Returns the grid's topology.
+    pub fn topology(&self) -> Topology {
+        self.topology
+    }
+
+    fn wraps(&self) -> bool {
+        matches!(self.topology, Topology::Torus { .. })
+    }
+
+    fn row(&self, y: usize) -> &[u64] {
+        &self.rows[y * self.words_per_row..(y + 1) * self.words_per_row]
+    }
+
+    /// Clears the unused high bits of a row's last word.
+    fn trim(&self, row: &mut [u64]) {
+        let used = self.width % 64;
+        if used != 0 {
+            row[self.words_per_row - 1] &= (1 << used) - 1;
+        }
+    }
+
+    /// Clears the cells of row `y` outside the coordinate limit.
+    fn clip(&self, y: usize, row: &mut [u64]) {
+        let Some(limit) = self.limit else {
+            return;
+        };
+        let extent = limit.extent() as usize;
+        if y > extent {
+            row.fill(0);
+            return;
+        }
+        for (i, word) in row.iter_mut().enumerate() {
+            let start = i * 64;
+            if start > extent {
+                *word = 0;
+            } else if extent - start < 63 {
+                *word &= (1 << (extent - start + 1)) - 1;
+            }
+        }
+    }
+
+    /// Row whose bit `x` holds cell `x - 1` of `row`.
+    fn shifted_from_west(&self, row: &[u64]) -> Vec<u64> {
+        let mut out: Vec<u64> = (0..row.len())
+            .map(|i| row[i] << 1 | if i > 0 { row[i - 1] >> 63 } else { 0 })
+            .collect();
+        if self.wraps() {
+            let last = self.width - 1;
+            out[0] |= row[last / 64] >> (last % 64) & 1;
+        }
+        self.trim(&mut out);
+        out
+    }
+
+    /// Row whose bit `x` holds cell `x + 1` of `row`.
+    fn shifted_from_east(&self, row: &[u64]) -> Vec<u64> {
+        let mut out: Vec<u64> = (0..row.len())
+            .map(|i| row[i] >> 1 | row.get(i + 1).map_or(0, |next| next << 63))
+            .collect();
+        if self.wraps() {
+            let last = self.width - 1;
+            out[last / 64] |= (row[0] & 1) << (last % 64);
+        }
+        out
+    }
+
+    /// Returns the row at `y + offset`, wrapping on a torus and empty past a bounded edge.
+    fn neighbor_row(&self, y: usize, offset: isize) -> Vec<u64> {
+        let target = y as isize + offset;
+        let target = if self.wraps() {
+            Some(target.rem_euclid(self.height as isize) as usize)
+        } else {
+            usize::try_from(target).ok().filter(|&t| t < self.height)
+        };
+        target.map_or_else(|| vec![0; self.words_per_row], |t| self.row(t).to_vec())
+    }
+
+    /// Advances one generation.
+    pub fn step(&mut self) {
+        let births: Vec<bool> = (0..=8).map(|n| self.rule.next_state(false, n)).collect();
+        let survivals: Vec<bool> = (0..=8).map(|n| self.rule.next_state(true, n)).collect();
+
+        let mut next = Vec::with_capacity(self.rows.len());
+        for y in 0..self.height {
+            let above = self.neighbor_row(y, -1);
+            let below = self.neighbor_row(y, 1);
+            let current = self.row(y);
+            let planes = [
+                self.shifted_from_west(&above),
+                above.clone(),
+                self.shifted_from_east(&above),
+                self.shifted_from_west(current),
+                self.shifted_from_east(current),
+                self.shifted_from_west(&below),
+                below.clone(),
+                self.shifted_from_east(&below),
+            ];
+
+            let mut row = vec![0; self.words_per_row];
+            for (word, out) in row.iter_mut().enumerate() {
+                // Bit-sliced binary counter: bit planes s0..s3 hold each cell's neighbor count.
+                let (mut s0, mut s1, mut s2, mut s3) = (0u64, 0u64, 0u64, 0u64);
+                for plane in &planes {
+                    let p = plane[word];
+                    let c0 = s0 & p;
+                    s0 ^= p;
+                    let c1 = s1 & c0;
+                    s1 ^= c0;
+                    let c2 = s2 & c1;
+                    s2 ^= c1;
+                    s3 |= c2;
+                }
+
+                let alive = current[word];
+                let mut result = 0;
+                for count in 0..=8usize {
+                    let bit =
+                        |plane: u64, b: usize| if count >> b & 1 == 1 { plane } else { !plane };
+                    let matches = bit(s0, 0) & bit(s1, 1) & bit(s2, 2) & bit(s3, 3);
+                    let born = if births[count] { !alive } else { 0 };
+                    let kept = if survivals[count] { alive } else { 0 };
+                    result |= matches & (born | kept);
+                }
+                *out = result;
+            }
+            self.trim(&mut row);
+            self.clip(y, &mut row);
+            next.extend(row);
+        }
+        self.rows = next;
+        self.generation += 1;
+    }
+
+    fn write(&mut self, x: i32, y: i32, alive: bool) {
+        if let Some((x, y)) = self.topology.normalize(x, y) {
+            let index = y as usize * self.words_per_row + x as usize / 64;
+            let mask = 1 << (x as usize % 64);
+            if alive {
+                self.rows[index] |= mask;
+            } else {
+                self.rows[index] &= !mask;
+            }
+        }
+    }
+}
+
+impl Engine for DenseGrid {
+    fn get(&self, x: i32, y: i32) -> bool {
+        self.topology.normalize(x, y).is_some_and(|(x, y)| {
+            self.row(y as usize)[x as usize / 64] >> (x as usize % 64) & 1 == 1
+        })
+    }
+
+    fn set(&mut self, x: i32, y: i32) {
+        self.write(x, y, true);
+    }
+
+    fn unset(&mut self, x: i32, y: i32) {
+        self.write(x, y, false);
+    }
+
+    fn advance(&mut self, generations: u64) {
+        for _ in 0..generations {
+            self.step();
+        }
+    }
+
+    fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    fn population(&self) -> usize {
+        self.rows
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    fn live_cells(&self) -> Vec<(i32, i32)> {
+        let mut cells = Vec::with_capacity(self.population());
+        for y in 0..self.height {
+            for (i, &word) in self.row(y).iter().enumerate() {
+                let mut bits = word;
+                while bits != 0 {
+                    let x = i * 64 + bits.trailing_zeros() as usize;
+                    cells.push((x as i32, y as i32));
+                    bits &= bits - 1;
+                }
+            }
+        }
+        cells.sort_unstable();
+        cells
+    }
+}
@@ -0,0 +1,77 @@
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+use alloc::vec::Vec;
+use hashbrown::HashSet;
+
+use crate::GameOfLife;
+
+/// Cells that became alive or dead between two boards, returned by `GameOfLife::diff` and
+/// `GameOfLife::tick_diff`.
+///
+/// Only live cells are compared; Generations decay states are not reported. Both lists are sorted.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BoardDiff {
+    births: Vec<(i32, i32)>,
+    deaths: Vec<(i32, i32)>,
+}
+
+impl BoardDiff {
+    pub(crate) fn between(before: &HashSet<(i32, i32)>, after: &HashSet<(i32, i32)>) -> Self {
+        let births = after.difference(before).copied().collect();
+        let deaths = before.difference(after).copied().collect();
+        Self::from_changes(births, deaths)
+    }
+
+    /// Sorts already-known births and deaths into a diff.
+    pub(crate) fn from_changes(mut births: Vec<(i32, i32)>, mut deaths: Vec<(i32, i32)>) -> Self {
+        births.sort_unstable();
+        deaths.sort_unstable();
+        Self { births, deaths }
+    }
+
+    /// Cells alive in the newer board but not the older one.
+    pub fn births(&self) -> &[(i32, i32)] {
+        &self.births
+    }
+
+    /// Cells alive in the older board but not the newer one.
+    pub fn deaths(&self) -> &[(i32, i32)] {
+        &self.deaths
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+impl BoardDiff {
+    /// Returns whether no cell changed.
+    pub fn is_empty(&self) -> bool {
+        self.births.is_empty() && self.deaths.is_empty()
+    }
+
+    /// Returns the number of changed cells.
+    pub fn len(&self) -> usize {
+        self.births.len() + self.deaths.len()
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+impl GameOfLife {
+    /// Returns the cells that differ from `self` to `other`: births are alive only in `other`.
+    pub fn diff(&self, other: &GameOfLife) -> BoardDiff {
+        BoardDiff::between(&self.state, &other.state)
+    }
+
+    /// Advances one generation like `tick` and returns the cells it changed, so frontends can
+    /// redraw only those.
+    pub fn tick_diff(&mut self) -> BoardDiff {
+        if self.stable {
+            self.tick();
+            return BoardDiff::default();
+        }
+        let before = self.state.clone();
+        self.tick();
+        BoardDiff::between(&before, &self.state)
+    }
+}
@@ -0,0 +1,234 @@
+use alloc::vec::Vec;
+use core::ops::RangeInclusive;
+use hashbrown::HashMap;
+
+use crate::{BoardDiff, BoundingBox, GameOfLife};
+
+/// Bulk edits. Each call records a single undo snapshot, however many cells it touches. Cells moved
+/// past the edge of the `i32` plane are dropped, as the simulation drops cells born there.
+impl GameOfLife {
+    /// Marks every cell in `cells` as alive.
+    pub fn set_cells(&mut self, cells: impl IntoIterator<Item = (i32, i32)>) {
+        let added: Vec<(i32, i32)> = cells
+            .into_iter()
+            .filter_map(|(x, y)| self.topology.normalize(x, y))
+            .filter(|cell| !self.state.contains(cell))
+            .collect();
+        if added.is_empty() {
+            return;
+        }
+
+        self.snapshot();
+        for (x, y) in added {
+            self.place(x, y);
+        }
+    }
+
+    /// Kills the diff's deaths and brings its births to life, such as to redo changes captured
+    /// with `GameOfLife::diff` on another board.
+    pub fn apply_diff(&mut self, diff: &BoardDiff) {
+        if diff.is_empty() {
+            return;
+        }
+
+        self.snapshot();
+        self.invalidate();
+        for cell in diff.deaths() {
+            self.state.remove(cell);
+            self.ages.remove(cell);
+        }
+        for &(x, y) in diff.births() {
+            self.place(x, y);
+        }
+    }
+
+    /// Kills every cell inside the inclusive ranges.
+    pub fn clear_region(&mut self, x_range: RangeInclusive<i32>, y_range: RangeInclusive<i32>) {
+        let bounds = BoundingBox::from_ranges(&x_range, &y_range);
+        self.replace_cells(bounds, |_, _| None);
+    }
+
+    /// Moves every live cell by `(dx, dy)`.
+    pub fn translate(&mut self, dx: i32, dy: i32) {
+        if let Some(bounds) = self.occupied_bounds() {
+            self.translate_bounds(bounds, dx, dy);
+        }
+    }
+
+    /// Moves the live cells inside the inclusive ranges by `(dx, dy)`.
+    pub fn translate_region(
+        &mut self,
+        x_range: RangeInclusive<i32>,
+        y_range: RangeInclusive<i32>,
+        dx: i32,
+        dy: i32,
+    ) {
+        self.translate_bounds(BoundingBox::from_ranges(&x_range, &y_range), dx, dy);
+    }
+
+    /// Rotates the pattern a quarter turn counter-clockwise (as drawn by `Viewport`) within its bounding box.
+    pub fn rotate90(&mut self) {
+        if let Some(bounds) = self.occupied_bounds() {
+            self.rotate90_bounds(bounds);
+        }
+    }
+
+    /// Rotates the cells inside the inclusive ranges a quarter turn counter-clockwise.
+    ///
+    /// The rotated block keeps the region's minimum corner, so a non-square region's cells can land
+    /// outside it.
+    pub fn rotate90_region(&mut self, x_range: RangeInclusive<i32>, y_range: RangeInclusive<i32>) {
+        self.rotate90_bounds(BoundingBox::from_ranges(&x_range, &y_range));
+    }
+
+    /// Mirrors the pattern left-to-right within its bounding box.
+    pub fn flip_horizontal(&mut self) {
+        if let Some(bounds) = self.occupied_bounds() {
+            self.flip_bounds(bounds, true);
+        }
+    }
+
+    /// Mirrors the cells inside the inclusive ranges left-to-right.
+    pub fn flip_horizontal_region(
+        &mut self,
+        x_range: RangeInclusive<i32>,
+        y_range: RangeInclusive<i32>,
+    ) {
+        self.flip_bounds(BoundingBox::from_ranges(&x_range, &y_range), true);
+    }
+
+    /// Mirrors the pattern top-to-bottom within its bounding box.
+    pub fn flip_vertical(&mut self) {
+        if let Some(bounds) = self.occupied_bounds() {
+            self.flip_bounds(bounds, false);
+        }
+    }
+
+    /// Mirrors the cells inside the inclusive ranges top-to-bottom.
+    pub fn flip_vertical_region(
+        &mut self,
+        x_range: RangeInclusive<i32>,
+        y_range: RangeInclusive<i32>,
+    ) {
+        self.flip_bounds(BoundingBox::from_ranges(&x_range, &y_range), false);
+    }
+
+    fn translate_bounds(&mut self, bounds: BoundingBox, dx: i32, dy: i32) {
+        self.replace_cells(bounds, |x, y| {
+            Some((x.checked_add(dx)?, y.checked_add(dy)?))
+        });
+    }
+
+    fn rotate90_bounds(&mut self, bounds: BoundingBox) {
+        let BoundingBox {
+            min_x,
+            min_y,
+            max_y,
+            ..
+        } = bounds;
+        self.replace_cells(bounds, |x, y| {
+            Some((
+                offset(min_x, i64::from(max_y) - i64::from(y))?,
+                offset(min_y, i64::from(x) - i64::from(min_x))?,
+            ))
+        });
+    }
+
+    fn flip_bounds(&mut self, bounds: BoundingBox, horizontal: bool) {
+        let BoundingBox {
+            min_x,
+            min_y,
+            max_x,
+            max_y,
+        } = bounds;
+        self.replace_cells(bounds, |x, y| {
+            Some(if horizontal {
+                (offset(min_x, i64::from(max_x) - i64::from(x))?, y)
+            } else {
+                (x, offset(min_y, i64::from(max_y) - i64::from(y))?)
+            })
+        });
+    }
+
+    /// Extents of the live and decaying cells, which whole-board edits carry along together.
+    fn occupied_bounds(&self) -> Option<BoundingBox> {
+        BoundingBox::of(
+            self.state
+                .iter()
+                .chain(self.decay.iter().flatten())
+                .copied(),
+        )
+    }
+
+    /// Removes the live and decaying cells within `bounds` and re-adds those `map` sends somewhere.
+    fn replace_cells(&mut self, bounds: BoundingBox, map: impl Fn(i32, i32) -> Option<(i32, i32)>) {
+        let selected: Vec<(i32, i32)> = self
+            .state
+            .iter()
+            .copied()
+            .filter(|&(x, y)| bounds.contains(x, y))
+            .collect();
+        let decaying: Vec<(i32, i32)> = self
+            .decay
+            .iter()
+            .flatten()
+            .copied()
+            .filter(|&(x, y)| bounds.contains(x, y))
+            .collect();
+        if selected.is_empty() && decaying.is_empty() {
+            return;
+        }
+
+        self.snapshot();
+        self.invalidate();
+        // Decaying cells move like live ones; live cells landing on them take precedence.
+        let mut moved_decay = Vec::with_capacity(decaying.len());
+        for cell in decaying {
+            let state = self.remove_decaying(cell);
+            if let Some(target) =
+                map(cell.0, cell.1).and_then(|(x, y)| self.topology.normalize(x, y))
+            {
+                moved_decay.extend(state.map(|state| (target, state)));
+            }
+        }
+        let mut moved: HashMap<(i32, i32), u32> = HashMap::new();
+        let mut moved_colors = Vec::new();
+        for cell in selected {
+            self.state.remove(&cell);
+            let age = self.ages.remove(&cell).unwrap_or(0);
+            let color = self.color_of(cell);
+            if let Some(target) =
+                map(cell.0, cell.1).and_then(|(x, y)| self.topology.normalize(x, y))
+            {
+                let merged = moved.entry(target).or_default();
+                *merged = (*merged).max(age);
+                moved_colors.push((target, color));
+            }
+        }
+        for (cell, color) in moved_colors {
+            self.set_color_of(cell, color);
+        }
+        for (cell, age) in moved {
+            // Cells landing on an already-live cell keep the older of the two ages.
+            if !self.state.insert(cell) {
+                let existing = self.age_of(cell);
+                if existing >= age {
+                    continue;
+                }
+            }
+            if age > 0 {
+                self.ages.insert(cell, age);
+            }
+        }
+        for (cell, state) in moved_decay {
+            if self.cell_state_of(cell) == 0 {
+                self.insert_decaying(cell, state);
+            }
+        }
+    }
+}
+
+/// Returns `base + delta`, or `None` past the edge of the `i32` plane.
+fn offset(base: i32, delta: i64) -> Option<i32> {
+    i32::try_from(i64::from(base) + delta).ok()
+}
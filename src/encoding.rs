@@ -0,0 +1,123 @@
+use alloc::{string::String, vec::Vec};
+use core::error::Error;
+use core::fmt;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::JsValue;
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Reasons a base64-encoded board could not be decoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The input contained a character outside the URL-safe base64 alphabet.
+    InvalidCharacter(char),
+    /// The input length cannot come from unpadded base64.
+    InvalidLength,
+    /// The decoded bytes ended partway through a coordinate.
+    Truncated,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::InvalidCharacter(ch) => write!(f, "invalid base64 character {ch:?}"),
+            DecodeError::InvalidLength => write!(f, "invalid base64 length"),
+            DecodeError::Truncated => write!(f, "encoded board ends mid-coordinate"),
+        }
+    }
+}
+
+impl Error for DecodeError {}
+
+#[cfg(target_arch = "wasm32")]
+impl From<DecodeError> for JsValue {
+    fn from(err: DecodeError) -> Self {
+        JsValue::from_str(&err.to_string())
+    }
+}
+
+/// Encodes bytes as unpadded URL-safe base64.
+pub(crate) fn encode_base64(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let buf = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |buf, (i, &b)| buf | (b as u32) << (16 - 8 * i));
+        for i in 0..=chunk.len() {
+            out.push(ALPHABET[(buf >> (18 - 6 * i) & 0x3f) as usize] as char);
+        }
+    }
+    out
+}
+
+/// Decodes unpadded URL-safe base64, tolerating trailing `=` padding.
+pub(crate) fn decode_base64(text: &str) -> Result<Vec<u8>, DecodeError> {
+    let text = text.trim_end_matches('=');
+    if text.len() % 4 == 1 {
+        return Err(DecodeError::InvalidLength);
+    }
+
+    let mut out = Vec::with_capacity(text.len() * 3 / 4);
+    let mut buf = 0u32;
+    let mut bits = 0;
+    for ch in text.chars() {
+        let value = ALPHABET
+            .iter()
+            .position(|&c| c as char == ch)
+            .ok_or(DecodeError::InvalidCharacter(ch))?;
+        buf = buf << 6 | value as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((buf >> bits) as u8);
+        }
+    }
+    Ok(out)
+}
+
+/// Appends `value` as a zigzag-encoded LEB128 varint so small coordinates stay short.
+pub(crate) fn write_varint(out: &mut Vec<u8>, value: i32) {
+    let mut zigzag = ((value << 1) ^ (value >> 31)) as u32;
+    while zigzag >= 0x80 {
+        out.push(zigzag as u8 | 0x80);
+        zigzag >>= 7;
+    }
+    out.push(zigzag as u8);
+}
+
+/// Reads a zigzag-encoded varint written by `write_varint`.
+pub(crate) fn read_varint(bytes: &mut impl Iterator<Item = u8>) -> Result<i32, DecodeError> {
+    let mut zigzag = 0u32;
+    for shift in (0..35).step_by(7) {
+        let byte = bytes.next().ok_or(DecodeError::Truncated)?;
+        zigzag |= ((byte & 0x7f) as u32) << shift;
+        if byte & 0x80 == 0 {
+            return Ok((zigzag >> 1) as i32 ^ -((zigzag & 1) as i32));
+        }
+    }
+    Err(DecodeError::Truncated)
+}
+
+/// Appends `value` as an unsigned LEB128 varint.
+pub(crate) fn write_uvarint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+/// Reads an unsigned varint written by `write_uvarint`.
+pub(crate) fn read_uvarint(bytes: &mut impl Iterator<Item = u8>) -> Result<u64, DecodeError> {
+    let mut value = 0u64;
+    for shift in (0..70).step_by(7) {
+        let byte = bytes.next().ok_or(DecodeError::Truncated)?;
+        value |= ((byte & 0x7f) as u64) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    Err(DecodeError::Truncated)
+}
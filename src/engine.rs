@@ -0,0 +1,98 @@
+use crate::{ChunkedGrid, DenseGrid, GameOfLife, HashLife, Topology};
+use alloc::{boxed::Box, vec::Vec};
+
+/// Common interface over simulation backends, so callers can swap `GameOfLife` for `HashLife`,
+/// `DenseGrid`, or `ChunkedGrid`.
+pub trait Engine {
+    /// Returns whether the cell at `(x, y)` is alive.
+    fn get(&self, x: i32, y: i32) -> bool;
+
+    /// Marks the cell at `(x, y)` as alive.
+    fn set(&mut self, x: i32, y: i32);
+
+    /// Marks the cell at `(x, y)` as dead.
+    fn unset(&mut self, x: i32, y: i32);
+
+    /// Advances the simulation by `generations` steps.
+    fn advance(&mut self, generations: u64);
+
+    /// Returns how many generations have been simulated.
+    fn generation(&self) -> u64;
+
+    /// Returns the number of live cells.
+    fn population(&self) -> usize;
+
+    /// Returns every live cell, sorted.
+    fn live_cells(&self) -> Vec<(i32, i32)>;
+}
+
+impl Engine for GameOfLife {
+    fn get(&self, x: i32, y: i32) -> bool {
+        GameOfLife::get(self, x, y)
+    }
+
+    fn set(&mut self, x: i32, y: i32) {
+        GameOfLife::set(self, x, y);
+    }
+
+    fn unset(&mut self, x: i32, y: i32) {
+        GameOfLife::unset(self, x, y);
+    }
+
+    fn advance(&mut self, generations: u64) {
+        GameOfLife::advance(self, generations);
+    }
+
+    fn generation(&self) -> u64 {
+        GameOfLife::generation(self)
+    }
+
+    fn population(&self) -> usize {
+        GameOfLife::population(self)
+    }
+
+    fn live_cells(&self) -> Vec<(i32, i32)> {
+        let mut cells: Vec<(i32, i32)> = self.iter_live().collect();
+        cells.sort_unstable();
+        cells
+    }
+}
+
+/// Selects which engine simulates a board.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Backend {
+    /// The `HashSet` of live cells in `GameOfLife`; works on any topology.
+    #[default]
+    Sparse,
+    /// Packed bit rows in `DenseGrid`; needs a bounded or toroidal topology and a two-state rule
+    /// on the Moore neighborhood.
+    Dense,
+    /// The memoized quadtree in `HashLife`; needs an infinite topology and a two-state rule
+    /// without B0 on the Moore neighborhood.
+    HashLife,
+    /// 64 by 64 bitmap chunks in `ChunkedGrid`; needs an infinite topology and a two-state rule
+    /// without B0 on the Moore neighborhood.
+    Chunked,
+}
+
+impl Backend {
+    /// Copies `game` into an engine of this kind, or returns `None` if the backend cannot
+    /// represent its topology or rule.
+    pub fn build(self, game: &GameOfLife) -> Option<Box<dyn Engine>> {
+        match self {
+            Backend::Sparse => Some(Box::new(game.clone())),
+            Backend::Dense => {
+                DenseGrid::from_game(game).map(|grid| Box::new(grid) as Box<dyn Engine>)
+            }
+            Backend::HashLife => (game.topology() == Topology::Infinite
+                && !game.rule().next_state(false, 0)
+                && game.rule().states() == 2
+                && game.rule().is_moore())
+            .then(|| Box::new(HashLife::from_game(game)) as Box<dyn Engine>),
+            Backend::Chunked => {
+                ChunkedGrid::from_game(game).map(|grid| Box::new(grid) as Box<dyn Engine>)
+            }
+        }
+    }
+}
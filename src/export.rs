@@ -0,0 +1,320 @@
+//! Animated GIF and APNG export of a viewport over several generations, plus single-frame PNGs
+//! through `Viewport::render_png`.
+//!
+//! Both encoders are self-contained: GIF frames are LZW-compressed, and PNG data is stored in
+//! uncompressed deflate blocks, which every decoder accepts.
+
+use std::collections::HashMap;
+
+use crate::{GameOfLife, ImageStyle, Viewport};
+
+const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+
+/// Largest payload of one uncompressed deflate block.
+const STORED_BLOCK_LEN: usize = 0xffff;
+
+/// One frame as palette indices into `ImageStyle::palette`, row by row.
+struct Raster {
+    width: u32,
+    height: u32,
+    pixels: Vec<u8>,
+}
+
+impl Raster {
+    /// Draws `viewport` of `game` as `Viewport::render` would, one square per rendered glyph.
+    fn draw(game: &GameOfLife, viewport: &Viewport, style: &ImageStyle) -> Self {
+        let (columns, cells) = viewport.glyph_grid(game);
+        let rows = cells.len() / columns.max(1);
+        let (width, height) = (style.image_len(columns), style.image_len(rows));
+        let (width_px, size) = (width as usize, style.cell_size() as usize);
+
+        let mut pixels = vec![0; width_px * height as usize];
+        if style.grid().is_some() {
+            let stride = style.stride() as usize;
+            for (y, row) in pixels.chunks_mut(width_px).enumerate() {
+                if y % stride == 0 {
+                    row.fill(2);
+                } else {
+                    row.iter_mut().step_by(stride).for_each(|pixel| *pixel = 2);
+                }
+            }
+        }
+        for (index, _) in cells.iter().enumerate().filter(|(_, alive)| **alive) {
+            let x = style.cell_offset(index % columns) as usize;
+            let y = style.cell_offset(index / columns) as usize;
+            for py in y..y + size {
+                pixels[py * width_px + x..][..size].fill(1);
+            }
+        }
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+}
+
+/// Draws `frames` frames of `viewport`, starting with `game` as it is and advancing a copy one
+/// generation between frames. `game` itself is left untouched.
+fn rasters(
+    game: &GameOfLife,
+    viewport: &Viewport,
+    frames: usize,
+    style: &ImageStyle,
+) -> Vec<Raster> {
+    let mut game = game.clone();
+    game.set_history_limit(0);
+    (0..frames)
+        .map(|frame| {
+            if frame > 0 {
+                game.tick();
+            }
+            Raster::draw(&game, viewport, style)
+        })
+        .collect()
+}
+
+/// Encodes `frames` generations of `viewport` as a looping animated GIF.
+///
+/// # Panics
+///
+/// Panics if the image would be wider or taller than 65535 pixels, the most a GIF can describe.
+pub fn gif(game: &GameOfLife, viewport: &Viewport, frames: usize, style: &ImageStyle) -> Vec<u8> {
+    let rasters = rasters(game, viewport, frames, style);
+    let (width, height) = rasters
+        .first()
+        .map_or((0, 0), |raster| (raster.width, raster.height));
+    let (Ok(width), Ok(height)) = (u16::try_from(width), u16::try_from(height)) else {
+        panic!("a {width}x{height} image is too large for a GIF");
+    };
+    let delay = (style.frame_delay().as_millis() / 10).min(u16::MAX.into()) as u16;
+
+    let mut out = b"GIF89a".to_vec();
+    out.extend_from_slice(&width.to_le_bytes());
+    out.extend_from_slice(&height.to_le_bytes());
+    // A four-entry global color table: dead, live, grid, and an unused black entry.
+    out.extend_from_slice(&[0x91, 0, 0]);
+    for (r, g, b) in style.palette() {
+        out.extend_from_slice(&[r, g, b]);
+    }
+    out.extend_from_slice(&[0; 3]);
+    // Netscape extension: loop forever.
+    out.extend_from_slice(b"\x21\xff\x0bNETSCAPE2.0\x03\x01\x00\x00\x00");
+
+    for raster in &rasters {
+        out.extend_from_slice(&[0x21, 0xf9, 4, 0]);
+        out.extend_from_slice(&delay.to_le_bytes());
+        out.extend_from_slice(&[0, 0]);
+
+        out.push(0x2c);
+        out.extend_from_slice(&[0, 0, 0, 0]);
+        // Every frame has the size of the first.
+        out.extend_from_slice(&width.to_le_bytes());
+        out.extend_from_slice(&height.to_le_bytes());
+        out.push(0);
+
+        out.push(2);
+        for block in lzw_encode(&raster.pixels).chunks(255) {
+            out.push(block.len() as u8);
+            out.extend_from_slice(block);
+        }
+        out.push(0);
+    }
+    out.push(0x3b);
+    out
+}
+
+/// Compresses 2-bit palette indices with GIF's variable-width LZW, without early code changes.
+fn lzw_encode(pixels: &[u8]) -> Vec<u8> {
+    const CLEAR: u16 = 4;
+    const END: u16 = 5;
+    const MAX_CODES: u16 = 4096;
+
+    let mut writer = BitWriter::default();
+    let mut table: HashMap<(u16, u8), u16> = HashMap::new();
+    let mut next = END + 1;
+    let mut width = 3;
+    writer.write(CLEAR, width);
+
+    let Some((&first, rest)) = pixels.split_first() else {
+        writer.write(END, width);
+        return writer.finish();
+    };
+    let mut prefix = u16::from(first);
+    for &pixel in rest {
+        if let Some(&code) = table.get(&(prefix, pixel)) {
+            prefix = code;
+            continue;
+        }
+        writer.write(prefix, width);
+        table.insert((prefix, pixel), next);
+        next += 1;
+        if next > 1 << width && width < 12 {
+            width += 1;
+        }
+        if next == MAX_CODES {
+            writer.write(CLEAR, width);
+            table.clear();
+            next = END + 1;
+            width = 3;
+        }
+        prefix = u16::from(pixel);
+    }
+    writer.write(prefix, width);
+    // The decoder adds an entry for the final code too, so it may have widened by now.
+    if next == 1 << width && width < 12 {
+        width += 1;
+    }
+    writer.write(END, width);
+    writer.finish()
+}
+
+/// Packs codes least significant bit first, as GIF expects.
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u32,
+    bits: u32,
+}
+
+impl BitWriter {
+    fn write(&mut self, code: u16, width: u32) {
+        self.buffer |= u32::from(code) << self.bits;
+        self.bits += width;
+        while self.bits >= 8 {
+            self.bytes.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.bits -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.bits > 0 {
+            self.bytes.push(self.buffer as u8);
+        }
+        self.bytes
+    }
+}
+
+/// Encodes `frames` generations of `viewport` as a looping animated PNG.
+///
+/// Viewers without APNG support show the first frame.
+pub fn apng(game: &GameOfLife, viewport: &Viewport, frames: usize, style: &ImageStyle) -> Vec<u8> {
+    let rasters = rasters(game, viewport, frames, style);
+    let Some(first) = rasters.first() else {
+        return Vec::new();
+    };
+    let delay = style.frame_delay().as_millis().min(u16::MAX.into()) as u16;
+
+    let mut out = png_header(first, &style.palette());
+    let mut actl = (rasters.len() as u32).to_be_bytes().to_vec();
+    actl.extend_from_slice(&0u32.to_be_bytes());
+    write_chunk(&mut out, b"acTL", &actl);
+
+    let mut sequence = 0u32;
+    for (index, raster) in rasters.iter().enumerate() {
+        let mut fctl = sequence.to_be_bytes().to_vec();
+        sequence += 1;
+        fctl.extend_from_slice(&raster.width.to_be_bytes());
+        fctl.extend_from_slice(&raster.height.to_be_bytes());
+        fctl.extend_from_slice(&[0; 8]);
+        fctl.extend_from_slice(&delay.to_be_bytes());
+        fctl.extend_from_slice(&1000u16.to_be_bytes());
+        fctl.extend_from_slice(&[0, 0]);
+        write_chunk(&mut out, b"fcTL", &fctl);
+
+        let data = zlib_stored(&scanlines(raster));
+        if index == 0 {
+            write_chunk(&mut out, b"IDAT", &data);
+        } else {
+            let mut fdat = sequence.to_be_bytes().to_vec();
+            sequence += 1;
+            fdat.extend_from_slice(&data);
+            write_chunk(&mut out, b"fdAT", &fdat);
+        }
+    }
+    write_chunk(&mut out, b"IEND", &[]);
+    out
+}
+
+impl Viewport {
+    /// Renders the viewport of `game` as a PNG with the default `ImageStyle`.
+    pub fn render_png(&self, game: &GameOfLife) -> Vec<u8> {
+        self.render_png_styled(game, &ImageStyle::new())
+    }
+
+    /// Renders the viewport of `game` as a PNG, drawn with `style`.
+    pub fn render_png_styled(&self, game: &GameOfLife, style: &ImageStyle) -> Vec<u8> {
+        let raster = Raster::draw(game, self, style);
+        let mut out = png_header(&raster, &style.palette());
+        write_chunk(&mut out, b"IDAT", &zlib_stored(&scanlines(&raster)));
+        write_chunk(&mut out, b"IEND", &[]);
+        out
+    }
+}
+
+/// Writes the PNG signature, an 8-bit indexed `IHDR` sized to `raster`, and `palette`.
+fn png_header(raster: &Raster, palette: &[(u8, u8, u8)]) -> Vec<u8> {
+    let mut out = PNG_SIGNATURE.to_vec();
+    let mut ihdr = raster.width.to_be_bytes().to_vec();
+    ihdr.extend_from_slice(&raster.height.to_be_bytes());
+    ihdr.extend_from_slice(&[8, 3, 0, 0, 0]);
+    write_chunk(&mut out, b"IHDR", &ihdr);
+    let plte: Vec<u8> = palette.iter().flat_map(|&(r, g, b)| [r, g, b]).collect();
+    write_chunk(&mut out, b"PLTE", &plte);
+    out
+}
+
+/// Prefixes each row of `raster` with PNG's "no filter" byte.
+fn scanlines(raster: &Raster) -> Vec<u8> {
+    let width = raster.width as usize;
+    let mut out = Vec::with_capacity(raster.pixels.len() + raster.height as usize);
+    for row in raster.pixels.chunks(width.max(1)) {
+        out.push(0);
+        out.extend_from_slice(row);
+    }
+    out
+}
+
+fn write_chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let start = out.len();
+    out.extend_from_slice(kind);
+    out.extend_from_slice(data);
+    let crc = crc32(&out[start..]);
+    out.extend_from_slice(&crc.to_be_bytes());
+}
+
+/// Wraps `data` in a zlib stream of uncompressed deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut out = vec![0x78, 0x01];
+    let mut blocks = data.chunks(STORED_BLOCK_LEN).peekable();
+    if blocks.peek().is_none() {
+        out.extend_from_slice(&[1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        out.push(u8::from(blocks.peek().is_none()));
+        let len = block.len() as u16;
+        out.extend_from_slice(&len.to_le_bytes());
+        out.extend_from_slice(&(!len).to_le_bytes());
+        out.extend_from_slice(block);
+    }
+    out.extend_from_slice(&adler32(data).to_be_bytes());
+    out
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(!0u32, |crc, &byte| {
+        (0..8).fold(crc ^ u32::from(byte), |crc, _| {
+            (crc >> 1) ^ (0xedb8_8320 & (crc & 1).wrapping_neg())
+        })
+    })
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (a, b) = bytes.iter().fold((1u32, 0u32), |(a, b), &byte| {
+        let a = (a + u32::from(byte)) % 65521;
+        (a, (b + a) % 65521)
+    });
+    b << 16 | a
+}
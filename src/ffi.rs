@@ -0,0 +1,154 @@
+//! A C-compatible API for embedding the simulator in engines written in other languages.
+//!
+//! Boards are opaque `GolBoard` pointers, a `GameOfLife` on the Rust side, created by `gol_new` or
+//! `gol_new_torus` and released with `gol_free`. Viewports are copied into caller-owned buffers in the same row-major layout as
+//! `cells_at`, one byte per cell, so no memory crosses the boundary in the other direction. The
+//! matching declarations are in `include/gameoflife.h`.
+//!
+//! Functions that take a board expect a pointer returned by `gol_new` or `gol_new_torus` and not
+//! yet freed. A board is not thread-safe; callers must not use one from several threads at once.
+
+use core::ffi::{CStr, c_char};
+
+use alloc::boxed::Box;
+
+use crate::{GameOfLife, Rule, Topology};
+
+/// Creates an empty infinite board under Conway's rules.
+#[unsafe(no_mangle)]
+pub extern "C" fn gol_new() -> *mut GameOfLife {
+    Box::into_raw(Box::new(GameOfLife::new()))
+}
+
+/// Creates an empty `width` by `height` torus, or returns null if either size is not positive.
+#[unsafe(no_mangle)]
+pub extern "C" fn gol_new_torus(width: i32, height: i32) -> *mut GameOfLife {
+    if width <= 0 || height <= 0 {
+        return core::ptr::null_mut();
+    }
+    Box::into_raw(Box::new(GameOfLife::with_topology(Topology::Torus {
+        width,
+        height,
+    })))
+}
+
+/// Releases a board. Null is ignored.
+///
+/// # Safety
+///
+/// `game` must be null or a live board pointer, and must not be used afterwards.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gol_free(game: *mut GameOfLife) {
+    if !game.is_null() {
+        drop(unsafe { Box::from_raw(game) });
+    }
+}
+
+/// Brings the cell at `(x, y)` to life if `alive` is true, or kills it otherwise.
+///
+/// # Safety
+///
+/// `game` must be a live board pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gol_set(game: *mut GameOfLife, x: i32, y: i32, alive: bool) {
+    let game = unsafe { &mut *game };
+    if alive {
+        game.set(x, y);
+    } else {
+        game.unset(x, y);
+    }
+}
+
+/// Returns whether the cell at `(x, y)` is alive.
+///
+/// # Safety
+///
+/// `game` must be a live board pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gol_get(game: *const GameOfLife, x: i32, y: i32) -> bool {
+    unsafe { &*game }.get(x, y)
+}
+
+/// Advances the board by `generations` generations.
+///
+/// # Safety
+///
+/// `game` must be a live board pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gol_tick(game: *mut GameOfLife, generations: u32) {
+    let game = unsafe { &mut *game };
+    for _ in 0..generations {
+        game.tick();
+    }
+}
+
+/// Returns the number of generations computed so far.
+///
+/// # Safety
+///
+/// `game` must be a live board pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gol_generation(game: *const GameOfLife) -> u64 {
+    unsafe { &*game }.generation()
+}
+
+/// Returns the number of live cells.
+///
+/// # Safety
+///
+/// `game` must be a live board pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gol_population(game: *const GameOfLife) -> u64 {
+    unsafe { &*game }.population() as u64
+}
+
+/// Parses `rule`, a NUL-terminated rulestring such as `B36/S23`, and evolves under it from now on.
+/// Returns false and leaves the rule unchanged if it is not valid UTF-8 or not a valid rule.
+///
+/// # Safety
+///
+/// `game` must be a live board pointer and `rule` a valid NUL-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gol_set_rule(game: *mut GameOfLife, rule: *const c_char) -> bool {
+    let game = unsafe { &mut *game };
+    let rule = unsafe { CStr::from_ptr(rule) };
+    match rule.to_str().map(str::parse::<Rule>) {
+        Ok(Ok(rule)) => {
+            game.set_rule(rule);
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Copies the `width` by `height` viewport starting at `(origin_x, origin_y)` into `buffer`, one
+/// byte per cell as `cells_at` lays them out. Returns the number of bytes written, or -1 without
+/// writing anything if a size is negative or `len` is smaller than `width * height`.
+///
+/// # Safety
+///
+/// `game` must be a live board pointer and `buffer` must be valid for writes of `len` bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn gol_cells_at(
+    game: *const GameOfLife,
+    origin_x: i32,
+    origin_y: i32,
+    width: i32,
+    height: i32,
+    buffer: *mut u8,
+    len: usize,
+) -> i64 {
+    let (Ok(w), Ok(h)) = (usize::try_from(width), usize::try_from(height)) else {
+        return -1;
+    };
+    let Some(size) = w.checked_mul(h).filter(|&size| size <= len) else {
+        return -1;
+    };
+    if size == 0 {
+        return 0;
+    }
+    let cells = unsafe { &*game }.cells_at(width, height, origin_x, origin_y);
+    let buffer = unsafe { core::slice::from_raw_parts_mut(buffer, size) };
+    buffer.copy_from_slice(&cells);
+    size as i64
+}
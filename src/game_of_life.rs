@@ -1,20 +1,271 @@
-use std::collections::{HashSet, VecDeque};
-use std::fmt;
-use std::ops::RangeInclusive;
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::collections::VecDeque;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use core::fmt;
+use core::ops::RangeInclusive;
+use hashbrown::{HashMap, HashSet};
+
+use lock::{Guard, Lock};
+use observer::Observers;
+use tiles::TileLog;
 
 #[cfg(target_arch = "wasm32")]
 use wasm_bindgen::prelude::*;
 
+mod bounds;
+mod builder;
+pub mod catalog;
+mod cell_buffer;
+mod chunked;
+mod colors;
+mod cycle;
+mod dense;
+mod diff;
+mod editing;
+mod encoding;
+mod engine;
+#[cfg(feature = "image")]
+pub mod export;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod hashlife;
+mod history;
+#[cfg(feature = "std")]
+pub mod identify;
+mod layers;
+mod limit;
+mod lock;
+mod macrocell;
+mod neighborhood;
+mod observer;
+mod pattern;
+pub mod patterns;
+mod replay;
+mod rule;
+mod run;
+mod selection;
+#[cfg(feature = "server")]
+pub mod server;
+mod simulation;
+mod snapshot;
+mod statistics;
+mod style;
+mod svg;
+mod symmetry;
+#[cfg(feature = "testing")]
+pub mod testing;
+mod tiles;
+mod topology;
+mod viewport;
+#[cfg(target_arch = "wasm32")]
+mod wasm;
+
+pub use bounds::BoundingBox;
+pub use builder::GameOfLifeBuilder;
+pub use cell_buffer::CellBuffer;
+pub use chunked::ChunkedGrid;
+pub use colors::ColorMode;
+pub use cycle::Cycle;
+pub use dense::DenseGrid;
+pub use diff::BoardDiff;
+pub use encoding::DecodeError;
+pub use engine::{Backend, Engine};
+pub use hashlife::HashLife;
+pub use history::HistoryEntry;
+pub use layers::{Layer, Layers, LayersRender};
+pub use limit::CoordLimit;
+pub use neighborhood::Neighborhood;
+pub use observer::{Observer, ObserverId, SimulationEvent};
+pub use pattern::{Format, Pattern, PatternError, Transform};
+pub use replay::{Player, Recorder, Replay, ReplayError, ReplayEvent};
+pub use rule::{Rule, RuleParseError};
+pub use run::{RunReport, StopCondition, StopReason};
+pub use selection::Selection;
+pub use simulation::Simulation;
+pub use snapshot::SnapshotError;
+pub use statistics::Statistics;
+pub use style::{Color, ImageStyle, Packing, RenderStyle};
+pub use tiles::TILE_SIZE;
+pub use topology::Topology;
+pub use viewport::{Viewport, ViewportCells, ViewportRender};
+
 /// Core Game of Life state machine backed by a sparse hash set.
 const HISTORY_LIMIT: usize = 255;
 
+/// Candidate count below which thread coordination costs more than parallel evaluation saves.
+#[cfg(feature = "rayon")]
+const PARALLEL_THRESHOLD: usize = 4096;
+
+/// With the `serde` feature, boards serialize with their rules, generation, statistics, and undo/redo
+/// history. Call `clear_history` first to save only the current state.
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
-#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct GameOfLife {
     state: HashSet<(i32, i32)>,
-    history: VecDeque<HashSet<(i32, i32)>>,
+    /// Cells decaying under a Generations rule: `decay[i]` holds the cells in state `i + 2`.
+    /// Trailing empty layers are dropped, so boards in the same states compare equal.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
+    decay: Vec<HashSet<(i32, i32)>>,
+    /// Live cells by color under a multi-color `color_mode`: `colors[i]` holds the cells of color
+    /// `i + 1`, and cells in no layer have color 0. Dead cells may linger here until reborn.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
+    colors: Vec<HashSet<(i32, i32)>>,
+    color_mode: ColorMode,
+    /// Consecutive generations each live cell had survived as of `ages_generation`; cells without
+    /// an entry were newborn then. Ages are not serialized, so a loaded board starts every cell at 0.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    ages: HashMap<(i32, i32), u32>,
+    /// Generation `ages` was last brought up to date. Stable ticks age every cell uniformly, so
+    /// they only bump `generation` and the difference is added on lookup.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    ages_generation: u64,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "VecDeque::is_empty"))]
+    history: VecDeque<Snapshot>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
+    redo: Vec<Snapshot>,
+    history_limit: usize,
+    rule: Rule,
+    topology: Topology,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    coord_limit: Option<CoordLimit>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
+    rule_regions: Vec<RuleRegion>,
+    generation: u64,
+    /// Births and deaths from the most recent tick.
+    last_changes: (usize, usize),
+    /// Cells removed by `coord_limit` during the most recent tick.
+    last_culled: usize,
+    /// Smallest and largest populations observed around ticks.
+    population_range: Option<(usize, usize)>,
+    /// Set when the last tick left the board unchanged, so further ticks can skip recomputation.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    stable: bool,
+    /// Cells whose state changed in the last step, so the next one only re-evaluates their
+    /// neighborhoods. `None` after an edit, which makes the next step evaluate every live cell.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    changed: Option<HashSet<(i32, i32)>>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    cells_cache: Option<CellsCache>,
+    /// Tiles changed by recent steps, while `set_tile_tracking` is on.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    tile_log: Option<TileLog>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    observers: Observers,
+}
+
+impl Default for GameOfLife {
+    fn default() -> Self {
+        Self {
+            state: HashSet::new(),
+            decay: Vec::new(),
+            colors: Vec::new(),
+            color_mode: ColorMode::Mono,
+            ages: HashMap::new(),
+            ages_generation: 0,
+            history: VecDeque::new(),
+            redo: Vec::new(),
+            history_limit: HISTORY_LIMIT,
+            rule: Rule::default(),
+            topology: Topology::default(),
+            coord_limit: None,
+            rule_regions: Vec::new(),
+            generation: 0,
+            last_changes: (0, 0),
+            last_culled: 0,
+            population_range: None,
+            stable: false,
+            changed: None,
+            cells_cache: None,
+            tile_log: None,
+            observers: Observers::default(),
+        }
+    }
+}
+
+/// A board saved for undo/redo together with the generation it was taken at.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Snapshot {
+    state: HashSet<(i32, i32)>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    decay: Vec<HashSet<(i32, i32)>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    colors: Vec<HashSet<(i32, i32)>>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    ages: HashMap<(i32, i32), u32>,
+    generation: u64,
+}
+
+/// A rectangular zone whose cells evolve under their own rule.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct RuleRegion {
+    x_range: RangeInclusive<i32>,
+    y_range: RangeInclusive<i32>,
+    rule: Rule,
+}
+
+impl RuleRegion {
+    fn contains(&self, x: i32, y: i32) -> bool {
+        self.x_range.contains(&x) && self.y_range.contains(&y)
+    }
+
+    /// Returns the rule of the last region in `regions` containing `(x, y)`, or `default`.
+    fn rule_at(regions: &[RuleRegion], default: Rule, x: i32, y: i32) -> Rule {
+        regions
+            .iter()
+            .rev()
+            .find(|region| region.contains(x, y))
+            .map_or(default, |region| region.rule)
+    }
+}
+
+/// `cells_at` arguments plus the board's `state_hash`.
+type CellsKey = (i32, i32, i32, i32, u64);
+
+/// Most recent `cells_at` buffer, shared behind a lock so `cells_at` can stay `&self`.
+#[derive(Debug, Default)]
+struct CellsCache {
+    entry: Lock<Option<(CellsKey, Vec<u8>)>>,
+}
+
+impl CellsCache {
+    fn entry(&self) -> Guard<'_, Option<(CellsKey, Vec<u8>)>> {
+        lock::lock(&self.entry)
+    }
+}
+
+impl Clone for CellsCache {
+    fn clone(&self) -> Self {
+        Self {
+            entry: Lock::new(self.entry().clone()),
+        }
+    }
 }
 
+/// Boards compare equal when they hold the same live and decaying cells; undo history and rules
+/// are ignored.
+impl PartialEq for GameOfLife {
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state && self.decay == other.decay
+    }
+}
+
+impl Eq for GameOfLife {}
+
 #[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
 impl GameOfLife {
     /// Creates a new, empty game board.
@@ -22,24 +273,62 @@ impl GameOfLife {
         Self::default()
     }
 
+    /// Creates a board holding `patterns::soup(width, height, density, seed)`: a reproducible
+    /// random fill of the region from `(0, 0)` to `(width - 1, height - 1)`.
+    pub fn random_soup(width: u32, height: u32, density: f64, seed: u64) -> Self {
+        patterns::soup(width, height, density, seed).to_game()
+    }
+
     /// Returns whether the cell at `(x, y)` is alive.
     pub fn get(&self, x: i32, y: i32) -> bool {
-        self.state.contains(&(x, y))
+        self.topology
+            .normalize(x, y)
+            .is_some_and(|cell| self.state.contains(&cell))
     }
 
-    /// Marks the cell at `(x, y)` as alive.
+    /// Marks the cell at `(x, y)` as alive. Cells outside a bounded board are ignored.
     pub fn set(&mut self, x: i32, y: i32) {
-        self.state.insert((x, y));
+        if let Some(cell) = self.topology.normalize(x, y)
+            && !self.state.contains(&cell)
+        {
+            self.snapshot();
+            self.place(cell.0, cell.1);
+        }
     }
 
-    /// Marks the cell at `(x, y)` as dead.
+    /// Marks the cell at `(x, y)` as dead, including a decaying cell.
     pub fn unset(&mut self, x: i32, y: i32) {
-        self.state.remove(&(x, y));
+        if let Some(cell) = self.topology.normalize(x, y)
+            && self.cell_state_of(cell) != 0
+        {
+            self.snapshot();
+            self.kill(cell);
+        }
     }
 
-    /// Removes all live cells from the board.
+    /// Removes all live and decaying cells from the board.
     pub fn clear(&mut self) {
-        self.state.clear();
+        if !self.state.is_empty() || !self.decay.is_empty() {
+            self.snapshot();
+            self.invalidate();
+            self.state.clear();
+            self.decay.clear();
+            self.colors.clear();
+            self.ages.clear();
+        }
+    }
+
+    /// Returns the state of the cell at `(x, y)`: 0 when dead, 1 when alive, and `2..` while it
+    /// decays under a Generations rule.
+    pub fn cell_state(&self, x: i32, y: i32) -> u8 {
+        self.topology
+            .normalize(x, y)
+            .map_or(0, |cell| self.cell_state_of(cell))
+    }
+
+    /// Returns the number of cells decaying under a Generations rule, which `population` excludes.
+    pub fn decaying_population(&self) -> usize {
+        self.decay.iter().map(HashSet::len).sum()
     }
 
     /// Toggles the cell at `(x, y)` and returns the new state.
@@ -53,6 +342,139 @@ impl GameOfLife {
         }
     }
 
+    /// Returns the number of live cells.
+    pub fn population(&self) -> usize {
+        self.state.len()
+    }
+
+    /// Returns the extents of the live cells, or `None` when the board is empty.
+    pub fn bounding_box(&self) -> Option<BoundingBox> {
+        BoundingBox::of(self.state.iter().copied())
+    }
+
+    /// Returns how many generations have been simulated.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Overrides the generation counter, e.g. when restoring a saved board.
+    pub fn set_generation(&mut self, generation: u64) {
+        self.sync_ages();
+        self.generation = generation;
+        self.ages_generation = generation;
+        self.restart_tiles();
+    }
+
+    /// Returns an order-independent hash of the live cells, ignoring history.
+    ///
+    /// Each cell is mixed into a 64-bit value and the results are summed, so equal boards always
+    /// hash equally regardless of `HashSet` iteration order. Distinct boards can collide, so a
+    /// matching hash should be confirmed with `==` when exactness matters.
+    pub fn state_hash(&self) -> u64 {
+        self.hash_translated(0, 0)
+    }
+
+    /// Returns `state_hash` as a fingerprint that is stable across runs, platforms, and crate
+    /// versions, so it can be stored or compared between processes.
+    ///
+    /// Keeping one fingerprint per generation is enough to spot likely repeats in long runs without
+    /// cloning boards. Rules, topology, ages, and history are not included.
+    pub fn fingerprint(&self) -> u64 {
+        self.state_hash()
+    }
+
+    /// Returns a fingerprint that ignores position: boards holding the same cells shifted by any
+    /// offset share it. Useful for deduplicating soup results and spotting spaceships.
+    pub fn canonical_fingerprint(&self) -> u64 {
+        let cells = self.state.iter().chain(self.decay.iter().flatten());
+        let (min_x, min_y) = cells.fold((i32::MAX, i32::MAX), |(mx, my), &(x, y)| {
+            (mx.min(x), my.min(y))
+        });
+        self.hash_translated(min_x, min_y)
+    }
+
+    /// Sums the cell hashes with `(dx, dy)` subtracted from every coordinate. Decaying cells are
+    /// rotated by their state so they never cancel out a live cell.
+    fn hash_translated(&self, dx: i32, dy: i32) -> u64 {
+        let mix = |x: i32, y: i32| Self::mix_cell(x.wrapping_sub(dx), y.wrapping_sub(dy));
+        let live = self
+            .state
+            .iter()
+            .fold(0u64, |hash, &(x, y)| hash.wrapping_add(mix(x, y)));
+        self.decay
+            .iter()
+            .zip(2u32..)
+            .fold(live, |hash, (layer, state)| {
+                layer.iter().fold(hash, |hash, &(x, y)| {
+                    hash.wrapping_add(mix(x, y).rotate_left(state))
+                })
+            })
+    }
+
+    /// SplitMix64 finalizer over the packed coordinates, giving well-spread per-cell hashes.
+    fn mix_cell(x: i32, y: i32) -> u64 {
+        let mut z =
+            (((x as u32) as u64) << 32 | (y as u32) as u64).wrapping_add(0x9e37_79b9_7f4a_7c15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns the phase of a `period`-generation oscillator with the smallest canonical form.
+    ///
+    /// Each phase is translated so its live cells start at `(0, 0)` and compared as a sorted cell
+    /// list, so every phase of the same oscillator canonicalizes to the same board.
+    pub fn canonical_phase(&self, period: usize) -> GameOfLife {
+        let mut phase = self.clone();
+        phase.history.clear();
+
+        let mut best = Self::canonical_cells(&phase.state);
+        for _ in 1..period {
+            phase.tick();
+            let cells = Self::canonical_cells(&phase.state);
+            if cells < best {
+                best = cells;
+            }
+        }
+
+        phase.history.clear();
+        phase.invalidate();
+        // A phase wider than the positive half of the plane loses the cells that do not fit.
+        phase.state = best
+            .into_iter()
+            .filter_map(|(x, y)| Some((i32::try_from(x).ok()?, i32::try_from(y).ok()?)))
+            .collect();
+        phase
+    }
+
+    /// Sorted live cells translated so the minimum x and y coordinates are zero, widened so that
+    /// phases spanning the whole plane do not overflow.
+    fn canonical_cells(state: &HashSet<(i32, i32)>) -> Vec<(i64, i64)> {
+        let min_x = state.iter().map(|&(x, _)| x).min().unwrap_or(0);
+        let min_y = state.iter().map(|&(_, y)| y).min().unwrap_or(0);
+        let mut cells: Vec<(i64, i64)> = state
+            .iter()
+            .map(|&(x, y)| {
+                (
+                    i64::from(x) - i64::from(min_x),
+                    i64::from(y) - i64::from(min_y),
+                )
+            })
+            .collect();
+        cells.sort_unstable();
+        cells
+    }
+
+    /// Encodes the live cells for use as a URL fragment, e.g. `window.location.hash`.
+    pub fn to_url_hash(&self) -> String {
+        self.to_base64()
+    }
+
+    /// Decodes a board produced by `to_url_hash`, accepting an optional leading `#`.
+    pub fn from_url_hash(hash: &str) -> Result<GameOfLife, DecodeError> {
+        Self::from_base64(hash.strip_prefix('#').unwrap_or(hash))
+    }
+
     /// Serializes a `width` by `height` viewport starting at the origin into a flat buffer of 0s and 1s.
     pub fn cells(&self, width: i32, height: i32) -> Vec<u8> {
         self.cells_at(width, height, 0, 0)
@@ -61,66 +483,388 @@ impl GameOfLife {
     /// Serializes a `width` by `height` viewport starting at `(origin_x, origin_y)`.
     pub fn cells_at(&self, width: i32, height: i32, origin_x: i32, origin_y: i32) -> Vec<u8> {
         assert!(width >= 0 && height >= 0, "width and height must be non-negative");
-        let mut cells = vec![0u8; (width * height) as usize];
+        let Some(cache) = &self.cells_cache else {
+            return self.scan_cells(width, height, origin_x, origin_y);
+        };
+
+        let key = (width, height, origin_x, origin_y, self.state_hash());
+        let mut entry = cache.entry();
+        match &*entry {
+            Some((cached_key, cells)) if *cached_key == key => cells.clone(),
+            _ => {
+                let cells = self.scan_cells(width, height, origin_x, origin_y);
+                *entry = Some((key, cells.clone()));
+                cells
+            }
+        }
+    }
+
+    /// Enables or disables memoizing the most recent `cells_at` buffer while the board is unchanged.
+    pub fn set_cells_cache(&mut self, enabled: bool) {
+        self.cells_cache = enabled.then(CellsCache::default);
+    }
+
+    /// Returns whether a memoized `cells_at` buffer is currently held.
+    pub fn has_cached_cells(&self) -> bool {
+        self.cells_cache
+            .as_ref()
+            .is_some_and(|cache| cache.entry().is_some())
+    }
+
+    /// Returns how many consecutive generations the cell at `(x, y)` has survived, or `None` if it
+    /// is dead. Cells born or placed this generation are age 0.
+    pub fn age(&self, x: i32, y: i32) -> Option<u32> {
+        self.topology
+            .normalize(x, y)
+            .filter(|cell| self.state.contains(cell))
+            .map(|cell| self.age_of(cell))
+    }
+
+    /// Overwrites a `width` by `height` viewport with row-major bytes in the `cells_at` layout, as
+    /// one undoable edit. Nonzero bytes bring cells to life, ending any decay; zero bytes kill live
+    /// cells but leave decaying ones, which `cells_at` reads as 0, to finish decaying. Cells past
+    /// the edge of the `i32` plane are skipped.
+    pub fn write_cells_at(
+        &mut self,
+        width: i32,
+        height: i32,
+        origin_x: i32,
+        origin_y: i32,
+        cells: &[u8],
+    ) {
+        assert!(
+            width >= 0 && height >= 0,
+            "width and height must be non-negative"
+        );
+        assert_eq!(
+            cells.len() as u64,
+            width as u64 * height as u64,
+            "expected width * height cells"
+        );
+        let changes: Vec<((i32, i32), bool)> = cells
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, &byte)| {
+                let (dx, dy) = (idx % width as usize, idx / width as usize);
+                let cell = self.topology.normalize(
+                    origin_x.checked_add(dx as i32)?,
+                    origin_y.checked_add(dy as i32)?,
+                )?;
+                let alive = byte != 0;
+                (self.state.contains(&cell) != alive).then_some((cell, alive))
+            })
+            .collect();
+        if changes.is_empty() {
+            return;
+        }
+
+        self.snapshot();
+        for (cell, alive) in changes {
+            if alive {
+                self.place(cell.0, cell.1);
+            } else {
+                self.kill(cell);
+            }
+        }
+    }
+
+    /// Like `cells_at`, but holds each cell's `cell_state`, so decaying cells read as 2 and up.
+    pub fn states_at(&self, width: i32, height: i32, origin_x: i32, origin_y: i32) -> Vec<u8> {
+        assert!(
+            width >= 0 && height >= 0,
+            "width and height must be non-negative"
+        );
+        let mut states = Vec::with_capacity((width * height) as usize);
         for y in 0..height {
             for x in 0..width {
-                let idx = (y * width + x) as usize;
-                cells[idx] = self.get(origin_x + x, origin_y + y) as u8;
+                states.push(self.cell_state(origin_x + x, origin_y + y));
             }
         }
+        states
+    }
+
+    /// Like `cells_at`, but holds `age + 1` for live cells and 0 for dead ones, so newborn cells
+    /// still read as 1.
+    pub fn ages_at(&self, width: i32, height: i32, origin_x: i32, origin_y: i32) -> Vec<u32> {
+        assert!(
+            width >= 0 && height >= 0,
+            "width and height must be non-negative"
+        );
+        let mut ages = Vec::with_capacity((width * height) as usize);
+        for y in 0..height {
+            for x in 0..width {
+                ages.push(
+                    self.age(origin_x + x, origin_y + y)
+                        .map_or(0, |age| age.saturating_add(1)),
+                );
+            }
+        }
+        ages
+    }
+
+    fn scan_cells(&self, width: i32, height: i32, origin_x: i32, origin_y: i32) -> Vec<u8> {
+        let mut cells = Vec::new();
+        self.scan_cells_into(&mut cells, width, height, origin_x, origin_y);
         cells
     }
 
-    fn get_neighbors(x: i32, y: i32) -> Vec<(i32, i32)> {
-        let mut neighbors = Vec::with_capacity(8);
-        for dy in -1..=1 {
-            for dx in -1..=1 {
-                if dx == 0 && dy == 0 {
-                    continue;
-                }
-                neighbors.push((x + dx, y + dy));
+    /// Overwrites `cells` with a row-major viewport, keeping its capacity.
+    fn scan_cells_into(
+        &self,
+        cells: &mut Vec<u8>,
+        width: i32,
+        height: i32,
+        origin_x: i32,
+        origin_y: i32,
+    ) {
+        cells.clear();
+        cells.reserve((width * height) as usize);
+        for y in 0..height {
+            for x in 0..width {
+                cells.push(self.get(origin_x + x, origin_y + y) as u8);
+            }
+        }
+    }
+
+    /// Returns the on-board cells in the neighborhood of `(x, y)` under the rule there, wrapping on
+    /// a torus. The infinite plane ends at the `i32` bounds, so cells there have fewer neighbors.
+    fn get_neighbors(&self, x: i32, y: i32) -> Vec<(i32, i32)> {
+        self.cells_around(x, y, self.rule_at(x, y).neighborhood())
+    }
+
+    /// Returns the on-board cells in `neighborhood` around `(x, y)`, as `get_neighbors` does.
+    fn cells_around(&self, x: i32, y: i32, neighborhood: Neighborhood) -> Vec<(i32, i32)> {
+        let mut neighbors = Vec::with_capacity(usize::from(neighborhood.size()));
+        for (dx, dy) in neighborhood.offsets() {
+            let (Some(nx), Some(ny)) = (x.checked_add(dx), y.checked_add(dy)) else {
+                continue;
+            };
+            if let Some(cell) = self.topology.normalize(nx, ny) {
+                neighbors.push(cell);
             }
         }
         neighbors
     }
 
+    /// Returns a neighborhood covering every rule on the board, so that it holds every cell whose
+    /// fate a change at its center can affect.
+    fn reach(&self) -> Neighborhood {
+        self.rule_regions
+            .iter()
+            .fold(self.rule.neighborhood(), |reach, region| {
+                reach.union(region.rule.neighborhood())
+            })
+    }
+
     /// Advances the simulation one generation in place.
+    ///
+    /// Once a tick leaves the board unchanged, later ticks only bump the generation counter
+    /// (without recording undo snapshots) until the board or its rules are edited.
     pub fn tick(&mut self) {
-        self.snapshot();
-        // Start with the union of all neighbors of live cells to avoid scanning an infinite grid.
-        let neighbors: HashSet<(i32, i32)> = self
-            .state
+        self.advance(1);
+    }
+
+    /// Advances the simulation `generations` steps as a single undoable change.
+    ///
+    /// Only one snapshot is recorded, and the remaining steps are skipped outright once the board
+    /// settles into a still life.
+    pub fn advance(&mut self, generations: u64) {
+        if generations == 0 {
+            return;
+        }
+
+        if self.stable {
+            self.redo.clear();
+        } else {
+            self.snapshot();
+        }
+        for remaining in (1..=generations).rev() {
+            if self.stable {
+                self.last_changes = (0, 0);
+                self.last_culled = 0;
+                self.generation += remaining;
+                if !self.observers.is_empty() {
+                    self.notify(None, &[], false);
+                }
+                return;
+            }
+            self.step();
+        }
+    }
+
+    /// Computes the next generation without recording history.
+    ///
+    /// A cell can only change if something in its neighborhood changed last generation, so after
+    /// an unedited step only the neighborhoods of the changed cells are re-evaluated.
+    pub(crate) fn step(&mut self) {
+        let after_edit = self.changed.is_none();
+        let candidates = match self.changed.take() {
+            Some(changed) => self.neighborhoods(changed),
+            None => self.candidates(),
+        };
+        let flips = self.evaluate(candidates);
+        let (mut births, deaths): (Vec<_>, Vec<_>) =
+            flips.iter().partition(|&&cell| !self.state.contains(&cell));
+        let culled = self.cull(&mut births, &deaths, after_edit);
+        let born: Vec<_> = match self.color_mode {
+            ColorMode::Mono => Vec::new(),
+            _ => births
+                .iter()
+                .map(|&cell| (cell, self.birth_color(cell)))
+                .collect(),
+        };
+        let mut next = self.state.clone();
+        for cell in deaths.iter().chain(&culled) {
+            next.remove(cell);
+        }
+        next.extend(births.iter().copied());
+        // Decaying cells move on every step, so they count as changed along with the flips.
+        // Culled cells count too, so they are culled again if their neighborhood stays the same.
+        let mut changed = flips;
+        changed.extend(self.decay.iter().flatten().copied());
+        changed.extend(culled.iter().copied());
+        let was_settled = self.decay.is_empty();
+        self.step_decay(&deaths);
+        self.recolor(&deaths, born);
+        self.record_populations(self.state.len(), next.len());
+        self.last_changes = (births.len(), deaths.len());
+        self.last_culled = culled.len();
+        let changes = self
+            .observers
+            .wants_cells()
+            .then(|| BoardDiff::from_changes(births, deaths));
+
+        self.ages = next
             .iter()
-            .copied()
-            .flat_map(|(x, y)| Self::get_neighbors(x, y))
+            .filter(|&&cell| self.state.contains(&cell))
+            .map(|&cell| (cell, self.age_of(cell).saturating_add(1)))
             .collect();
+        self.ages_generation = self.generation + 1;
+
+        self.stable = was_settled && self.decay.is_empty() && changed.is_empty();
+        if !self.stable {
+            self.forget_derived();
+        }
+        self.record_tiles(&changed);
+        self.changed = Some(changed);
+        self.state = next;
+        self.generation += 1;
+        if !self.observers.is_empty() {
+            self.notify(changes, &culled, self.stable);
+        }
+    }
+
+    /// Returns the generation, population, last tick's births/deaths/culls, and population extremes.
+    pub fn stats(&self) -> Statistics {
+        let population = self.state.len();
+        let (min_population, max_population) =
+            self.population_range.unwrap_or((population, population));
+        let (births, deaths) = self.last_changes;
+        Statistics {
+            generation: self.generation,
+            population,
+            births,
+            deaths,
+            culled: self.last_culled,
+            min_population,
+            max_population,
+        }
+    }
 
-        self.state = neighbors
+    fn record_populations(&mut self, before: usize, after: usize) {
+        let (min, max) = self.population_range.unwrap_or((before, before));
+        self.population_range = Some((min.min(before).min(after), max.max(before).max(after)));
+    }
+
+    /// Returns the candidates that are born or die next generation.
+    #[cfg(not(feature = "rayon"))]
+    fn evaluate(&self, candidates: HashSet<(i32, i32)>) -> HashSet<(i32, i32)> {
+        candidates
+            .into_iter()
+            .filter(|&cell| self.flips(cell))
+            .collect()
+    }
+
+    /// Returns the candidates that are born or die next generation, splitting large boards across
+    /// rayon's thread pool. Each cell's fate depends only on the previous generation, so the result
+    /// is identical to the sequential path.
+    #[cfg(feature = "rayon")]
+    fn evaluate(&self, candidates: HashSet<(i32, i32)>) -> HashSet<(i32, i32)> {
+        use rayon::prelude::*;
+
+        if candidates.len() < PARALLEL_THRESHOLD {
+            return candidates
+                .into_iter()
+                .filter(|&cell| self.flips(cell))
+                .collect();
+        }
+        candidates
+            .into_par_iter()
+            .filter(|&cell| self.flips(cell))
+            .collect()
+    }
+
+    /// Returns whether a normalized cell is born or dies next generation.
+    fn flips(&self, (x, y): (i32, i32)) -> bool {
+        self.next_state(x, y) != self.state.contains(&(x, y))
+    }
+
+    /// Returns how many cells in the neighborhood of `(x, y)` are alive, counting the 8 surrounding
+    /// cells unless the rule there uses another `Neighborhood`.
+    pub fn live_neighbors(&self, x: i32, y: i32) -> u8 {
+        self.count_live(x, y, self.rule_at(x, y).neighborhood())
+    }
+
+    fn count_live(&self, x: i32, y: i32, neighborhood: Neighborhood) -> u8 {
+        self.cells_around(x, y, neighborhood)
             .iter()
-            .copied()
-            .filter(|(x, y)| {
-                let live_neighbor_count = Self::get_neighbors(*x, *y)
-                    .iter()
-                    .filter(|(nx, ny)| self.get(*nx, *ny))
-                    .count();
-
-                match (live_neighbor_count, self.get(*x, *y)) {
-                    (0..=1, true) => false,
-                    (2..=3, true) => true,
-                    (4.., true) => false,
-                    (3, false) => true,
-                    (_, false) => false,
-                }
-            })
-            .collect();
+            .filter(|(nx, ny)| self.get(*nx, *ny))
+            .count() as u8
     }
 
-    /// Captures the current board into the undo stack, trimming to the latest 255 entries.
+    /// Returns whether the cell at `(x, y)` will be alive after the next `tick()`.
+    ///
+    /// Decaying cells are never reborn, whatever their neighbors.
+    pub fn next_state(&self, x: i32, y: i32) -> bool {
+        let alive = self.get(x, y);
+        if !alive && self.cell_state(x, y) != 0 {
+            return false;
+        }
+        let rule = self.rule_at(x, y);
+        rule.next_state(alive, self.count_live(x, y, rule.neighborhood()))
+    }
+
+    /// Captures the current board into the undo stack and discards any redo states.
     fn snapshot(&mut self) {
-        if self.history.len() == HISTORY_LIMIT {
+        self.redo.clear();
+        if self.history_limit == 0 {
+            return;
+        }
+        if self.history.len() == self.history_limit {
             self.history.pop_front();
         }
-        self.history.push_back(self.state.clone());
+        self.history.push_back(self.capture());
+    }
+
+    fn capture(&self) -> Snapshot {
+        Snapshot {
+            state: self.state.clone(),
+            decay: self.decay.clone(),
+            colors: self.colors.clone(),
+            ages: self.current_ages(),
+            generation: self.generation,
+        }
+    }
+
+    fn restore(&mut self, snapshot: Snapshot) {
+        self.invalidate();
+        self.state = snapshot.state;
+        self.decay = snapshot.decay;
+        self.colors = snapshot.colors;
+        self.ages = snapshot.ages;
+        self.generation = snapshot.generation;
+        self.ages_generation = snapshot.generation;
+        self.restart_tiles();
     }
 
     /// Returns whether there is a buffered state to revert to.
@@ -131,74 +875,288 @@ impl GameOfLife {
     /// Restores the most recent snapshot, returning `true` if one existed.
     pub fn undo(&mut self) -> bool {
         if let Some(previous) = self.history.pop_back() {
-            self.state = previous;
+            self.redo.push(self.capture());
+            self.restore(previous);
             true
         } else {
             false
         }
     }
 
-}
+    /// Returns whether an undone state can be reapplied.
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
 
-impl fmt::Display for GameOfLife {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        Viewport::new(0..=5, 0..=5).render(self).fmt(f)
+    /// Reapplies the most recently undone state, returning `true` if one existed.
+    ///
+    /// Redo states are discarded as soon as the board is ticked or edited.
+    pub fn redo(&mut self) -> bool {
+        if let Some(next) = self.redo.pop() {
+            self.history.push_back(self.capture());
+            self.restore(next);
+            true
+        } else {
+            false
+        }
     }
-}
 
-/// Stores reusable viewing bounds for rendering `GameOfLife` states.
-#[derive(Clone, Debug)]
-pub struct Viewport {
-    x_range: RangeInclusive<i32>,
-    y_range: RangeInclusive<i32>,
-}
+    /// Returns how many snapshots `undo` can step back through.
+    pub fn history_len(&self) -> usize {
+        self.history.len()
+    }
 
-impl Viewport {
-    pub fn new(x_range: RangeInclusive<i32>, y_range: RangeInclusive<i32>) -> Self {
-        Self { x_range, y_range }
+    /// Returns the maximum number of undo snapshots kept (255 by default).
+    pub fn history_limit(&self) -> usize {
+        self.history_limit
     }
 
-    pub fn render<'a>(&'a self, game: &'a GameOfLife) -> ViewportRender<'a> {
-        ViewportRender {
-            viewport: self,
-            game,
+    /// Changes how many undo snapshots are kept, dropping the oldest ones beyond `limit`.
+    pub fn set_history_limit(&mut self, limit: usize) {
+        self.history_limit = limit;
+        while self.history.len() > limit {
+            self.history.pop_front();
         }
     }
-}
 
-pub struct ViewportRender<'a> {
-    viewport: &'a Viewport,
-    game: &'a GameOfLife,
+    /// Discards every undo and redo snapshot, keeping the current board.
+    pub fn clear_history(&mut self) {
+        self.history.clear();
+        self.redo.clear();
+    }
 }
 
-impl<'a> ViewportRender<'a> {
-    fn ordered_bounds(range: &RangeInclusive<i32>) -> (i32, i32) {
-        let start = *range.start();
-        let end = *range.end();
-        if start <= end {
-            (start, end)
-        } else {
-            (end, start)
+impl GameOfLife {
+    /// Creates an empty board that evolves under `rule` instead of Conway's B3/S23.
+    pub fn with_rule(rule: Rule) -> Self {
+        Self {
+            rule,
+            ..Self::default()
         }
     }
-}
 
-impl<'a> fmt::Display for ViewportRender<'a> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let (x_min, x_max) = Self::ordered_bounds(&self.viewport.x_range);
-        let (y_min, y_max) = Self::ordered_bounds(&self.viewport.y_range);
-
-        for y in (y_min..=y_max).rev() {
-            for x in x_min..=x_max {
-                if self.game.get(x, y) {
-                    write!(f, "◼")?;
-                } else {
-                    write!(f, "◻")?;
-                }
+    /// Returns the rule applied outside any rule regions.
+    pub fn rule(&self) -> Rule {
+        self.rule
+    }
+
+    /// Replaces the rule applied outside any rule regions.
+    pub fn set_rule(&mut self, rule: Rule) {
+        self.invalidate();
+        self.rule = rule;
+    }
+
+    /// Evolves cells inside the given ranges with `rule` instead of the board's rule.
+    ///
+    /// A cell belongs to a region when its own coordinates fall within both inclusive ranges,
+    /// so cells on the boundary use the region's rule. Neighbor counts always see across region
+    /// edges. When regions overlap, the most recently added one wins.
+    pub fn add_rule_region(
+        &mut self,
+        x_range: RangeInclusive<i32>,
+        y_range: RangeInclusive<i32>,
+        rule: Rule,
+    ) {
+        self.invalidate();
+        self.rule_regions.push(RuleRegion {
+            x_range,
+            y_range,
+            rule,
+        });
+    }
+
+    /// Iterates over the live cells in no particular order.
+    pub fn iter_live(&self) -> impl Iterator<Item = (i32, i32)> + '_ {
+        self.state.iter().copied()
+    }
+
+    /// Encodes the live cells as URL-safe base64 of sorted, zigzag-varint coordinate pairs.
+    ///
+    /// Only live cells are stored; rules, history, and the generation counter are not.
+    pub fn to_base64(&self) -> String {
+        let mut cells: Vec<(i32, i32)> = self.state.iter().copied().collect();
+        cells.sort_unstable();
+
+        let mut bytes = Vec::with_capacity(cells.len() * 2);
+        for (x, y) in cells {
+            encoding::write_varint(&mut bytes, x);
+            encoding::write_varint(&mut bytes, y);
+        }
+        encoding::encode_base64(&bytes)
+    }
+
+    /// Decodes a board produced by `to_base64`.
+    pub fn from_base64(text: &str) -> Result<Self, DecodeError> {
+        let mut bytes = encoding::decode_base64(text)?.into_iter().peekable();
+        let mut game = Self::new();
+        while bytes.peek().is_some() {
+            let x = encoding::read_varint(&mut bytes)?;
+            let y = encoding::read_varint(&mut bytes)?;
+            game.place(x, y);
+        }
+        Ok(game)
+    }
+
+    /// Returns every live cell and its neighbors, sorted: the cells the next `tick()` evaluates
+    /// after an edit. Later ticks narrow this to the neighborhoods of cells that just changed.
+    pub fn tick_candidates(&self) -> Vec<(i32, i32)> {
+        let mut candidates: Vec<(i32, i32)> = self.candidates().into_iter().collect();
+        candidates.sort_unstable();
+        candidates
+    }
+
+    /// Unions live cells with their neighbors to avoid scanning an infinite grid.
+    fn candidates(&self) -> HashSet<(i32, i32)> {
+        self.neighborhoods(self.state.iter().copied())
+    }
+
+    /// Unions `cells` with the cells they neighbor under any rule on the board.
+    fn neighborhoods(&self, cells: impl IntoIterator<Item = (i32, i32)>) -> HashSet<(i32, i32)> {
+        let reach = self.reach();
+        cells
+            .into_iter()
+            .flat_map(|(x, y)| self.cells_around(x, y, reach).into_iter().chain([(x, y)]))
+            .collect()
+    }
+
+    /// Inserts a live cell without recording an undo snapshot, for loaders building a fresh board.
+    pub(crate) fn place(&mut self, x: i32, y: i32) {
+        if let Some(cell) = self.topology.normalize(x, y) {
+            self.invalidate();
+            self.remove_decaying(cell);
+            if !self.colors.is_empty() {
+                self.set_color_of(cell, 0);
             }
-            writeln!(f)?;
+            self.state.insert(cell);
         }
+    }
+
+    /// Removes a normalized live or decaying cell without recording an undo snapshot.
+    pub(crate) fn kill(&mut self, cell: (i32, i32)) {
+        self.invalidate();
+        self.state.remove(&cell);
+        self.remove_decaying(cell);
+        self.ages.remove(&cell);
+    }
+
+    /// State of a normalized cell, as returned by `cell_state`.
+    pub(crate) fn cell_state_of(&self, cell: (i32, i32)) -> u8 {
+        if self.state.contains(&cell) {
+            return 1;
+        }
+        self.decay
+            .iter()
+            .position(|layer| layer.contains(&cell))
+            .map_or(0, |index| index as u8 + 2)
+    }
+
+    /// Puts a normalized cell into decay state `state`, which must be at least 2.
+    pub(crate) fn insert_decaying(&mut self, cell: (i32, i32), state: u8) {
+        let index = usize::from(state) - 2;
+        if self.decay.len() <= index {
+            self.decay.resize_with(index + 1, HashSet::new);
+        }
+        self.decay[index].insert(cell);
+    }
+
+    /// Removes a normalized cell from whichever decay state holds it.
+    pub(crate) fn remove_decaying(&mut self, cell: (i32, i32)) -> Option<u8> {
+        let index = self
+            .decay
+            .iter_mut()
+            .position(|layer| layer.remove(&cell))?;
+        self.trim_decay();
+        Some(index as u8 + 2)
+    }
+
+    fn trim_decay(&mut self) {
+        while self.decay.last().is_some_and(HashSet::is_empty) {
+            self.decay.pop();
+        }
+    }
 
-        Ok(())
+    /// Moves every decaying cell one state on and starts this step's `deaths` decaying. Cells leave
+    /// once they pass their rule's last state.
+    fn step_decay(&mut self, deaths: &[(i32, i32)]) {
+        let dying: HashSet<(i32, i32)> = deaths
+            .iter()
+            .filter(|&&(x, y)| self.rule_at(x, y).states() > 2)
+            .copied()
+            .collect();
+        if dying.is_empty() && self.decay.is_empty() {
+            return;
+        }
+        self.decay.insert(0, dying);
+        if self.rule_regions.is_empty() {
+            self.decay
+                .truncate(usize::from(self.rule.states()).saturating_sub(2));
+        } else {
+            let (regions, rule) = (&self.rule_regions, self.rule);
+            for (layer, state) in self.decay.iter_mut().zip(2u8..) {
+                layer.retain(|&(x, y)| state < RuleRegion::rule_at(regions, rule, x, y).states());
+            }
+        }
+        self.trim_decay();
+    }
+
+    /// Age of a live, normalized cell.
+    pub(crate) fn age_of(&self, cell: (i32, i32)) -> u32 {
+        let stored = self.ages.get(&cell).copied().unwrap_or(0);
+        stored.saturating_add(self.pending_ages())
+    }
+
+    /// Generations every live cell has aged since `ages` was last updated.
+    fn pending_ages(&self) -> u32 {
+        if self.stable {
+            u32::try_from(self.generation - self.ages_generation).unwrap_or(u32::MAX)
+        } else {
+            0
+        }
+    }
+
+    /// Every live cell's up-to-date age, omitting newborns.
+    fn current_ages(&self) -> HashMap<(i32, i32), u32> {
+        if self.pending_ages() == 0 {
+            return self.ages.clone();
+        }
+        self.state
+            .iter()
+            .map(|&cell| (cell, self.age_of(cell)))
+            .collect()
+    }
+
+    /// Folds the ages accumulated by skipped stable ticks into `ages`.
+    fn sync_ages(&mut self) {
+        if self.pending_ages() > 0 {
+            self.ages = self.current_ages();
+        }
+        self.ages_generation = self.generation;
+    }
+
+    /// Drops derived state that assumes the board and rules are unchanged, after an edit.
+    fn invalidate(&mut self) {
+        self.forget_derived();
+        self.restart_tiles();
+    }
+
+    /// Drops caches that assume the board is unchanged, after an edit or a step.
+    fn forget_derived(&mut self) {
+        self.sync_ages();
+        self.stable = false;
+        self.changed = None;
+        if let Some(cache) = &self.cells_cache {
+            *cache.entry() = None;
+        }
+    }
+
+    /// Returns the rule that governs the cell at `(x, y)`.
+    fn rule_at(&self, x: i32, y: i32) -> Rule {
+        RuleRegion::rule_at(&self.rule_regions, self.rule, x, y)
+    }
+}
+
+impl fmt::Display for GameOfLife {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Viewport::new(0..=5, 0..=5).render(self).fmt(f)
     }
 }
@@ -0,0 +1,540 @@
+use alloc::{vec, vec::Vec};
+use hashbrown::HashMap;
+
+use crate::{CoordLimit, Engine, GameOfLife, Rule};
+
+pub(crate) type NodeId = u32;
+
+pub(crate) const DEAD: NodeId = 0;
+pub(crate) const ALIVE: NodeId = 1;
+
+/// Level of the root between jumps: centered on the origin, it covers exactly the `i32` plane.
+const PLANE_LEVEL: u8 = 32;
+
+/// A canonical quadtree node covering a `2^level` square. Leaves (level 0) are single cells.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Node {
+    pub(crate) nw: NodeId,
+    pub(crate) ne: NodeId,
+    pub(crate) sw: NodeId,
+    pub(crate) se: NodeId,
+    pub(crate) level: u8,
+    pub(crate) population: u64,
+}
+
+/// Gosper's HashLife: a memoized quadtree engine that can jump exponentially many generations.
+///
+/// Identical subtrees are stored once and each node caches its future, so patterns with repetitive
+/// structure (guns, breeders, large periodic soups) can be advanced by `2^k` generations in time
+/// roughly proportional to the amount of distinct structure rather than to `2^k`.
+///
+/// HashLife simulates the `i32` plane under a single Life-like rule; the topology and rule regions
+/// of a `GameOfLife` are ignored by `from_game`, but its coordinate limit is kept. As in
+/// `GameOfLife`, nothing lives past the edge of the plane or the limit, so patterns that come
+/// within reach of it are advanced in shorter jumps, down to single generations. Node storage grows
+/// with every distinct subtree ever seen and is only released when the engine is dropped.
+#[derive(Debug, Clone)]
+pub struct HashLife {
+    pub(crate) rule: Rule,
+    nodes: Vec<Node>,
+    pub(crate) index: HashMap<(NodeId, NodeId, NodeId, NodeId), NodeId>,
+    results: HashMap<(NodeId, u8), NodeId>,
+    empty: Vec<NodeId>,
+    pub(crate) root: NodeId,
+    pub(crate) generation: u64,
+    limit: Option<CoordLimit>,
+}
+
+impl Default for HashLife {
+    fn default() -> Self {
+        Self::new(Rule::default())
+    }
+}
+
+impl HashLife {
+    /// Creates an empty universe evolving under `rule`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rule` births cells with zero live neighbors (B0), which would fill the infinite plane,
+    /// is a Generations rule, since nodes only hold live and dead cells, or counts a neighborhood
+    /// other than the 8-cell Moore one.
+    pub fn new(rule: Rule) -> Self {
+        assert!(
+            !rule.next_state(false, 0),
+            "HashLife cannot simulate B0 rules on an infinite plane"
+        );
+        assert_eq!(
+            rule.states(),
+            2,
+            "HashLife cannot simulate Generations rules"
+        );
+        assert!(
+            rule.is_moore(),
+            "HashLife only simulates rules on the 8-cell Moore neighborhood"
+        );
+        let leaf = |population| Node {
+            nw: DEAD,
+            ne: DEAD,
+            sw: DEAD,
+            se: DEAD,
+            level: 0,
+            population,
+        };
+        let mut engine = Self {
+            rule,
+            nodes: vec![leaf(0), leaf(1)],
+            index: HashMap::new(),
+            results: HashMap::new(),
+            empty: vec![DEAD],
+            root: DEAD,
+            generation: 0,
+            limit: None,
+        };
+        engine.root = engine.empty_node(PLANE_LEVEL);
+        engine
+    }
+
+    /// Builds a universe holding the live cells, rule, coordinate limit, and generation of `game`.
+    pub fn from_game(game: &GameOfLife) -> Self {
+        let mut engine = Self::new(game.rule());
+        engine.limit = game.coord_limit();
+        for (x, y) in game.iter_live() {
+            engine.set(x, y);
+        }
+        engine.generation = game.generation();
+        engine
+    }
+
+    /// Builds a `GameOfLife` with this universe's live cells, rule, coordinate limit, and
+    /// generation.
+    pub fn to_game(&self) -> GameOfLife {
+        let mut game = GameOfLife::with_rule(self.rule);
+        game.set_coord_limit(self.limit);
+        for (x, y) in self.live_cells() {
+            game.place(x, y);
+        }
+        game.set_generation(self.generation);
+        game
+    }
+
+    /// Returns the rule the universe evolves under.
+    pub fn rule(&self) -> Rule {
+        self.rule
+    }
+
+    /// Advances the universe by exactly `2^k` generations, in one memoized jump unless the pattern
+    /// could reach the edge of the plane or the coordinate limit on the way. The generation count
+    /// stops at `u64::MAX`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is 64 or more, a jump longer than any `u64` generation count.
+    pub fn advance_power_of_two(&mut self, k: u8) {
+        assert!(k < 64, "HashLife cannot jump 2^{k} generations");
+        self.advance_by(1 << k);
+    }
+
+    /// Advances by `generations` in the longest jumps that keep every cell inside the bounds.
+    fn advance_by(&mut self, mut generations: u64) {
+        while generations > 0 {
+            let k = (generations.ilog2() as u8).min(self.safe_jump());
+            let before = self.root;
+            self.jump(k);
+            generations -= 1 << k;
+            if self.root == before {
+                // The board repeats every 2^k generations, so whole multiples of that change nothing.
+                let skipped = generations - generations % (1 << k);
+                self.generation = self.generation.saturating_add(skipped);
+                generations -= skipped;
+            }
+        }
+    }
+
+    /// Advances `2^k` generations on the unbounded plane, then removes cells outside the bounds.
+    /// This is exact when no cell could reach the outside before the last generation.
+    fn jump(&mut self, k: u8) {
+        // Grow until the pattern sits in the central half and the tree is deep enough for the jump,
+        // then add one more ring so nothing can escape the centered result.
+        while self.level() < k + 2 || !self.is_padded() {
+            self.expand();
+        }
+        self.expand();
+        self.root = self.successor(self.root, k);
+        self.clip();
+        self.generation = self.generation.saturating_add(1 << k);
+    }
+
+    /// Returns the largest `k` for which no live cell can leave the bounds within `2^k`
+    /// generations, since cells spread at most one cell per generation. Single generations are
+    /// always safe, since `jump` removes cells born outside before they have any effect.
+    fn safe_jump(&self) -> u8 {
+        let (lo, hi) = self.bounds();
+        let half = 1i64 << (self.level() - 1);
+        let mut nearest = i64::MAX;
+        self.edge_distance(self.root, -half, -half, (lo, hi), &mut nearest);
+        if nearest <= 0 {
+            0
+        } else {
+            nearest.ilog2() as u8
+        }
+    }
+
+    /// Lowers `nearest` to the distance from any live cell of `id` to the outside of the bounds.
+    fn edge_distance(
+        &self,
+        id: NodeId,
+        left: i64,
+        top: i64,
+        bounds: (i64, i64),
+        nearest: &mut i64,
+    ) {
+        let node = self.node(id);
+        let last = (1i64 << node.level) - 1;
+        let (lo, hi) = bounds;
+        // No cell of the node is closer to the outside than the node's own edges.
+        let closest = (left - lo)
+            .min(hi - (left + last))
+            .min(top - lo)
+            .min(hi - (top + last));
+        if node.population == 0 || closest >= *nearest {
+            return;
+        }
+        if node.level == 0 {
+            *nearest = closest;
+            return;
+        }
+        let half = 1i64 << (node.level - 1);
+        self.edge_distance(node.nw, left, top, bounds, nearest);
+        self.edge_distance(node.ne, left + half, top, bounds, nearest);
+        self.edge_distance(node.sw, left, top + half, bounds, nearest);
+        self.edge_distance(node.se, left + half, top + half, bounds, nearest);
+    }
+
+    /// The smallest and largest coordinate a cell may have on either axis.
+    fn bounds(&self) -> (i64, i64) {
+        let extent = self
+            .limit
+            .map_or(i64::MAX, |limit| i64::from(limit.extent()));
+        (
+            (-extent).max(i64::from(i32::MIN)),
+            extent.min(i64::from(i32::MAX)),
+        )
+    }
+
+    /// Resizes the root to `PLANE_LEVEL` and removes live cells outside the bounds.
+    pub(crate) fn clip(&mut self) {
+        while self.level() > PLANE_LEVEL {
+            self.root = self.center(self.root);
+        }
+        while self.level() < PLANE_LEVEL {
+            self.expand();
+        }
+        if self.limit.is_some() {
+            let half = 1i64 << (PLANE_LEVEL - 1);
+            self.root = self.retain_within(self.root, -half, -half, self.bounds());
+        }
+    }
+
+    /// Returns a copy of `id`, whose top-left cell is at `(left, top)`, without the live cells
+    /// outside the bounds.
+    fn retain_within(&mut self, id: NodeId, left: i64, top: i64, bounds: (i64, i64)) -> NodeId {
+        let node = self.node(id);
+        let last = (1i64 << node.level) - 1;
+        let (lo, hi) = bounds;
+        if node.population == 0
+            || (lo <= left && left + last <= hi && lo <= top && top + last <= hi)
+        {
+            return id;
+        }
+        if left + last < lo || hi < left || top + last < lo || hi < top {
+            return self.empty_node(node.level);
+        }
+        let half = 1i64 << (node.level - 1);
+        let nw = self.retain_within(node.nw, left, top, bounds);
+        let ne = self.retain_within(node.ne, left + half, top, bounds);
+        let sw = self.retain_within(node.sw, left, top + half, bounds);
+        let se = self.retain_within(node.se, left + half, top + half, bounds);
+        self.join(nw, ne, sw, se)
+    }
+
+    /// Returns the number of distinct quadtree nodes currently stored.
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    fn level(&self) -> u8 {
+        self.nodes[self.root as usize].level
+    }
+
+    pub(crate) fn node(&self, id: NodeId) -> Node {
+        self.nodes[id as usize]
+    }
+
+    /// Returns the canonical node with the given quadrants, creating it if needed.
+    pub(crate) fn join(&mut self, nw: NodeId, ne: NodeId, sw: NodeId, se: NodeId) -> NodeId {
+        if let Some(&id) = self.index.get(&(nw, ne, sw, se)) {
+            return id;
+        }
+        let children = [nw, ne, sw, se].map(|id| self.node(id));
+        let id = self.nodes.len() as NodeId;
+        self.nodes.push(Node {
+            nw,
+            ne,
+            sw,
+            se,
+            level: children[0].level + 1,
+            // Deduplicated macrocell files can describe nodes holding more than `u64::MAX` cells.
+            population: children
+                .iter()
+                .fold(0, |sum: u64, child| sum.saturating_add(child.population)),
+        });
+        self.index.insert((nw, ne, sw, se), id);
+        id
+    }
+
+    pub(crate) fn empty_node(&mut self, level: u8) -> NodeId {
+        while self.empty.len() <= level as usize {
+            let below = *self.empty.last().expect("level 0 is always present");
+            let next = self.join(below, below, below, below);
+            self.empty.push(next);
+        }
+        self.empty[level as usize]
+    }
+
+    /// Returns whether every live cell lies in the central half of the root.
+    fn is_padded(&self) -> bool {
+        let root = self.node(self.root);
+        let inner = [
+            self.node(root.nw).se,
+            self.node(root.ne).sw,
+            self.node(root.sw).ne,
+            self.node(root.se).nw,
+        ]
+        .into_iter()
+        .fold(0, |sum: u64, id| sum.saturating_add(self.node(id).population));
+        inner == root.population
+    }
+
+    /// Doubles the root's size, keeping the pattern centered on the origin.
+    fn expand(&mut self) {
+        let root = self.node(self.root);
+        let e = self.empty_node(root.level - 1);
+        let nw = self.join(e, e, e, root.nw);
+        let ne = self.join(e, e, root.ne, e);
+        let sw = self.join(e, root.sw, e, e);
+        let se = self.join(root.se, e, e, e);
+        self.root = self.join(nw, ne, sw, se);
+    }
+
+    /// Half-size node straddling two horizontally adjacent nodes.
+    fn centered_horizontal(&mut self, w: NodeId, e: NodeId) -> NodeId {
+        let (w, e) = (self.node(w), self.node(e));
+        self.join(w.ne, e.nw, w.se, e.sw)
+    }
+
+    /// Half-size node straddling two vertically adjacent nodes.
+    fn centered_vertical(&mut self, n: NodeId, s: NodeId) -> NodeId {
+        let (n, s) = (self.node(n), self.node(s));
+        self.join(n.sw, n.se, s.nw, s.ne)
+    }
+
+    /// Half-size node at the center of `id`.
+    fn center(&mut self, id: NodeId) -> NodeId {
+        let node = self.node(id);
+        let (nw, ne, sw, se) = (
+            self.node(node.nw),
+            self.node(node.ne),
+            self.node(node.sw),
+            self.node(node.se),
+        );
+        self.join(nw.se, ne.sw, sw.ne, se.nw)
+    }
+
+    /// Returns the center half of `id` advanced by `2^step` generations (`step <= level - 2`).
+    fn successor(&mut self, id: NodeId, step: u8) -> NodeId {
+        let node = self.node(id);
+        if node.population == 0 {
+            return self.empty_node(node.level - 1);
+        }
+        if let Some(&result) = self.results.get(&(id, step)) {
+            return result;
+        }
+
+        let result = if node.level == 2 {
+            self.base_successor(id)
+        } else {
+            let n00 = node.nw;
+            let n01 = self.centered_horizontal(node.nw, node.ne);
+            let n02 = node.ne;
+            let n10 = self.centered_vertical(node.nw, node.sw);
+            let n11 = self.center(id);
+            let n12 = self.centered_vertical(node.ne, node.se);
+            let n20 = node.sw;
+            let n21 = self.centered_horizontal(node.sw, node.se);
+            let n22 = node.se;
+            let parts = [n00, n01, n02, n10, n11, n12, n20, n21, n22];
+
+            // At full speed both halves of the jump advance time; otherwise only the second does.
+            let full_speed = step == node.level - 2;
+            let inner_step = if full_speed { step - 1 } else { step };
+            let p = parts.map(|part| {
+                if full_speed {
+                    self.successor(part, inner_step)
+                } else {
+                    self.center(part)
+                }
+            });
+
+            let nw = self.join(p[0], p[1], p[3], p[4]);
+            let ne = self.join(p[1], p[2], p[4], p[5]);
+            let sw = self.join(p[3], p[4], p[6], p[7]);
+            let se = self.join(p[4], p[5], p[7], p[8]);
+            let nw = self.successor(nw, inner_step);
+            let ne = self.successor(ne, inner_step);
+            let sw = self.successor(sw, inner_step);
+            let se = self.successor(se, inner_step);
+            self.join(nw, ne, sw, se)
+        };
+
+        self.results.insert((id, step), result);
+        result
+    }
+
+    /// Brute-forces one generation for the central 2x2 cells of a 4x4 node.
+    fn base_successor(&mut self, id: NodeId) -> NodeId {
+        let mut grid = [[false; 4]; 4];
+        for (y, row) in grid.iter_mut().enumerate() {
+            for (x, cell) in row.iter_mut().enumerate() {
+                *cell = self.cell_in(id, x as u64, y as u64);
+            }
+        }
+
+        let mut next = [DEAD; 4];
+        for (i, (x, y)) in [(1, 1), (2, 1), (1, 2), (2, 2)].into_iter().enumerate() {
+            let count = grid[y - 1..=y + 1]
+                .iter()
+                .flat_map(|row| &row[x - 1..=x + 1])
+                .filter(|&&alive| alive)
+                .count() as u8
+                - grid[y][x] as u8;
+            if self.rule.next_state(grid[y][x], count) {
+                next[i] = ALIVE;
+            }
+        }
+        self.join(next[0], next[1], next[2], next[3])
+    }
+
+    /// Reads a cell relative to the node's top-left corner.
+    pub(crate) fn cell_in(&self, mut id: NodeId, mut x: u64, mut y: u64) -> bool {
+        loop {
+            let node = self.node(id);
+            if node.level == 0 {
+                return id == ALIVE;
+            }
+            let half = 1u64 << (node.level - 1);
+            id = match (x >= half, y >= half) {
+                (false, false) => node.nw,
+                (true, false) => node.ne,
+                (false, true) => node.sw,
+                (true, true) => node.se,
+            };
+            x %= half;
+            y %= half;
+        }
+    }
+
+    /// Returns a copy of `id` with the cell at `(x, y)` (relative to its top-left) replaced.
+    fn with_cell(&mut self, id: NodeId, x: u64, y: u64, alive: bool) -> NodeId {
+        let node = self.node(id);
+        if node.level == 0 {
+            return if alive { ALIVE } else { DEAD };
+        }
+        let half = 1u64 << (node.level - 1);
+        let (east, south) = (x >= half, y >= half);
+        let (x, y) = (x % half, y % half);
+        let (mut nw, mut ne, mut sw, mut se) = (node.nw, node.ne, node.sw, node.se);
+        let quadrant = match (east, south) {
+            (false, false) => &mut nw,
+            (true, false) => &mut ne,
+            (false, true) => &mut sw,
+            (true, true) => &mut se,
+        };
+        *quadrant = self.with_cell(*quadrant, x, y, alive);
+        self.join(nw, ne, sw, se)
+    }
+
+    /// Converts world coordinates to root-relative ones, growing the root until they fit.
+    fn locate(&mut self, x: i32, y: i32) -> (u64, u64) {
+        loop {
+            let half = 1i64 << (self.level() - 1);
+            let (rx, ry) = (x as i64 + half, y as i64 + half);
+            if (0..2 * half).contains(&rx) && (0..2 * half).contains(&ry) {
+                return (rx as u64, ry as u64);
+            }
+            self.expand();
+        }
+    }
+
+    fn set_cell(&mut self, x: i32, y: i32, alive: bool) {
+        let (rx, ry) = self.locate(x, y);
+        self.root = self.with_cell(self.root, rx, ry, alive);
+    }
+
+    fn collect_cells(&self, id: NodeId, left: i64, top: i64, out: &mut Vec<(i32, i32)>) {
+        let node = self.node(id);
+        if node.population == 0 {
+            return;
+        }
+        if node.level == 0 {
+            if let (Ok(x), Ok(y)) = (i32::try_from(left), i32::try_from(top)) {
+                out.push((x, y));
+            }
+            return;
+        }
+        let half = 1i64 << (node.level - 1);
+        self.collect_cells(node.nw, left, top, out);
+        self.collect_cells(node.ne, left + half, top, out);
+        self.collect_cells(node.sw, left, top + half, out);
+        self.collect_cells(node.se, left + half, top + half, out);
+    }
+}
+
+impl Engine for HashLife {
+    fn get(&self, x: i32, y: i32) -> bool {
+        let half = 1i64 << (self.level() - 1);
+        let (rx, ry) = (x as i64 + half, y as i64 + half);
+        (0..2 * half).contains(&rx)
+            && (0..2 * half).contains(&ry)
+            && self.cell_in(self.root, rx as u64, ry as u64)
+    }
+
+    fn set(&mut self, x: i32, y: i32) {
+        self.set_cell(x, y, true);
+    }
+
+    fn unset(&mut self, x: i32, y: i32) {
+        self.set_cell(x, y, false);
+    }
+
+    /// Advances by `generations` in power-of-two jumps.
+    fn advance(&mut self, generations: u64) {
+        self.advance_by(generations);
+    }
+
+    fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    fn population(&self) -> usize {
+        self.node(self.root).population as usize
+    }
+
+    fn live_cells(&self) -> Vec<(i32, i32)> {
+        let half = 1i64 << (self.level() - 1);
+        let mut cells = Vec::with_capacity(self.population());
+        self.collect_cells(self.root, -half, -half, &mut cells);
+        cells.sort_unstable();
+        cells
+    }
+}
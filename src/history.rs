@@ -0,0 +1,88 @@
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+use crate::{GameOfLife, Snapshot};
+
+/// A read-only view of one saved undo state, yielded by `GameOfLife::history`.
+#[derive(Debug, Clone, Copy)]
+pub struct HistoryEntry<'a> {
+    snapshot: &'a Snapshot,
+    game: &'a GameOfLife,
+}
+
+impl<'a> HistoryEntry<'a> {
+    /// Returns the generation the state was saved at.
+    pub fn generation(&self) -> u64 {
+        self.snapshot.generation
+    }
+
+    /// Returns the number of live cells in the saved state.
+    pub fn population(&self) -> usize {
+        self.snapshot.state.len()
+    }
+
+    /// Returns whether the cell at `(x, y)` was alive.
+    pub fn get(&self, x: i32, y: i32) -> bool {
+        self.game
+            .topology
+            .normalize(x, y)
+            .is_some_and(|cell| self.snapshot.state.contains(&cell))
+    }
+
+    /// Iterates the saved live cells in arbitrary order.
+    pub fn iter_live(&self) -> impl Iterator<Item = (i32, i32)> + 'a {
+        self.snapshot.state.iter().copied()
+    }
+
+    /// Builds a board in the saved state, as `undo` would restore it, without any history.
+    ///
+    /// Like `undo`, it keeps the current rule, topology, color mode, rule regions, and coordinate
+    /// limit, which undo states do not record.
+    pub fn to_game(&self) -> GameOfLife {
+        let mut game = GameOfLife {
+            color_mode: self.game.color_mode,
+            history_limit: self.game.history_limit,
+            rule: self.game.rule,
+            topology: self.game.topology,
+            coord_limit: self.game.coord_limit,
+            rule_regions: self.game.rule_regions.clone(),
+            ..GameOfLife::default()
+        };
+        game.restore(self.snapshot.clone());
+        game
+    }
+}
+
+impl GameOfLife {
+    /// Iterates the saved undo states, most recent first, without consuming them.
+    ///
+    /// Entries are whatever `undo` would restore: one per edit and one per `tick` or `advance` call,
+    /// so an `advance(n)` contributes a single entry.
+    pub fn history(&self) -> impl Iterator<Item = HistoryEntry<'_>> {
+        self.history.iter().rev().map(|snapshot| HistoryEntry {
+            snapshot,
+            game: self,
+        })
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+impl GameOfLife {
+    /// Steps back through up to `steps` saved states, returning how many were restored.
+    ///
+    /// Each rewound state moves onto the redo stack, so `fast_forward` can return to it until the
+    /// board is next ticked or edited.
+    pub fn rewind(&mut self, steps: usize) -> usize {
+        (0..steps).take_while(|_| self.undo()).count()
+    }
+
+    /// Reapplies up to `steps` rewound or undone states, returning how many were reapplied.
+    pub fn fast_forward(&mut self, steps: usize) -> usize {
+        (0..steps).take_while(|_| self.redo()).count()
+    }
+
+    /// Returns how many states `fast_forward` can reapply.
+    pub fn redo_len(&self) -> usize {
+        self.redo.len()
+    }
+}
@@ -0,0 +1,440 @@
+//! Census of a settled board: splits the live cells into separate objects and labels each with
+//! its apgcode, the notation soup searches use, such as `xs4_33` for the block or `xq4_153` for
+//! the glider.
+//!
+//! Objects are found by grouping cells that lie within two cells of each other, then splitting
+//! groups whose parts run independently, such as two blocks side by side. Each object is run on
+//! its own, on an unbounded board under the game's rule, until it repeats in place or shifted,
+//! which also gives a spaceship's period and velocity. Only live cells are considered; decaying cells of Generations rules are ignored.
+
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashSet, VecDeque};
+use std::fmt;
+use std::sync::OnceLock;
+
+use crate::catalog::{self, Kind, Speed};
+use crate::{GameOfLife, Pattern, Rule, Transform};
+
+/// Longest period looked for before an object is labelled `PATHOLOGICAL`.
+pub const MAX_PERIOD: u64 = 256;
+
+/// The label apgsearch gives objects that never repeat.
+const PATHOLOGICAL: &str = "PATHOLOGICAL";
+
+/// Digits of the extended Wechsler format; columns use the first 32.
+const DIGITS: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+/// A separate object on the board and what it was identified as.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Object {
+    pattern: Pattern,
+    kind: Kind,
+    period: Option<u64>,
+    displacement: (i32, i32),
+    code: String,
+    name: Option<String>,
+}
+
+impl Object {
+    /// Returns the object's cells where they are on the board.
+    pub fn pattern(&self) -> &Pattern {
+        &self.pattern
+    }
+
+    /// Returns `StillLife`, `Oscillator`, or `Spaceship`, or `Other` if the object never repeats.
+    pub fn kind(&self) -> Kind {
+        self.kind
+    }
+
+    /// Returns the number of generations before the object repeats, if it does.
+    pub fn period(&self) -> Option<u64> {
+        self.period
+    }
+
+    /// Returns how far the object moves each period; `(0, 0)` unless it is a spaceship.
+    pub fn displacement(&self) -> (i32, i32) {
+        self.displacement
+    }
+
+    /// Returns how fast and in which direction a spaceship travels, such as `c/4 diagonal` for the
+    /// glider, or `None` for an object that stays in place.
+    pub fn speed(&self) -> Option<Speed> {
+        let (dx, dy) = self.displacement;
+        let period = self.period?;
+        (self.kind == Kind::Spaceship).then_some(Speed { dx, dy, period })
+    }
+
+    /// Returns the apgcode, such as `xp2_7` for the blinker, or `PATHOLOGICAL`.
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// Returns the name of a built-in pattern with the same apgcode, under Conway's rule only.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns the name if there is one, otherwise the apgcode.
+    pub fn label(&self) -> &str {
+        self.name().unwrap_or(&self.code)
+    }
+}
+
+/// How often each kind of object occurs on a board, keyed by apgcode.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Census {
+    counts: BTreeMap<String, usize>,
+    names: BTreeMap<String, String>,
+}
+
+impl Census {
+    /// Counts `objects` by apgcode.
+    pub fn new<'a>(objects: impl IntoIterator<Item = &'a Object>) -> Self {
+        let mut census = Self::default();
+        for object in objects {
+            *census.counts.entry(object.code.clone()).or_default() += 1;
+            if let Some(name) = &object.name {
+                census.names.insert(object.code.clone(), name.clone());
+            }
+        }
+        census
+    }
+
+    /// Returns how many objects have the apgcode or name `label`, ignoring case for names.
+    pub fn count(&self, label: &str) -> usize {
+        let code = self
+            .names
+            .iter()
+            .find(|(_, name)| name.eq_ignore_ascii_case(label))
+            .map_or(label, |(code, _)| code);
+        self.counts.get(code).copied().unwrap_or(0)
+    }
+
+    /// Returns the total number of objects.
+    pub fn total(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    /// Iterates `(apgcode, count)` pairs, most common first, ties in apgcode order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, usize)> {
+        let mut counts: Vec<(&str, usize)> = self
+            .counts
+            .iter()
+            .map(|(code, &count)| (code.as_str(), count))
+            .collect();
+        counts.sort_by_key(|&(_, count)| Reverse(count));
+        counts.into_iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+}
+
+impl fmt::Display for Census {
+    /// Writes a summary such as `2 blocks, 1 blinker, 1 glider`, using apgcodes for objects
+    /// without a name.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, (code, count)) in self.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            match self.names.get(code) {
+                Some(name) if count == 1 => write!(f, "1 {}", name.to_lowercase())?,
+                Some(name) => write!(f, "{count} {}s", name.to_lowercase())?,
+                None => write!(f, "{count} {code}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Splits the live cells of `game` into objects and identifies each one.
+///
+/// Run the board until it settles first, for example with `GameOfLife::run_until`; objects that
+/// are still interacting are identified as whatever they form together.
+pub fn objects(game: &GameOfLife) -> Vec<Object> {
+    let rule = game.rule();
+    let mut objects: Vec<Object> = clusters(game.iter_live())
+        .into_iter()
+        .flat_map(|cluster| separate(cluster, rule))
+        .map(|cells| identify(cells, rule))
+        .collect();
+    objects.sort_by(|a, b| a.pattern.cells().cmp(b.pattern.cells()));
+    objects
+}
+
+/// Returns the objects on `game` that travel across the board, with their period and
+/// displacement per period.
+pub fn spaceships(game: &GameOfLife) -> Vec<Object> {
+    objects(game)
+        .into_iter()
+        .filter(|object| object.kind == Kind::Spaceship)
+        .collect()
+}
+
+/// Counts the objects on `game` by apgcode.
+pub fn census(game: &GameOfLife) -> Census {
+    Census::new(&objects(game))
+}
+
+/// Returns the apgcode of `pattern` run under `rule`, or `None` if it is empty or does not repeat
+/// within `MAX_PERIOD` generations.
+pub fn apgcode(pattern: &Pattern, rule: Rule) -> Option<String> {
+    evolve(pattern.cells(), rule).map(|evolution| evolution.apgcode())
+}
+
+/// Identifies `cells` as a single object.
+fn identify(cells: Vec<(i32, i32)>, rule: Rule) -> Object {
+    let evolution = evolve(&cells, rule);
+    let (kind, period, displacement, code) = match &evolution {
+        Some(evolution) => (
+            evolution.kind(),
+            Some(evolution.period),
+            evolution.displacement,
+            evolution.apgcode(),
+        ),
+        None => (Kind::Other, None, (0, 0), PATHOLOGICAL.to_string()),
+    };
+    let name = (rule == Rule::conway())
+        .then(|| known_names().get(&code).cloned())
+        .flatten();
+    Object {
+        pattern: Pattern::new(cells),
+        kind,
+        period,
+        displacement,
+        code,
+        name,
+    }
+}
+
+/// Names of the built-in still lifes, oscillators, and spaceships under Conway's rule, by apgcode.
+fn known_names() -> &'static BTreeMap<String, String> {
+    static NAMES: OnceLock<BTreeMap<String, String>> = OnceLock::new();
+    NAMES.get_or_init(|| {
+        catalog::builtin()
+            .entries()
+            .iter()
+            .filter(|entry| {
+                matches!(
+                    entry.kind(),
+                    Kind::StillLife | Kind::Oscillator | Kind::Spaceship
+                )
+            })
+            .filter_map(|entry| {
+                let code = apgcode(entry.pattern(), Rule::conway())?;
+                Some((code, entry.name().to_string()))
+            })
+            .collect()
+    })
+}
+
+/// Groups cells that lie within two cells of each other, so objects whose cells only touch
+/// through an empty cell, like the beacon, stay together.
+fn clusters(cells: impl Iterator<Item = (i32, i32)>) -> Vec<Vec<(i32, i32)>> {
+    components(cells.collect(), 2)
+}
+
+/// Splits `cells` into groups connected through cells at most `reach` apart on either axis.
+fn components(mut remaining: HashSet<(i32, i32)>, reach: i32) -> Vec<Vec<(i32, i32)>> {
+    let mut groups = Vec::new();
+    while let Some(&start) = remaining.iter().next() {
+        remaining.remove(&start);
+        let mut group = vec![start];
+        let mut queue = VecDeque::from([start]);
+        while let Some((x, y)) = queue.pop_front() {
+            for dy in -reach..=reach {
+                for dx in -reach..=reach {
+                    let cell = (x.wrapping_add(dx), y.wrapping_add(dy));
+                    if remaining.remove(&cell) {
+                        group.push(cell);
+                        queue.push_back(cell);
+                    }
+                }
+            }
+        }
+        group.sort_unstable();
+        groups.push(group);
+    }
+    groups
+}
+
+/// Splits a cluster into its touching parts if each part repeats on its own and running them
+/// together gives the same result as running them apart; otherwise keeps it whole.
+fn separate(cluster: Vec<(i32, i32)>, rule: Rule) -> Vec<Vec<(i32, i32)>> {
+    let parts = components(cluster.iter().copied().collect(), 1);
+    if parts.len() < 2 {
+        return vec![cluster];
+    }
+    let Some(periods) = parts
+        .iter()
+        .map(|part| evolve(part, rule).map(|evolution| evolution.period))
+        .collect::<Option<Vec<u64>>>()
+    else {
+        return vec![cluster];
+    };
+    let generations = periods
+        .into_iter()
+        .fold(1, |period, part| period / gcd(period, part) * part)
+        .min(MAX_PERIOD);
+
+    let mut whole = isolated(&cluster, rule);
+    let mut apart: Vec<GameOfLife> = parts.iter().map(|part| isolated(part, rule)).collect();
+    for _ in 0..generations {
+        whole.step();
+        apart.iter_mut().for_each(GameOfLife::step);
+        let mut together: Vec<(i32, i32)> = apart.iter().flat_map(GameOfLife::iter_live).collect();
+        together.sort_unstable();
+        let mut cells: Vec<(i32, i32)> = whole.iter_live().collect();
+        cells.sort_unstable();
+        if cells != together {
+            return vec![cluster];
+        }
+    }
+    parts
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+/// A board holding only `cells`, without history.
+fn isolated(cells: &[(i32, i32)], rule: Rule) -> GameOfLife {
+    let mut game = GameOfLife::with_rule(rule);
+    game.set_history_limit(0);
+    game.set_cells(cells.iter().copied());
+    game
+}
+
+/// Every phase of a repeating object, each shifted so its bounding box starts at `(0, 0)`.
+struct Evolution {
+    period: u64,
+    displacement: (i32, i32),
+    phases: Vec<Vec<(i32, i32)>>,
+}
+
+/// Runs `cells` on their own until they repeat, or returns `None` if they die out or have not
+/// repeated within `MAX_PERIOD` generations.
+fn evolve(cells: &[(i32, i32)], rule: Rule) -> Option<Evolution> {
+    let (start, (x, y)) = normalized(cells.iter().copied())?;
+    let mut game = isolated(cells, rule);
+    let mut phases = vec![start];
+    for period in 1..=MAX_PERIOD {
+        game.step();
+        let (phase, (phase_x, phase_y)) = normalized(game.iter_live())?;
+        if phase == phases[0] {
+            return Some(Evolution {
+                period,
+                displacement: (phase_x - x, phase_y - y),
+                phases,
+            });
+        }
+        phases.push(phase);
+    }
+    None
+}
+
+/// Sorted cells shifted to start at `(0, 0)`, and the shift.
+type Normalized = (Vec<(i32, i32)>, (i32, i32));
+
+/// Sorts `cells` shifted to start at `(0, 0)`, along with the shift, or `None` if there are none.
+fn normalized(cells: impl Iterator<Item = (i32, i32)>) -> Option<Normalized> {
+    let mut cells: Vec<(i32, i32)> = cells.collect();
+    let min_x = cells.iter().map(|&(x, _)| x).min()?;
+    let min_y = cells.iter().map(|&(_, y)| y).min()?;
+    for (x, y) in &mut cells {
+        *x -= min_x;
+        *y -= min_y;
+    }
+    cells.sort_unstable();
+    Some((cells, (min_x, min_y)))
+}
+
+impl Evolution {
+    fn kind(&self) -> Kind {
+        match (self.period, self.displacement) {
+            (_, (dx, dy)) if dx != 0 || dy != 0 => Kind::Spaceship,
+            (1, _) => Kind::StillLife,
+            _ => Kind::Oscillator,
+        }
+    }
+
+    /// Picks the shortest, then alphabetically first, Wechsler encoding over every phase and
+    /// orientation, prefixed with `xs` and the population for still lifes, or `xp`/`xq` and the
+    /// period for oscillators and spaceships.
+    fn apgcode(&self) -> String {
+        let prefix = match self.kind() {
+            Kind::StillLife => format!("xs{}", self.phases[0].len()),
+            Kind::Spaceship => format!("xq{}", self.period),
+            _ => format!("xp{}", self.period),
+        };
+        let body = self
+            .phases
+            .iter()
+            .flat_map(|phase| {
+                Transform::ALL.into_iter().filter_map(|transform| {
+                    let moved = phase.iter().map(|&(x, y)| transform.apply(x, y));
+                    normalized(moved).map(|(cells, _)| wechsler(&cells))
+                })
+            })
+            .min_by(|a, b| a.len().cmp(&b.len()).then_with(|| a.cmp(b)))
+            .unwrap_or_default();
+        format!("{prefix}_{body}")
+    }
+}
+
+/// Encodes normalized cells in extended Wechsler format: strips of five rows separated by `z`,
+/// each column a base-32 digit with its top row as the lowest bit, and runs of zeros shortened
+/// to `w` (two), `x` (three), or `y` and a digit (four to thirty-nine). Trailing zeros in a strip
+/// are dropped.
+fn wechsler(cells: &[(i32, i32)]) -> String {
+    let width = cells.iter().map(|&(x, _)| x + 1).max().unwrap_or(0) as usize;
+    let height = cells.iter().map(|&(_, y)| y + 1).max().unwrap_or(0) as usize;
+    let mut strips = vec![vec![0u8; width]; height.div_ceil(5)];
+    for &(x, y) in cells {
+        strips[y as usize / 5][x as usize] |= 1 << (y % 5);
+    }
+
+    let mut out = String::new();
+    for (index, strip) in strips.iter().enumerate() {
+        if index > 0 {
+            out.push('z');
+        }
+        let end = strip
+            .iter()
+            .rposition(|&column| column != 0)
+            .map_or(0, |end| end + 1);
+        let mut zeros = 0;
+        for &column in &strip[..end] {
+            if column == 0 {
+                zeros += 1;
+                continue;
+            }
+            push_zeros(&mut out, zeros);
+            zeros = 0;
+            out.push(DIGITS[usize::from(column)].into());
+        }
+    }
+    out
+}
+
+fn push_zeros(out: &mut String, mut zeros: usize) {
+    while zeros > 0 {
+        match zeros {
+            1 => out.push('0'),
+            2 => out.push('w'),
+            3 => out.push('x'),
+            _ => {
+                let run = zeros.min(39);
+                out.push('y');
+                out.push(DIGITS[run - 4].into());
+                zeros -= run;
+                continue;
+            }
+        }
+        return;
+    }
+}
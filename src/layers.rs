@@ -0,0 +1,232 @@
+use alloc::{string::String, vec, vec::Vec};
+use core::fmt;
+
+use crate::{GameOfLife, RenderStyle, Viewport};
+
+/// One board in a `Layers` stack, with its own style and settings.
+#[derive(Debug, Clone)]
+pub struct Layer {
+    name: String,
+    game: GameOfLife,
+    style: RenderStyle,
+    visible: bool,
+    paused: bool,
+}
+
+impl Layer {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn game(&self) -> &GameOfLife {
+        &self.game
+    }
+
+    /// Returns the board for editing.
+    pub fn game_mut(&mut self) -> &mut GameOfLife {
+        &mut self.game
+    }
+
+    /// Returns the style the layer's live cells are drawn with.
+    pub fn style(&self) -> &RenderStyle {
+        &self.style
+    }
+
+    pub fn set_style(&mut self, style: RenderStyle) {
+        self.style = style;
+    }
+
+    /// Returns whether the layer is drawn and counted by `cells_at`.
+    pub fn visible(&self) -> bool {
+        self.visible
+    }
+
+    pub fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+
+    /// Returns whether `Layers::tick` skips the layer.
+    pub fn paused(&self) -> bool {
+        self.paused
+    }
+
+    pub fn set_paused(&mut self, paused: bool) {
+        self.paused = paused;
+    }
+}
+
+/// A stack of independent boards that tick together and draw into one viewport, such as a main
+/// board with a scratch layer for prototyping a pattern before merging it in.
+///
+/// Layers never interact while they evolve; each keeps its own rule, topology, and undo history.
+/// They are drawn bottom to top, so where several layers have a live cell the last one pushed
+/// wins, and dead cells take the bottom layer's dead glyph.
+#[derive(Debug, Clone, Default)]
+pub struct Layers {
+    layers: Vec<Layer>,
+}
+
+impl Layers {
+    /// Creates an empty stack.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `game` on top of the stack, drawn with `style`, and returns its index.
+    pub fn push(&mut self, name: impl Into<String>, game: GameOfLife, style: RenderStyle) -> usize {
+        self.layers.push(Layer {
+            name: name.into(),
+            game,
+            style,
+            visible: true,
+            paused: false,
+        });
+        self.layers.len() - 1
+    }
+
+    /// Removes and returns the layer at `index`, shifting the layers above it down.
+    pub fn remove(&mut self, index: usize) -> Option<Layer> {
+        (index < self.layers.len()).then(|| self.layers.remove(index))
+    }
+
+    pub fn len(&self) -> usize {
+        self.layers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&Layer> {
+        self.layers.get(index)
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut Layer> {
+        self.layers.get_mut(index)
+    }
+
+    /// Returns the index of the first layer called `name`.
+    pub fn position(&self, name: &str) -> Option<usize> {
+        self.layers.iter().position(|layer| layer.name == name)
+    }
+
+    /// Iterates the layers from the bottom of the stack up.
+    pub fn iter(&self) -> impl Iterator<Item = &Layer> {
+        self.layers.iter()
+    }
+
+    /// Advances every layer that is not paused by one generation.
+    pub fn tick(&mut self) {
+        self.advance(1);
+    }
+
+    /// Advances every layer that is not paused by `generations` generations.
+    pub fn advance(&mut self, generations: u64) {
+        for layer in self.layers.iter_mut().filter(|layer| !layer.paused) {
+            layer.game.advance(generations);
+        }
+    }
+
+    /// Brings `source`'s live cells to life on `target`, as one undoable edit there, and leaves
+    /// `source` unchanged. Returns false if either index is out of range or they are the same.
+    pub fn merge(&mut self, source: usize, target: usize) -> bool {
+        if source == target || source >= self.len() || target >= self.len() {
+            return false;
+        }
+        let cells: Vec<(i32, i32)> = self.layers[source].game.iter_live().collect();
+        self.layers[target].game.set_cells(cells);
+        true
+    }
+
+    /// Merges `source` into `target` and clears `source`, committing a scratch layer's pattern.
+    pub fn commit(&mut self, source: usize, target: usize) -> bool {
+        let merged = self.merge(source, target);
+        if merged {
+            self.layers[source].game.clear();
+        }
+        merged
+    }
+
+    /// Returns a board holding every visible layer's live cells, under the bottom layer's rule
+    /// and topology.
+    pub fn flatten(&self) -> GameOfLife {
+        let mut layers = self.layers.iter().filter(|layer| layer.visible);
+        let mut game = match layers.next() {
+            Some(bottom) => bottom.game.clone(),
+            None => return GameOfLife::new(),
+        };
+        game.set_cells(layers.flat_map(|layer| layer.game.iter_live()));
+        game.clear_history();
+        game
+    }
+
+    /// Serializes a `width` by `height` region starting at `(origin_x, origin_y)` like
+    /// `GameOfLife::cells_at`, except that each byte is one more than the index of the topmost
+    /// visible layer with a live cell there, or `0` where none has.
+    pub fn cells_at(&self, width: i32, height: i32, origin_x: i32, origin_y: i32) -> Vec<u8> {
+        assert!(
+            width >= 0 && height >= 0,
+            "width and height must be non-negative"
+        );
+        let mut cells = vec![0; width as usize * height as usize];
+        for (index, layer) in self.layers.iter().enumerate() {
+            if !layer.visible {
+                continue;
+            }
+            let id = u8::try_from(index + 1).unwrap_or(u8::MAX);
+            let layer_cells = layer.game.cells_at(width, height, origin_x, origin_y);
+            for (cell, alive) in cells.iter_mut().zip(layer_cells) {
+                if alive == 1 {
+                    *cell = id;
+                }
+            }
+        }
+        cells
+    }
+
+    /// Draws the visible layers through `viewport`, each live glyph in the style of the topmost
+    /// layer alive there.
+    pub fn render<'a>(&'a self, viewport: &'a Viewport) -> LayersRender<'a> {
+        LayersRender {
+            layers: self,
+            viewport,
+        }
+    }
+}
+
+/// Composited text rendering of a `Layers` stack, produced by `Layers::render`.
+///
+/// Each glyph is one cell or block of the viewport, as with `Viewport::render_styled`. Live cells
+/// use their layer's live glyph and color, or the first color of a heat map; packing, decay
+/// glyphs, and the off-board glyph are not applied.
+pub struct LayersRender<'a> {
+    layers: &'a Layers,
+    viewport: &'a Viewport,
+}
+
+impl fmt::Display for LayersRender<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let visible: Vec<&Layer> = self.layers.iter().filter(|layer| layer.visible).collect();
+        let Some(bottom) = visible.first() else {
+            return Ok(());
+        };
+        let grids: Vec<(usize, Vec<bool>)> = visible
+            .iter()
+            .map(|layer| self.viewport.glyph_grid(&layer.game))
+            .collect();
+        let columns = grids[0].0.max(1);
+        for index in 0..grids[0].1.len() {
+            let top = (0..visible.len())
+                .rev()
+                .find(|&layer| grids[layer].1[index]);
+            match top {
+                Some(layer) => visible[layer].style.write_cell(f, Some(0))?,
+                None => bottom.style.write_cell(f, None)?,
+            }
+            if (index + 1) % columns == 0 {
+                writeln!(f)?;
+            }
+        }
+        Ok(())
+    }
+}
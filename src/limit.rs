@@ -0,0 +1,82 @@
+use crate::GameOfLife;
+use alloc::vec::Vec;
+use hashbrown::HashSet;
+
+/// Bounds the cells a board keeps to `-extent..=extent` on both axes.
+///
+/// Patterns that drift far enough would otherwise run into the edge of the `i32` plane, where
+/// cells have no neighbors beyond the last coordinate. With a limit set, cells born outside it are
+/// removed instead and counted in `Statistics::culled` and `Observer::on_cell_culled`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CoordLimit {
+    extent: u32,
+}
+
+impl CoordLimit {
+    /// Keeps cells whose coordinates are both within `extent` of the origin.
+    pub fn new(extent: u32) -> Self {
+        Self { extent }
+    }
+
+    pub fn extent(&self) -> u32 {
+        self.extent
+    }
+
+    /// Returns whether `(x, y)` lies within the limit.
+    pub fn contains(&self, x: i32, y: i32) -> bool {
+        x.unsigned_abs() <= self.extent && y.unsigned_abs() <= self.extent
+    }
+}
+
+impl GameOfLife {
+    /// Returns the coordinate limit, or `None` if cells may reach the edge of the `i32` plane.
+    pub fn coord_limit(&self) -> Option<CoordLimit> {
+        self.coord_limit
+    }
+
+    /// Sets or clears the coordinate limit. Live and decaying cells already outside the new limit
+    /// are removed without being counted as culled.
+    pub fn set_coord_limit(&mut self, limit: Option<CoordLimit>) {
+        self.invalidate();
+        self.coord_limit = limit;
+        let Some(limit) = limit else {
+            return;
+        };
+        self.state.retain(|&(x, y)| limit.contains(x, y));
+        self.ages.retain(|&(x, y), _| limit.contains(x, y));
+        for layer in self.decay.iter_mut().chain(&mut self.colors) {
+            layer.retain(|&(x, y)| limit.contains(x, y));
+        }
+        self.trim_decay();
+    }
+
+    /// Moves births outside the limit from `births` into the returned, sorted list of culled
+    /// cells. After an edit, surviving cells that were placed outside the limit are culled too.
+    pub(crate) fn cull(
+        &self,
+        births: &mut Vec<(i32, i32)>,
+        deaths: &[(i32, i32)],
+        after_edit: bool,
+    ) -> Vec<(i32, i32)> {
+        let Some(limit) = self.coord_limit else {
+            return Vec::new();
+        };
+        let mut culled: Vec<(i32, i32)> = births
+            .iter()
+            .copied()
+            .filter(|&(x, y)| !limit.contains(x, y))
+            .collect();
+        births.retain(|&(x, y)| limit.contains(x, y));
+        if after_edit {
+            let deaths: HashSet<_> = deaths.iter().collect();
+            culled.extend(
+                self.state
+                    .iter()
+                    .filter(|&cell| !limit.contains(cell.0, cell.1) && !deaths.contains(cell)),
+            );
+        }
+        culled.sort_unstable();
+        culled
+    }
+}
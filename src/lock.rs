@@ -0,0 +1,36 @@
+//! The lock boards use for state they update behind `&self`: a `Mutex` with the `std` feature, so
+//! boards stay `Sync`, and a `RefCell` without it.
+
+#[cfg(feature = "std")]
+pub(crate) type Lock<T> = std::sync::Mutex<T>;
+#[cfg(feature = "std")]
+pub(crate) type Guard<'a, T> = std::sync::MutexGuard<'a, T>;
+
+#[cfg(not(feature = "std"))]
+pub(crate) type Lock<T> = core::cell::RefCell<T>;
+#[cfg(not(feature = "std"))]
+pub(crate) type Guard<'a, T> = core::cell::RefMut<'a, T>;
+
+/// Locks `lock`, ignoring poisoning since every update leaves the contents consistent.
+#[cfg(feature = "std")]
+pub(crate) fn lock<T>(lock: &Lock<T>) -> Guard<'_, T> {
+    lock.lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn lock<T>(lock: &Lock<T>) -> Guard<'_, T> {
+    lock.borrow_mut()
+}
+
+/// Returns the contents through exclusive access, which needs no locking.
+#[cfg(feature = "std")]
+pub(crate) fn get_mut<T>(lock: &mut Lock<T>) -> &mut T {
+    lock.get_mut()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn get_mut<T>(lock: &mut Lock<T>) -> &mut T {
+    lock.get_mut()
+}
@@ -0,0 +1,273 @@
+//! Golly's macrocell (`.mc`) format, which stores a pattern as its deduplicated quadtree.
+//!
+//! A file starts with `[M2]`, then optional `#R` (rule), `#G` (generation), and other comment
+//! lines, then one node per line. Level-3 nodes are written as 8x8 bitmaps (`.` dead, `*` live,
+//! `$` ending each row); larger nodes as `level nw ne sw se`, where children are 1-based line
+//! numbers among the nodes and `0` is an empty quadrant. The last node is the root, centered on
+//! the origin with rows growing downwards.
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::Write;
+use hashbrown::HashMap;
+
+use crate::hashlife::{ALIVE, DEAD, NodeId};
+use crate::{HashLife, PatternError, Rule};
+
+/// Side length of the bitmap leaves.
+const LEAF_SIZE: usize = 8;
+const LEAF_LEVEL: u8 = 3;
+
+/// Contents of a parsed macrocell file.
+pub(crate) struct Macrocell {
+    pub(crate) universe: HashLife,
+    pub(crate) name: Option<String>,
+    pub(crate) rule: Option<Rule>,
+}
+
+impl HashLife {
+    /// Builds a universe from Golly macrocell text, keeping its rule and generation.
+    ///
+    /// B0 and Generations rules are rejected as `PatternError::InvalidRule`, since HashLife cannot
+    /// simulate them.
+    pub fn from_macrocell(text: &str) -> Result<Self, PatternError> {
+        Ok(parse(text)?.universe)
+    }
+
+    /// Serializes the universe, its rule, and its generation as Golly macrocell text.
+    pub fn to_macrocell(&self) -> String {
+        write(self, Some(self.rule), None, self.generation)
+    }
+}
+
+pub(crate) fn parse(text: &str) -> Result<Macrocell, PatternError> {
+    let mut lines = text.lines().enumerate();
+    if !lines
+        .next()
+        .is_some_and(|(_, line)| line.trim_start().starts_with("[M2]"))
+    {
+        return Err(PatternError::InvalidHeader { line: 1 });
+    }
+
+    let mut name = None;
+    let mut rule = None;
+    let mut generation = 0;
+    // Node lines parsed so far; entry `n - 1` is node `n` in the file.
+    let mut nodes: Vec<NodeId> = Vec::new();
+    let mut universe = HashLife::new(Rule::default());
+
+    for (index, line) in lines {
+        let line_no = index + 1;
+        let line = line.trim();
+        if let Some(comment) = line.strip_prefix('#') {
+            let invalid_rule = PatternError::InvalidRule { line: line_no };
+            if let Some(notation) = comment.strip_prefix('R') {
+                let parsed: Rule = notation.trim().parse().map_err(|_| invalid_rule.clone())?;
+                if parsed.next_state(false, 0) || parsed.states() > 2 || !parsed.is_moore() {
+                    return Err(invalid_rule);
+                }
+                rule = Some(parsed);
+            } else if let Some(number) = comment.strip_prefix('G') {
+                generation = number
+                    .trim()
+                    .parse()
+                    .map_err(|_| PatternError::InvalidNumber { line: line_no })?;
+            } else if let Some(text) = comment.strip_prefix('N') {
+                name = Some(text.trim().to_string());
+            }
+            continue;
+        }
+        if line.is_empty() {
+            continue;
+        }
+
+        let node = if line.starts_with(['.', '*', '$']) {
+            parse_leaf(&mut universe, line, line_no)?
+        } else {
+            parse_branch(&mut universe, &nodes, line, line_no)?
+        };
+        nodes.push(node);
+    }
+
+    if let Some(&root) = nodes.last() {
+        universe.root = root;
+        universe.clip();
+    }
+    universe.rule = rule.unwrap_or_default();
+    universe.generation = generation;
+    Ok(Macrocell {
+        universe,
+        name,
+        rule,
+    })
+}
+
+fn parse_leaf(universe: &mut HashLife, line: &str, line_no: usize) -> Result<NodeId, PatternError> {
+    let mut grid = [[false; LEAF_SIZE]; LEAF_SIZE];
+    let (mut x, mut y) = (0, 0);
+    for ch in line.chars() {
+        match ch {
+            '.' | '*' if x < LEAF_SIZE && y < LEAF_SIZE => {
+                grid[y][x] = ch == '*';
+                x += 1;
+            }
+            '$' => {
+                x = 0;
+                y += 1;
+            }
+            ch => return Err(PatternError::UnexpectedCharacter { line: line_no, ch }),
+        }
+    }
+    Ok(leaf_node(universe, &grid, 0, 0, LEAF_SIZE))
+}
+
+/// Builds the node for the `size`-wide square of `grid` whose top-left is `(x, y)`.
+fn leaf_node(
+    universe: &mut HashLife,
+    grid: &[[bool; LEAF_SIZE]; LEAF_SIZE],
+    x: usize,
+    y: usize,
+    size: usize,
+) -> NodeId {
+    if size == 1 {
+        return if grid[y][x] { ALIVE } else { DEAD };
+    }
+    let half = size / 2;
+    let nw = leaf_node(universe, grid, x, y, half);
+    let ne = leaf_node(universe, grid, x + half, y, half);
+    let sw = leaf_node(universe, grid, x, y + half, half);
+    let se = leaf_node(universe, grid, x + half, y + half, half);
+    universe.join(nw, ne, sw, se)
+}
+
+fn parse_branch(
+    universe: &mut HashLife,
+    nodes: &[NodeId],
+    line: &str,
+    line_no: usize,
+) -> Result<NodeId, PatternError> {
+    let invalid = PatternError::InvalidNumber { line: line_no };
+    let numbers: Vec<usize> = line
+        .split_whitespace()
+        .map(|word| word.parse().map_err(|_| invalid.clone()))
+        .collect::<Result<_, _>>()?;
+    let [level, nw, ne, sw, se] = numbers[..] else {
+        return Err(invalid);
+    };
+    if level <= usize::from(LEAF_LEVEL) || level > 63 {
+        return Err(invalid);
+    }
+
+    let level = level as u8;
+    let mut quadrants = [DEAD; 4];
+    for (quadrant, child) in quadrants.iter_mut().zip([nw, ne, sw, se]) {
+        *quadrant = match child {
+            0 => universe.empty_node(level - 1),
+            n => *nodes.get(n - 1).ok_or_else(|| invalid.clone())?,
+        };
+        if universe.node(*quadrant).level != level - 1 {
+            return Err(invalid);
+        }
+    }
+    let [nw, ne, sw, se] = quadrants;
+    Ok(universe.join(nw, ne, sw, se))
+}
+
+pub(crate) fn write(
+    universe: &HashLife,
+    rule: Option<Rule>,
+    name: Option<&str>,
+    generation: u64,
+) -> String {
+    let mut out = String::from("[M2] (gameoflife)\n");
+    if let Some(name) = name {
+        let _ = writeln!(out, "#N {name}");
+    }
+    if let Some(rule) = rule {
+        let _ = writeln!(out, "#R {rule}");
+    }
+    if generation > 0 {
+        let _ = writeln!(out, "#G {generation}");
+    }
+
+    if universe.node(universe.root).population > 0 {
+        let root = trim(universe, universe.root);
+        write_node(universe, root, &mut HashMap::new(), &mut out);
+    }
+    out
+}
+
+/// Drops empty outer rings while the pattern still fits in the root's central half, so output
+/// does not carry margins left over from simulation.
+fn trim(universe: &HashLife, mut root: NodeId) -> NodeId {
+    loop {
+        let node = universe.node(root);
+        if node.level <= LEAF_LEVEL + 1 {
+            return root;
+        }
+        let (nw, ne, sw, se) = (
+            universe.node(node.nw),
+            universe.node(node.ne),
+            universe.node(node.sw),
+            universe.node(node.se),
+        );
+        let inner = [(nw, nw.se), (ne, ne.sw), (sw, sw.ne), (se, se.nw)];
+        if inner
+            .iter()
+            .any(|(quadrant, center)| universe.node(*center).population != quadrant.population)
+        {
+            return root;
+        }
+        match universe.index.get(&(nw.se, ne.sw, sw.ne, se.nw)) {
+            Some(&center) => root = center,
+            None => return root,
+        }
+    }
+}
+
+/// Writes `id` after its children, returning its 1-based line number (0 for empty nodes).
+fn write_node(
+    universe: &HashLife,
+    id: NodeId,
+    numbers: &mut HashMap<NodeId, usize>,
+    out: &mut String,
+) -> usize {
+    let node = universe.node(id);
+    if node.population == 0 {
+        return 0;
+    }
+    if let Some(&number) = numbers.get(&id) {
+        return number;
+    }
+
+    if node.level == LEAF_LEVEL {
+        let rows: Vec<String> = (0..LEAF_SIZE as u64)
+            .map(|y| {
+                let row: String = (0..LEAF_SIZE as u64)
+                    .map(|x| if universe.cell_in(id, x, y) { '*' } else { '.' })
+                    .collect();
+                row.trim_end_matches('.').to_string()
+            })
+            .collect();
+        let used = rows
+            .iter()
+            .rposition(|row| !row.is_empty())
+            .map_or(0, |i| i + 1);
+        for row in &rows[..used] {
+            out.push_str(row);
+            out.push('$');
+        }
+        out.push('\n');
+    } else {
+        let children = [node.nw, node.ne, node.sw, node.se]
+            .map(|child| write_node(universe, child, numbers, out));
+        let [nw, ne, sw, se] = children;
+        let _ = writeln!(out, "{} {nw} {ne} {sw} {se}", node.level);
+    }
+
+    let number = numbers.len() + 1;
+    numbers.insert(id, number);
+    number
+}
@@ -1,19 +1,221 @@
-use std::{thread, time::Duration};
+use std::error::Error;
+use std::fs;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Duration;
 
-use gameoflife::{GameOfLife, Viewport};
+use clap::Parser;
+use gameoflife::{Backend, BoundingBox, Format, GameOfLife, Pattern, Rule, Topology};
 
-fn main() {
-    let mut game = GameOfLife::new();
-    game.set(1, 1);
-    game.set(2, 1);
-    game.set(3, 1);
+mod bench;
+mod tui;
 
-    let viewport = Viewport::new(0..=5, 0..=5);
-    println!("{}", viewport.render(&game));
+/// Conway's Game of Life in the terminal.
+///
+/// Without `--headless`, opens an interactive simulator; with it, prints the board after `--steps`
+/// generations as plaintext rows (`O` live, `.` dead), top row first. `--bench` instead times the
+/// run and reports its speed, population, and memory use.
+#[derive(Debug, Parser)]
+#[command(version)]
+struct Args {
+    /// Pattern file to load, in RLE, plaintext, Life 1.06, or macrocell format.
+    #[arg(long, value_name = "FILE")]
+    pattern: Option<PathBuf>,
 
-    loop {
-        game.tick();
-        println!("{}", viewport.render(&game));
-        thread::sleep(Duration::from_millis(500));
+    /// Start from a random soup of this size, anchored at the origin, instead of a pattern.
+    #[arg(long, value_name = "WxH", value_parser = parse_size, conflicts_with = "pattern")]
+    soup: Option<(u32, u32)>,
+
+    /// Resume a session saved with `--save`, including its generation, rule, and undo history.
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["pattern", "soup"])]
+    resume: Option<PathBuf>,
+
+    /// Save the session to this file when the run ends, to continue later with `--resume`.
+    #[arg(long, value_name = "FILE", conflicts_with = "bench")]
+    save: Option<PathBuf>,
+
+    /// Fraction of live cells in `--soup`.
+    #[arg(long, default_value_t = 0.5, value_parser = parse_density)]
+    density: f64,
+
+    /// Random seed for `--soup`; the same seed always gives the same soup.
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+
+    /// Wrap the board into a torus of this size.
+    #[arg(long, value_name = "WxH", value_parser = parse_size)]
+    torus: Option<(u32, u32)>,
+
+    /// Rule in B/S notation, overriding the pattern's own rule.
+    #[arg(long, value_name = "B3/S23")]
+    rule: Option<Rule>,
+
+    /// Generations to simulate. Interactively, the simulation starts running and pauses after them.
+    #[arg(long, value_name = "N")]
+    steps: Option<u64>,
+
+    /// Milliseconds between generations in the interactive simulator.
+    #[arg(long, value_name = "MS", default_value_t = 500)]
+    delay: u64,
+
+    /// Inclusive corners of the region to show; defaults to the live cells' bounding box.
+    #[arg(long, value_name = "X0,Y0,X1,Y1", value_parser = parse_viewport)]
+    viewport: Option<BoundingBox>,
+
+    /// Print only the final state instead of opening the interactive simulator.
+    #[arg(long)]
+    headless: bool,
+
+    /// Time `--steps` generations (default 1000) and report the rate, peak population, and memory.
+    #[arg(long, conflicts_with = "headless")]
+    bench: bool,
+
+    /// Engine for `--bench`: sparse, dense (needs `--torus`), hashlife, or chunked.
+    #[arg(long, default_value = "sparse", value_parser = parse_backend, requires = "bench")]
+    backend: Backend,
+}
+
+fn parse_size(text: &str) -> Result<(u32, u32), String> {
+    let (width, height) = text.split_once(['x', 'X']).ok_or("expected WIDTHxHEIGHT")?;
+    let parse = |part: &str| match part.trim().parse::<u32>() {
+        Ok(0) => Err("sizes must be positive".to_string()),
+        Ok(n) if n > i32::MAX as u32 => Err("size too large".to_string()),
+        Ok(n) => Ok(n),
+        Err(err) => Err(err.to_string()),
+    };
+    Ok((parse(width)?, parse(height)?))
+}
+
+fn parse_density(text: &str) -> Result<f64, String> {
+    match text.parse::<f64>() {
+        Ok(density) if (0.0..=1.0).contains(&density) => Ok(density),
+        Ok(_) => Err("density must be between 0 and 1".to_string()),
+        Err(err) => Err(err.to_string()),
+    }
+}
+
+fn parse_backend(text: &str) -> Result<Backend, String> {
+    match text {
+        "sparse" => Ok(Backend::Sparse),
+        "dense" => Ok(Backend::Dense),
+        "hashlife" => Ok(Backend::HashLife),
+        "chunked" => Ok(Backend::Chunked),
+        other => Err(format!(
+            "unknown backend {other:?}; expected sparse, dense, hashlife, or chunked"
+        )),
+    }
+}
+
+fn parse_viewport(text: &str) -> Result<BoundingBox, String> {
+    let numbers = text
+        .split(',')
+        .map(|part| part.trim().parse::<i32>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|err| err.to_string())?;
+    let [x0, y0, x1, y1] = numbers[..] else {
+        return Err("expected four comma-separated integers".to_string());
+    };
+    Ok(BoundingBox {
+        min_x: x0.min(x1),
+        min_y: y0.min(y1),
+        max_x: x0.max(x1),
+        max_y: y0.max(y1),
+    })
+}
+
+fn load_game(args: &Args) -> Result<GameOfLife, Box<dyn Error>> {
+    let mut game = match (&args.resume, &args.pattern, args.soup) {
+        (Some(path), _, _) => GameOfLife::load(path)
+            .map_err(|err| format!("cannot resume {}: {err}", path.display()))?,
+        (None, Some(path), _) => {
+            let text = fs::read_to_string(path)
+                .map_err(|err| format!("cannot read {}: {err}", path.display()))?;
+            Pattern::parse(&text, Format::detect(&text))
+                .map_err(|err| format!("{}: {err}", path.display()))?
+                .to_game()
+        }
+        (None, None, Some((width, height))) => {
+            GameOfLife::random_soup(width, height, args.density, args.seed)
+        }
+        (None, None, None) => {
+            let mut game = GameOfLife::new();
+            game.set_cells([(1, 1), (2, 1), (3, 1)]);
+            game.clear_history();
+            game
+        }
+    };
+    if let Some(rule) = args.rule {
+        game.set_rule(rule);
+    }
+    if let Some((width, height)) = args.torus {
+        game.set_topology(Topology::Torus {
+            width: width as i32,
+            height: height as i32,
+        });
+        game.clear_history();
+    }
+    Ok(game)
+}
+
+/// Renders `bounds` as plaintext rows, top row first.
+fn plaintext(game: &GameOfLife, bounds: BoundingBox) -> String {
+    let (width, height) = (bounds.width() as i32, bounds.height() as i32);
+    let cells = game.cells_at(width, height, bounds.min_x, bounds.min_y);
+    cells
+        .chunks(width as usize)
+        .map(|row| {
+            let mut line: String = row
+                .iter()
+                .map(|&alive| if alive == 1 { 'O' } else { '.' })
+                .collect();
+            line.push('\n');
+            line
+        })
+        .collect()
+}
+
+fn run(args: Args) -> Result<(), Box<dyn Error>> {
+    let mut game = load_game(&args)?;
+
+    if args.bench {
+        let report = bench::run(&game, args.backend, args.steps.unwrap_or(1000))?;
+        print!("{report}");
+        return Ok(());
+    }
+
+    if args.headless {
+        game.advance(args.steps.unwrap_or(0));
+        if let Some(bounds) = args.viewport.or_else(|| game.bounding_box()) {
+            print!("{}", plaintext(&game, bounds));
+        }
+        return save_game(&args, &game);
+    }
+
+    let game = tui::run(
+        game,
+        tui::Settings {
+            delay: Duration::from_millis(args.delay),
+            steps: args.steps,
+            origin: args.viewport.map(|bounds| (bounds.min_x, bounds.min_y)),
+        },
+    )?;
+    save_game(&args, &game)
+}
+
+fn save_game(args: &Args, game: &GameOfLife) -> Result<(), Box<dyn Error>> {
+    if let Some(path) = &args.save {
+        game.save(path)
+            .map_err(|err| format!("cannot save {}: {err}", path.display()))?;
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    match run(Args::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
     }
 }
@@ -0,0 +1,101 @@
+/// The cells whose states a rule counts when deciding a cell's fate.
+///
+/// Radius 1 gives the classic neighborhoods; larger radii give Larger than Life style rules, in
+/// which counts run up to `size`. The cell itself is never counted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Neighborhood {
+    /// Every cell within `radius` steps in any direction, diagonals included: the 8 surrounding
+    /// cells at radius 1.
+    Moore { radius: u8 },
+    /// Every cell within `radius` orthogonal steps: the 4 adjacent cells at radius 1.
+    VonNeumann { radius: u8 },
+}
+
+impl Neighborhood {
+    /// Largest supported radius, which keeps every neighbor count below 128.
+    pub const MAX_RADIUS: u8 = 5;
+
+    /// The Moore neighborhood of the given radius.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radius` is not within `1..=MAX_RADIUS`.
+    pub fn moore(radius: u8) -> Self {
+        Self::check_radius(radius);
+        Neighborhood::Moore { radius }
+    }
+
+    /// The von Neumann neighborhood of the given radius.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radius` is not within `1..=MAX_RADIUS`.
+    pub fn von_neumann(radius: u8) -> Self {
+        Self::check_radius(radius);
+        Neighborhood::VonNeumann { radius }
+    }
+
+    pub(crate) fn check_radius(radius: u8) {
+        assert!(
+            (1..=Self::MAX_RADIUS).contains(&radius),
+            "neighborhood radius must be between 1 and {}",
+            Self::MAX_RADIUS
+        );
+    }
+
+    pub fn radius(self) -> u8 {
+        match self {
+            Neighborhood::Moore { radius } | Neighborhood::VonNeumann { radius } => radius,
+        }
+    }
+
+    /// Returns how many cells the neighborhood holds, the largest possible neighbor count.
+    pub fn size(self) -> u8 {
+        let radius = self.radius();
+        match self {
+            Neighborhood::Moore { .. } => (2 * radius + 1) * (2 * radius + 1) - 1,
+            Neighborhood::VonNeumann { .. } => 2 * radius * (radius + 1),
+        }
+    }
+
+    /// Returns whether the cell `(dx, dy)` away from the center belongs to the neighborhood.
+    pub fn contains(self, dx: i32, dy: i32) -> bool {
+        let radius = i32::from(self.radius());
+        if (dx, dy) == (0, 0) || dx.abs() > radius || dy.abs() > radius {
+            return false;
+        }
+        match self {
+            Neighborhood::Moore { .. } => true,
+            Neighborhood::VonNeumann { .. } => dx.abs() + dy.abs() <= radius,
+        }
+    }
+
+    /// Iterates the offsets of the neighborhood's cells from its center, row by row.
+    pub fn offsets(self) -> impl Iterator<Item = (i32, i32)> {
+        let radius = i32::from(self.radius());
+        (-radius..=radius)
+            .flat_map(move |dy| (-radius..=radius).map(move |dx| (dx, dy)))
+            .filter(move |&(dx, dy)| self.contains(dx, dy))
+    }
+
+    /// Returns a neighborhood containing both `self` and `other`, for finding every cell an edit can
+    /// affect under several rules.
+    pub(crate) fn union(self, other: Self) -> Self {
+        match (self, other) {
+            (Neighborhood::VonNeumann { radius: a }, Neighborhood::VonNeumann { radius: b }) => {
+                Neighborhood::VonNeumann { radius: a.max(b) }
+            }
+            _ => Neighborhood::Moore {
+                radius: self.radius().max(other.radius()),
+            },
+        }
+    }
+}
+
+/// The 8-cell Moore neighborhood of Conway's Life.
+impl Default for Neighborhood {
+    fn default() -> Self {
+        Neighborhood::Moore { radius: 1 }
+    }
+}
@@ -0,0 +1,236 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::fmt;
+#[cfg(feature = "std")]
+use std::sync::mpsc::Sender;
+
+use crate::lock::{self, Lock};
+use crate::{BoardDiff, GameOfLife, Statistics};
+
+/// Receives simulation events from a `GameOfLife` it was added to with `add_observer`.
+///
+/// Every method defaults to doing nothing, so observers only implement the events they need.
+/// Events fire for each generation `tick` or `advance` computes, births, deaths, and culls first, then
+/// `on_tick`, then `on_stabilized`. Once the board is a still life the remaining generations of an
+/// `advance` are skipped in one go and reported as a single `on_tick`.
+pub trait Observer: Send {
+    /// Called after each generation with the board's updated statistics.
+    fn on_tick(&mut self, _stats: &Statistics) {}
+
+    /// Called for each cell that came alive during the generation, in sorted order.
+    fn on_cell_born(&mut self, _x: i32, _y: i32) {}
+
+    /// Called for each cell that died during the generation, in sorted order.
+    fn on_cell_died(&mut self, _x: i32, _y: i32) {}
+
+    /// Called for each cell removed during the generation for crossing the board's `CoordLimit`,
+    /// in sorted order.
+    fn on_cell_culled(&mut self, _x: i32, _y: i32) {}
+
+    /// Called once when a generation leaves the board unchanged, i.e. it became a still life.
+    /// Oscillators never stabilize.
+    fn on_stabilized(&mut self, _generation: u64) {}
+
+    /// Returns whether `on_cell_born`, `on_cell_died`, and `on_cell_culled` do anything. Births and deaths are only
+    /// collected when some observer wants them, since that costs a pass over the board.
+    fn wants_cells(&self) -> bool {
+        true
+    }
+}
+
+/// One simulation event, as sent to an observing `Sender<SimulationEvent>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SimulationEvent {
+    Tick(Statistics),
+    CellBorn(i32, i32),
+    CellDied(i32, i32),
+    CellCulled(i32, i32),
+    Stabilized { generation: u64 },
+}
+
+/// Forwards every event into a channel, ignoring a disconnected receiver.
+#[cfg(feature = "std")]
+impl Observer for Sender<SimulationEvent> {
+    fn on_tick(&mut self, stats: &Statistics) {
+        let _ = self.send(SimulationEvent::Tick(*stats));
+    }
+
+    fn on_cell_born(&mut self, x: i32, y: i32) {
+        let _ = self.send(SimulationEvent::CellBorn(x, y));
+    }
+
+    fn on_cell_died(&mut self, x: i32, y: i32) {
+        let _ = self.send(SimulationEvent::CellDied(x, y));
+    }
+
+    fn on_cell_culled(&mut self, x: i32, y: i32) {
+        let _ = self.send(SimulationEvent::CellCulled(x, y));
+    }
+
+    fn on_stabilized(&mut self, generation: u64) {
+        let _ = self.send(SimulationEvent::Stabilized { generation });
+    }
+}
+
+/// Identifies an observer for `GameOfLife::remove_observer`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ObserverId(u64);
+
+/// The observers registered on a board. Clones of a board start without observers, so scratch
+/// copies such as those `detect_cycle` steps never fire events.
+#[derive(Default)]
+pub(crate) struct Observers {
+    next_id: u64,
+    /// Behind a lock only so boards stay `Sync`; notifying always has `&mut` access.
+    entries: Lock<Vec<(ObserverId, Box<dyn Observer>)>>,
+}
+
+impl Observers {
+    fn entries(&mut self) -> &mut Vec<(ObserverId, Box<dyn Observer>)> {
+        lock::get_mut(&mut self.entries)
+    }
+
+    pub(crate) fn is_empty(&mut self) -> bool {
+        self.entries().is_empty()
+    }
+
+    pub(crate) fn wants_cells(&mut self) -> bool {
+        self.entries()
+            .iter()
+            .any(|(_, observer)| observer.wants_cells())
+    }
+}
+
+impl Clone for Observers {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+impl fmt::Debug for Observers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let count = lock::lock(&self.entries).len();
+        write!(f, "Observers({count})")
+    }
+}
+
+/// Adapts a closure to one `Observer` event.
+struct OnTick<F>(F);
+struct OnCellBorn<F>(F);
+struct OnCellDied<F>(F);
+struct OnCellCulled<F>(F);
+struct OnStabilized<F>(F);
+
+impl<F: FnMut(&Statistics) + Send> Observer for OnTick<F> {
+    fn on_tick(&mut self, stats: &Statistics) {
+        (self.0)(stats);
+    }
+
+    fn wants_cells(&self) -> bool {
+        false
+    }
+}
+
+impl<F: FnMut(i32, i32) + Send> Observer for OnCellBorn<F> {
+    fn on_cell_born(&mut self, x: i32, y: i32) {
+        (self.0)(x, y);
+    }
+}
+
+impl<F: FnMut(i32, i32) + Send> Observer for OnCellDied<F> {
+    fn on_cell_died(&mut self, x: i32, y: i32) {
+        (self.0)(x, y);
+    }
+}
+
+impl<F: FnMut(i32, i32) + Send> Observer for OnCellCulled<F> {
+    fn on_cell_culled(&mut self, x: i32, y: i32) {
+        (self.0)(x, y);
+    }
+}
+
+impl<F: FnMut(u64) + Send> Observer for OnStabilized<F> {
+    fn on_stabilized(&mut self, generation: u64) {
+        (self.0)(generation);
+    }
+
+    fn wants_cells(&self) -> bool {
+        false
+    }
+}
+
+impl GameOfLife {
+    /// Registers `observer` for this board's simulation events.
+    pub fn add_observer(&mut self, observer: impl Observer + 'static) -> ObserverId {
+        let id = ObserverId(self.observers.next_id);
+        self.observers.next_id += 1;
+        self.observers.entries().push((id, Box::new(observer)));
+        id
+    }
+
+    /// Unregisters an observer, returning `false` if it was already removed.
+    pub fn remove_observer(&mut self, id: ObserverId) -> bool {
+        let entries = self.observers.entries();
+        let before = entries.len();
+        entries.retain(|(entry, _)| *entry != id);
+        entries.len() < before
+    }
+
+    /// Calls `callback` with the statistics after every generation.
+    pub fn on_tick(&mut self, callback: impl FnMut(&Statistics) + Send + 'static) -> ObserverId {
+        self.add_observer(OnTick(callback))
+    }
+
+    /// Calls `callback` with each cell born.
+    pub fn on_cell_born(&mut self, callback: impl FnMut(i32, i32) + Send + 'static) -> ObserverId {
+        self.add_observer(OnCellBorn(callback))
+    }
+
+    /// Calls `callback` with each cell that dies.
+    pub fn on_cell_died(&mut self, callback: impl FnMut(i32, i32) + Send + 'static) -> ObserverId {
+        self.add_observer(OnCellDied(callback))
+    }
+
+    /// Calls `callback` with each cell removed for crossing the board's `CoordLimit`.
+    pub fn on_cell_culled(
+        &mut self,
+        callback: impl FnMut(i32, i32) + Send + 'static,
+    ) -> ObserverId {
+        self.add_observer(OnCellCulled(callback))
+    }
+
+    /// Calls `callback` with the generation at which the board becomes a still life.
+    pub fn on_stabilized(&mut self, callback: impl FnMut(u64) + Send + 'static) -> ObserverId {
+        self.add_observer(OnStabilized(callback))
+    }
+
+    /// Delivers one generation's events; `changes` is present when some observer wants cells.
+    pub(crate) fn notify(
+        &mut self,
+        changes: Option<BoardDiff>,
+        culled: &[(i32, i32)],
+        stabilized: bool,
+    ) {
+        let stats = self.stats();
+        let generation = self.generation;
+        for (_, observer) in self.observers.entries() {
+            if let Some(changes) = &changes
+                && observer.wants_cells()
+            {
+                for &(x, y) in changes.births() {
+                    observer.on_cell_born(x, y);
+                }
+                for &(x, y) in changes.deaths() {
+                    observer.on_cell_died(x, y);
+                }
+                for &(x, y) in culled {
+                    observer.on_cell_culled(x, y);
+                }
+            }
+            observer.on_tick(&stats);
+            if stabilized {
+                observer.on_stabilized(generation);
+            }
+        }
+    }
+}
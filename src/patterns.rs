@@ -0,0 +1,172 @@
+//! Well-known patterns and random soups, each anchored with its bounding box at `(0, 0)`.
+//!
+//! Shapes are sourced from <https://conwaylife.com/wiki/> and use the same row-down orientation as
+//! the pattern file formats.
+
+use crate::Pattern;
+use alloc::format;
+
+fn from_rows(name: &str, rows: &[&str]) -> Pattern {
+    Pattern::new(rows.iter().enumerate().flat_map(|(y, row)| {
+        row.chars()
+            .enumerate()
+            .filter(|&(_, ch)| ch == 'O')
+            .map(move |(x, _)| (x as i32, y as i32))
+    }))
+    .with_name(name)
+}
+
+/// The 2x2 block, the most common still life.
+pub fn block() -> Pattern {
+    from_rows("Block", &["OO", "OO"])
+}
+
+/// The beehive still life.
+pub fn beehive() -> Pattern {
+    from_rows("Beehive", &[".OO.", "O..O", ".OO."])
+}
+
+/// The loaf still life.
+pub fn loaf() -> Pattern {
+    from_rows("Loaf", &[".OO.", "O..O", ".O.O", "..O."])
+}
+
+/// The boat still life.
+pub fn boat() -> Pattern {
+    from_rows("Boat", &["OO.", "O.O", ".O."])
+}
+
+/// The tub still life.
+pub fn tub() -> Pattern {
+    from_rows("Tub", &[".O.", "O.O", ".O."])
+}
+
+/// The period-2 blinker, in its vertical phase.
+pub fn blinker() -> Pattern {
+    from_rows("Blinker", &["O", "O", "O"])
+}
+
+/// The period-2 toad.
+pub fn toad() -> Pattern {
+    from_rows("Toad", &[".OOO", "OOO."])
+}
+
+/// The period-2 beacon.
+pub fn beacon() -> Pattern {
+    from_rows("Beacon", &["OO..", "OO..", "..OO", "..OO"])
+}
+
+/// The period-3 pulsar.
+pub fn pulsar() -> Pattern {
+    from_rows(
+        "Pulsar",
+        &[
+            "..OOO...OOO..",
+            ".............",
+            "O....O.O....O",
+            "O....O.O....O",
+            "O....O.O....O",
+            "..OOO...OOO..",
+            ".............",
+            "..OOO...OOO..",
+            "O....O.O....O",
+            "O....O.O....O",
+            "O....O.O....O",
+            ".............",
+            "..OOO...OOO..",
+        ],
+    )
+}
+
+/// The period-15 pentadecathlon.
+pub fn pentadecathlon() -> Pattern {
+    from_rows(
+        "Pentadecathlon",
+        &[
+            "..O..", ".O.O.", "O...O", "O...O", "O...O", "O...O", "O...O", "O...O", ".O.O.",
+            "..O..",
+        ],
+    )
+}
+
+/// The glider, travelling towards increasing x and y.
+pub fn glider() -> Pattern {
+    from_rows("Glider", &[".O.", "..O", "OOO"])
+}
+
+/// The lightweight spaceship, travelling towards decreasing x.
+pub fn lwss() -> Pattern {
+    from_rows(
+        "Lightweight spaceship",
+        &[".O..O", "O....", "O...O", "OOOO."],
+    )
+}
+
+/// The R-pentomino methuselah, which stabilizes after 1103 generations.
+pub fn r_pentomino() -> Pattern {
+    from_rows("R-pentomino", &[".OO", "OO.", ".O."])
+}
+
+/// The diehard methuselah, which vanishes after 130 generations.
+pub fn diehard() -> Pattern {
+    from_rows("Diehard", &["......O.", "OO......", ".O...OOO"])
+}
+
+/// The acorn methuselah, which takes 5206 generations to stabilize.
+pub fn acorn() -> Pattern {
+    from_rows("Acorn", &[".O.....", "...O...", "OO..OOO"])
+}
+
+/// Bill Gosper's glider gun, which emits a glider every 30 generations.
+pub fn gosper_glider_gun() -> Pattern {
+    from_rows(
+        "Gosper glider gun",
+        &[
+            "........................O...........",
+            "......................O.O...........",
+            "............OO......OO............OO",
+            "...........O...O....OO............OO",
+            "OO........O.....O...OO..............",
+            "OO........O...O.OO....O.O...........",
+            "..........O.....O.......O...........",
+            "...........O...O....................",
+            "............OO......................",
+        ],
+    )
+}
+
+/// A `width` by `height` random soup where each cell is alive with probability `density`.
+///
+/// The same seed always gives the same soup, on every platform, so experiments can be replayed.
+///
+/// # Panics
+///
+/// Panics if `density` is not within `0.0..=1.0`.
+pub fn soup(width: u32, height: u32, density: f64, seed: u64) -> Pattern {
+    assert!(
+        (0.0..=1.0).contains(&density),
+        "density must be between 0 and 1"
+    );
+    let mut rng = SplitMix64(seed);
+    let cells = (0..height).flat_map(|y| (0..width).map(move |x| (x as i32, y as i32)));
+    Pattern::new(cells.filter(|_| rng.next_f64() < density))
+        .with_name(format!("Soup {width}x{height} ({seed})"))
+}
+
+/// Steele, Lea, and Flood's SplitMix64: tiny, fast, and well-distributed enough for soups.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A uniform float in `0.0..1.0`, from the top 53 bits.
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
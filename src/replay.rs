@@ -0,0 +1,353 @@
+use alloc::{format, string::String, vec::Vec};
+use core::error::Error;
+use core::fmt;
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::time::Instant;
+
+use hashbrown::HashSet;
+
+use crate::{BoardDiff, GameOfLife, Rule, Topology};
+
+const HEADER: &str = "#Replay 1";
+
+/// One recorded step of a session.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ReplayEvent {
+    /// Cells edited between ticks.
+    Edit(BoardDiff),
+    /// The board's rule was replaced.
+    Rule(Rule),
+    /// One generation was computed, this long after recording began.
+    Tick(Duration),
+}
+
+/// Reasons a replay file could not be loaded. Line numbers start at 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReplayError {
+    /// The `#Replay 1` header was missing.
+    MissingHeader,
+    /// A line did not start with a known keyword, or a header line came after the events.
+    UnexpectedLine { line: usize },
+    /// A coordinate, size, or timestamp was not a valid number.
+    InvalidNumber { line: usize },
+    /// A rule line held an invalid rulestring.
+    InvalidRule { line: usize },
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReplayError::MissingHeader => write!(f, "missing {HEADER:?} header"),
+            ReplayError::UnexpectedLine { line } => write!(f, "line {line}: unexpected line"),
+            ReplayError::InvalidNumber { line } => write!(f, "line {line}: invalid number"),
+            ReplayError::InvalidRule { line } => write!(f, "line {line}: invalid rule"),
+        }
+    }
+}
+
+impl Error for ReplayError {}
+
+/// A recorded session: the starting board and everything that happened to it, captured by a
+/// `Recorder` and played back with `play`.
+///
+/// Replays save to a compact line-based text format:
+///
+/// ```text
+/// #Replay 1
+/// rule B3/S23
+/// topology torus 32x32
+/// cells 1,0 2,1 0,2 1,2 2,2
+/// e +5,5 -1,0
+/// t 120
+/// r B36/S23
+/// t 240
+/// ```
+///
+/// `e` lines hold the cells an edit brought to life (`+`) or killed (`-`), `r` lines a new rule,
+/// and `t` lines a tick, stamped in milliseconds since recording began. Only live cells are
+/// recorded; changes to decaying cells, the topology, or other settings are not.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Replay {
+    rule: Rule,
+    topology: Topology,
+    cells: Vec<(i32, i32)>,
+    events: Vec<ReplayEvent>,
+}
+
+impl Replay {
+    /// Returns a fresh board in the state recording started from.
+    pub fn initial(&self) -> GameOfLife {
+        let mut game = GameOfLife::with_topology(self.topology);
+        game.set_rule(self.rule);
+        for &(x, y) in &self.cells {
+            game.place(x, y);
+        }
+        game
+    }
+
+    /// Returns the recorded events in order.
+    pub fn events(&self) -> &[ReplayEvent] {
+        &self.events
+    }
+
+    /// Returns the number of recorded ticks.
+    pub fn ticks(&self) -> usize {
+        self.events
+            .iter()
+            .filter(|event| matches!(event, ReplayEvent::Tick(_)))
+            .count()
+    }
+
+    /// Returns the timestamp of the last tick, or zero if there were none.
+    pub fn duration(&self) -> Duration {
+        self.events
+            .iter()
+            .rev()
+            .find_map(|event| match event {
+                ReplayEvent::Tick(at) => Some(*at),
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+
+    /// Starts playing the session back from its initial board.
+    pub fn play(&self) -> Player<'_> {
+        Player {
+            game: self.initial(),
+            events: self.events.iter(),
+        }
+    }
+
+    /// Parses a replay written by `save`.
+    pub fn load(text: &str) -> Result<Self, ReplayError> {
+        let mut lines = text
+            .lines()
+            .enumerate()
+            .map(|(index, line)| (index + 1, line.trim()))
+            .filter(|(_, line)| !line.is_empty());
+        if lines.next().map(|(_, line)| line) != Some(HEADER) {
+            return Err(ReplayError::MissingHeader);
+        }
+
+        let mut replay = Replay::default();
+        for (line, text) in lines {
+            let (keyword, rest) = text.split_once(' ').unwrap_or((text, ""));
+            let rest = rest.trim();
+            let in_header = replay.events.is_empty();
+            match keyword {
+                "rule" if in_header => replay.rule = parse_rule(rest, line)?,
+                "topology" if in_header => replay.topology = parse_topology(rest, line)?,
+                "cells" if in_header => {
+                    for token in rest.split_whitespace() {
+                        replay.cells.push(parse_cell(token, line)?);
+                    }
+                }
+                "e" => {
+                    let (mut births, mut deaths) = (Vec::new(), Vec::new());
+                    for token in rest.split_whitespace() {
+                        match token.split_at_checked(1) {
+                            Some(("+", cell)) => births.push(parse_cell(cell, line)?),
+                            Some(("-", cell)) => deaths.push(parse_cell(cell, line)?),
+                            _ => return Err(ReplayError::UnexpectedLine { line }),
+                        }
+                    }
+                    let diff = BoardDiff::from_changes(births, deaths);
+                    replay.events.push(ReplayEvent::Edit(diff));
+                }
+                "r" => replay
+                    .events
+                    .push(ReplayEvent::Rule(parse_rule(rest, line)?)),
+                "t" => {
+                    let millis = rest
+                        .parse()
+                        .map_err(|_| ReplayError::InvalidNumber { line })?;
+                    let at = Duration::from_millis(millis);
+                    replay.events.push(ReplayEvent::Tick(at));
+                }
+                _ => return Err(ReplayError::UnexpectedLine { line }),
+            }
+        }
+        Ok(replay)
+    }
+
+    /// Writes the replay in the text format `load` reads.
+    pub fn save(&self) -> String {
+        let mut out = format!("{HEADER}\nrule {}\n", self.rule);
+        match self.topology {
+            Topology::Infinite => out.push_str("topology infinite\n"),
+            Topology::Bounded { width, height } => {
+                out.push_str(&format!("topology bounded {width}x{height}\n"));
+            }
+            Topology::Torus { width, height } => {
+                out.push_str(&format!("topology torus {width}x{height}\n"));
+            }
+        }
+        out.push_str("cells");
+        for (x, y) in &self.cells {
+            out.push_str(&format!(" {x},{y}"));
+        }
+        out.push('\n');
+
+        for event in &self.events {
+            match event {
+                ReplayEvent::Edit(diff) => {
+                    out.push('e');
+                    for (x, y) in diff.births() {
+                        out.push_str(&format!(" +{x},{y}"));
+                    }
+                    for (x, y) in diff.deaths() {
+                        out.push_str(&format!(" -{x},{y}"));
+                    }
+                }
+                ReplayEvent::Rule(rule) => out.push_str(&format!("r {rule}")),
+                ReplayEvent::Tick(at) => out.push_str(&format!("t {}", at.as_millis())),
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+fn parse_rule(text: &str, line: usize) -> Result<Rule, ReplayError> {
+    text.parse().map_err(|_| ReplayError::InvalidRule { line })
+}
+
+fn parse_topology(text: &str, line: usize) -> Result<Topology, ReplayError> {
+    let (kind, size) = text.split_once(' ').unwrap_or((text, ""));
+    let size = || -> Result<(i32, i32), ReplayError> {
+        let (width, height) = size
+            .trim()
+            .split_once('x')
+            .ok_or(ReplayError::InvalidNumber { line })?;
+        let parse = |n: &str| match n.parse() {
+            Ok(n) if n > 0 => Ok(n),
+            _ => Err(ReplayError::InvalidNumber { line }),
+        };
+        Ok((parse(width)?, parse(height)?))
+    };
+    match kind {
+        "infinite" => Ok(Topology::Infinite),
+        "bounded" => size().map(|(width, height)| Topology::Bounded { width, height }),
+        "torus" => size().map(|(width, height)| Topology::Torus { width, height }),
+        _ => Err(ReplayError::UnexpectedLine { line }),
+    }
+}
+
+fn parse_cell(text: &str, line: usize) -> Result<(i32, i32), ReplayError> {
+    let invalid = ReplayError::InvalidNumber { line };
+    let (x, y) = text.split_once(',').ok_or(invalid.clone())?;
+    match (x.parse(), y.parse()) {
+        (Ok(x), Ok(y)) => Ok((x, y)),
+        _ => Err(invalid),
+    }
+}
+
+/// Plays a `Replay` back one tick at a time, returned by `Replay::play`.
+///
+/// Each call to `next` applies the edits and rule changes recorded before the next tick, runs the
+/// tick, and returns when it happened, so a frontend can pace playback from the timestamps. Once
+/// every tick has played, edits recorded after the last one are applied and `next` returns `None`.
+#[derive(Debug)]
+pub struct Player<'a> {
+    game: GameOfLife,
+    events: core::slice::Iter<'a, ReplayEvent>,
+}
+
+impl Player<'_> {
+    /// Returns the board as of the last played tick.
+    pub fn game(&self) -> &GameOfLife {
+        &self.game
+    }
+
+    /// Plays the rest of the session and returns the final board.
+    pub fn finish(mut self) -> GameOfLife {
+        while self.next().is_some() {}
+        self.game
+    }
+}
+
+impl Iterator for Player<'_> {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Duration> {
+        for event in self.events.by_ref() {
+            match event {
+                ReplayEvent::Edit(diff) => self.game.apply_diff(diff),
+                ReplayEvent::Rule(rule) => self.game.set_rule(*rule),
+                ReplayEvent::Tick(at) => {
+                    self.game.tick();
+                    return Some(*at);
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Records a session on a board for later playback. Create it with the board as it starts, edit
+/// the board freely, and call `tick` instead of `GameOfLife::tick`.
+#[derive(Debug)]
+pub struct Recorder {
+    replay: Replay,
+    /// Live cells and rule as of the last recorded event, to spot edits against.
+    last: HashSet<(i32, i32)>,
+    rule: Rule,
+    #[cfg(feature = "std")]
+    started: Instant,
+}
+
+impl Recorder {
+    /// Starts recording from `game`'s current board, rule, and topology.
+    pub fn new(game: &GameOfLife) -> Self {
+        let mut cells: Vec<(i32, i32)> = game.state.iter().copied().collect();
+        cells.sort_unstable();
+        Self {
+            replay: Replay {
+                rule: game.rule(),
+                topology: game.topology(),
+                cells,
+                events: Vec::new(),
+            },
+            last: game.state.clone(),
+            rule: game.rule(),
+            #[cfg(feature = "std")]
+            started: Instant::now(),
+        }
+    }
+
+    /// Records edits made since the last tick, then ticks `game` and records the time since
+    /// recording began.
+    #[cfg(feature = "std")]
+    pub fn tick(&mut self, game: &mut GameOfLife) {
+        let at = self.started.elapsed();
+        self.tick_at(game, at);
+    }
+
+    /// Like `tick`, but stamps the tick with `at` instead of the time elapsed.
+    pub fn tick_at(&mut self, game: &mut GameOfLife, at: Duration) {
+        self.record_edits(game);
+        game.tick();
+        self.replay.events.push(ReplayEvent::Tick(at));
+        self.last.clone_from(&game.state);
+    }
+
+    /// Records edits made since the last tick and returns the finished replay.
+    pub fn finish(mut self, game: &GameOfLife) -> Replay {
+        self.record_edits(game);
+        self.replay
+    }
+
+    fn record_edits(&mut self, game: &GameOfLife) {
+        if game.rule() != self.rule {
+            self.rule = game.rule();
+            self.replay.events.push(ReplayEvent::Rule(self.rule));
+        }
+        let diff = BoardDiff::between(&self.last, &game.state);
+        if !diff.is_empty() {
+            self.replay.events.push(ReplayEvent::Edit(diff));
+        }
+    }
+}
@@ -0,0 +1,425 @@
+use alloc::string::{String, ToString};
+use alloc::{format, vec::Vec};
+use core::error::Error;
+use core::fmt;
+use core::str::FromStr;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::JsValue;
+
+use crate::Neighborhood;
+
+/// Birth and survival conditions for a Life-like cellular automaton.
+///
+/// Each condition is stored as a bitmask over live-neighbor counts, so bit `n`
+/// is set when a cell with `n` live neighbors is born (or survives).
+///
+/// Generations rules add decay states: a live cell that fails to survive passes through states
+/// `2..states` before dying, one per generation. Decaying cells do not count as live neighbors and
+/// cannot be reborn until they reach state 0. Life-like rules have 2 states.
+///
+/// Rules count the 8-cell Moore neighborhood unless built with another `Neighborhood`, such as
+/// the 4-cell von Neumann neighborhood or a Larger than Life range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rule {
+    birth: u128,
+    survival: u128,
+    states: u8,
+    neighborhood: Neighborhood,
+}
+
+impl Rule {
+    /// Builds a rule from the neighbor counts that cause a birth and allow survival.
+    pub fn new(birth: &[u8], survival: &[u8]) -> Self {
+        Self::generations(birth, survival, 2)
+    }
+
+    /// Builds a Generations rule with `states` cell states in total, counting dead and live.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `states` is below 2.
+    pub fn generations(birth: &[u8], survival: &[u8], states: u8) -> Self {
+        Self::custom(birth, survival, states, Neighborhood::default())
+    }
+
+    /// Builds a rule counting live cells in `neighborhood`, such as a von Neumann or Larger than
+    /// Life rule.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `states` is below 2, the neighborhood's radius is not within
+    /// `1..=Neighborhood::MAX_RADIUS`, or a count exceeds the neighborhood's size.
+    pub fn custom(birth: &[u8], survival: &[u8], states: u8, neighborhood: Neighborhood) -> Self {
+        assert!(states >= 2, "rules need at least a dead and a live state");
+        Neighborhood::check_radius(neighborhood.radius());
+        Self {
+            birth: Self::mask(birth, neighborhood),
+            survival: Self::mask(survival, neighborhood),
+            states,
+            neighborhood,
+        }
+    }
+
+    /// Conway's original B3/S23 rule.
+    pub fn conway() -> Self {
+        Self::new(&[3], &[2, 3])
+    }
+
+    /// HighLife (B36/S23), which adds a replicator to Conway's rule.
+    pub fn highlife() -> Self {
+        Self::new(&[3, 6], &[2, 3])
+    }
+
+    /// Day & Night (B3678/S34678), which treats live and dead cells symmetrically.
+    pub fn day_and_night() -> Self {
+        Self::new(&[3, 6, 7, 8], &[3, 4, 6, 7, 8])
+    }
+
+    /// Seeds (B2/S), in which every live cell dies each generation.
+    pub fn seeds() -> Self {
+        Self::new(&[2], &[])
+    }
+
+    /// Brian's Brain (B2/S/C3): Seeds with one refractory state, full of small spaceships.
+    pub fn brians_brain() -> Self {
+        Self::generations(&[2], &[], 3)
+    }
+
+    /// Star Wars (B2/S345/C4).
+    pub fn star_wars() -> Self {
+        Self::generations(&[2], &[3, 4, 5], 4)
+    }
+
+    /// Bosco's Rule (R5,C0,M1,S34..58,B34..45,NM), the best-known Larger than Life rule, home to
+    /// the bugs: gliders that travel through a radius-5 Moore neighborhood.
+    pub fn bosco() -> Self {
+        // The rulestring counts the cell itself among survivors' neighbors; this crate does not.
+        let survival: Vec<u8> = (33..=57).collect();
+        let birth: Vec<u8> = (34..=45).collect();
+        Self::custom(&birth, &survival, 2, Neighborhood::moore(5))
+    }
+
+    /// Returns the number of cell states: 2 for Life-like rules, more for Generations rules.
+    pub fn states(&self) -> u8 {
+        self.states
+    }
+
+    /// Returns the cells counted as a cell's neighbors.
+    pub fn neighborhood(&self) -> Neighborhood {
+        self.neighborhood
+    }
+
+    /// Returns whether the rule counts the 8-cell Moore neighborhood, as the bit-parallel and
+    /// quadtree engines require.
+    pub(crate) fn is_moore(&self) -> bool {
+        self.neighborhood == Neighborhood::default()
+    }
+
+    fn mask(counts: &[u8], neighborhood: Neighborhood) -> u128 {
+        let size = neighborhood.size();
+        counts.iter().fold(0, |mask, &count| {
+            assert!(
+                count <= size,
+                "neighbor counts must be between 0 and {size}"
+            );
+            mask | (1 << count)
+        })
+    }
+
+    fn parse_counts(digits: &str, neighborhood: Neighborhood) -> Result<u128, RuleParseError> {
+        let size = u32::from(neighborhood.size());
+        digits
+            .chars()
+            .try_fold(0, |mask, ch| match ch.to_digit(10) {
+                Some(count) if count <= size => Ok(mask | (1 << count)),
+                _ => Err(RuleParseError::InvalidCount(ch)),
+            })
+    }
+
+    /// Parses Larger than Life rulestrings in Golly's `R5,C0,M1,S34..58,B34..45,NM` form or the
+    /// HROT form `R2,C2,S2-3,5,B3,NN`, which allows lists of counts and ranges.
+    fn parse_extended(text: &str) -> Result<Self, RuleParseError> {
+        let invalid = || RuleParseError::InvalidFormat(text.to_string());
+        let mut radius = None;
+        let mut states = 2;
+        let mut middle = false;
+        let mut von_neumann = false;
+        let (mut survival, mut birth) = (Vec::new(), Vec::new());
+        // The field that bare list items after a comma belong to, `S` or `B`.
+        let mut list = None;
+        for token in text.split(',').map(str::trim) {
+            let (field, value) = match token.chars().next() {
+                Some(field) if field.is_ascii_alphabetic() => (field, &token[1..]),
+                _ => (list.ok_or_else(invalid)?, token),
+            };
+            list = None;
+            match field {
+                'R' => radius = Some(value.parse::<u8>().map_err(|_| invalid())?),
+                'C' => {
+                    states = match value.parse::<u8>() {
+                        Ok(0 | 2) => 2,
+                        Ok(count) if count > 2 => count,
+                        _ => return Err(RuleParseError::InvalidStates(value.to_string())),
+                    }
+                }
+                'M' => {
+                    middle = match value {
+                        "0" => false,
+                        "1" => true,
+                        _ => return Err(invalid()),
+                    }
+                }
+                'N' => {
+                    von_neumann = match value {
+                        "M" => false,
+                        "N" => true,
+                        _ => return Err(RuleParseError::InvalidNeighborhood(value.to_string())),
+                    }
+                }
+                'S' | 'B' => {
+                    let counts = if field == 'S' {
+                        &mut survival
+                    } else {
+                        &mut birth
+                    };
+                    if !value.is_empty() {
+                        counts.push(value);
+                    }
+                    list = Some(field);
+                }
+                _ => return Err(invalid()),
+            }
+        }
+
+        let radius = radius.ok_or_else(invalid)?;
+        if !(1..=Neighborhood::MAX_RADIUS).contains(&radius) {
+            return Err(RuleParseError::InvalidNeighborhood(format!("R{radius}")));
+        }
+        let neighborhood = if von_neumann {
+            Neighborhood::VonNeumann { radius }
+        } else {
+            Neighborhood::Moore { radius }
+        };
+        let mut survival = Self::parse_ranges(&survival, neighborhood, middle)?;
+        if middle {
+            // With `M1` a live cell counts itself, so survival counts are one higher than ours.
+            survival >>= 1;
+        }
+        let birth = Self::parse_ranges(&birth, neighborhood, false)?;
+        Ok(Self {
+            birth,
+            survival,
+            states,
+            neighborhood,
+        })
+    }
+
+    /// Parses counts and `a..b` or `a-b` ranges into a mask. `middle` allows one count more than
+    /// the neighborhood's size, for rulestrings that count the cell itself.
+    fn parse_ranges(
+        items: &[&str],
+        neighborhood: Neighborhood,
+        middle: bool,
+    ) -> Result<u128, RuleParseError> {
+        let max = u32::from(neighborhood.size()) + u32::from(middle);
+        let mut mask = 0;
+        for &item in items {
+            let invalid = || RuleParseError::InvalidRange(item.to_string());
+            let (low, high) = item
+                .split_once("..")
+                .or_else(|| item.split_once('-'))
+                .unwrap_or((item, item));
+            let low: u32 = low.trim().parse().map_err(|_| invalid())?;
+            let high: u32 = high.trim().parse().map_err(|_| invalid())?;
+            if low > high || high > max {
+                return Err(invalid());
+            }
+            for count in low..=high {
+                mask |= 1 << count;
+            }
+        }
+        Ok(mask)
+    }
+
+    /// Returns whether a cell is alive next generation given its current state and live-neighbor count.
+    pub fn next_state(&self, alive: bool, live_neighbors: u8) -> bool {
+        let mask = if alive { self.survival } else { self.birth };
+        live_neighbors < 128 && mask & (1 << live_neighbors) != 0
+    }
+}
+
+impl Default for Rule {
+    fn default() -> Self {
+        Self::conway()
+    }
+}
+
+/// Reasons a rulestring could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RuleParseError {
+    /// The string is not in `B.../S...` or `S/B` notation.
+    InvalidFormat(String),
+    /// A neighbor count was not a digit or exceeded the number of cells in the rule's
+    /// neighborhood.
+    InvalidCount(char),
+    /// A Generations state count was not a number from 2 to 255.
+    InvalidStates(String),
+    /// A Larger than Life rule named an unknown neighborhood or a radius outside
+    /// `1..=Neighborhood::MAX_RADIUS`.
+    InvalidNeighborhood(String),
+    /// A Larger than Life count or range was malformed or exceeded the neighborhood's size.
+    InvalidRange(String),
+}
+
+impl fmt::Display for RuleParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RuleParseError::InvalidFormat(rule) => write!(f, "unrecognized rulestring {rule:?}"),
+            RuleParseError::InvalidCount(ch) => write!(f, "invalid neighbor count {ch:?}"),
+            RuleParseError::InvalidStates(states) => write!(f, "invalid state count {states:?}"),
+            RuleParseError::InvalidNeighborhood(neighborhood) => {
+                write!(f, "invalid neighborhood {neighborhood:?}")
+            }
+            RuleParseError::InvalidRange(range) => write!(f, "invalid neighbor count {range:?}"),
+        }
+    }
+}
+
+impl Error for RuleParseError {}
+
+#[cfg(target_arch = "wasm32")]
+impl From<RuleParseError> for JsValue {
+    fn from(err: RuleParseError) -> Self {
+        JsValue::from_str(&err.to_string())
+    }
+}
+
+/// Parses `B3/S23`-style rulestrings (case-insensitive, slash optional) and legacy `23/3` S/B notation.
+///
+/// Generations rules add a state count, as in `B2/S/C3` or Golly's `S/B/C` form `/2/3`. A trailing
+/// `V`, as in `B2/S013V`, selects the von Neumann neighborhood, and rulestrings starting with `R`
+/// are Larger than Life rules such as `R5,C0,M1,S34..58,B34..45,NM`.
+impl FromStr for Rule {
+    type Err = RuleParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || RuleParseError::InvalidFormat(s.to_string());
+        let upper = s.trim().to_ascii_uppercase();
+        if upper.starts_with('R') {
+            return Self::parse_extended(&upper);
+        }
+        let (upper, neighborhood) = match upper.strip_suffix('V') {
+            Some(rest) => (rest.to_string(), Neighborhood::von_neumann(1)),
+            None => (upper, Neighborhood::default()),
+        };
+
+        let (text, states) = if let Some((rest, states)) = upper.split_once('C') {
+            (rest.strip_suffix('/').unwrap_or(rest), Some(states))
+        } else if !upper.starts_with(['B', 'S']) && upper.matches('/').count() == 2 {
+            let (rest, states) = upper.rsplit_once('/').ok_or_else(invalid)?;
+            (rest, Some(states))
+        } else {
+            (upper.as_str(), None)
+        };
+        let states = match states {
+            None => 2,
+            Some(states) => match states.parse::<u8>() {
+                Ok(count) if count >= 2 => count,
+                _ => return Err(RuleParseError::InvalidStates(states.to_string())),
+            },
+        };
+
+        let (birth, survival) = if let Some(rest) = text.strip_prefix('B') {
+            let (birth, survival) = rest.split_once('S').ok_or_else(invalid)?;
+            (birth.strip_suffix('/').unwrap_or(birth), survival)
+        } else if let Some(rest) = text.strip_prefix('S') {
+            let (survival, birth) = rest.split_once('B').ok_or_else(invalid)?;
+            (birth, survival.strip_suffix('/').unwrap_or(survival))
+        } else {
+            let (survival, birth) = text.split_once('/').ok_or_else(invalid)?;
+            (birth, survival)
+        };
+
+        Ok(Self {
+            birth: Self::parse_counts(birth, neighborhood)?,
+            survival: Self::parse_counts(survival, neighborhood)?,
+            states,
+            neighborhood,
+        })
+    }
+}
+
+/// Formats the rule in canonical `B.../S...` notation, with a `/C...` suffix for Generations rules
+/// and a `V` suffix for the von Neumann neighborhood. Larger than Life rules use HROT notation,
+/// such as `R5,C0,S33-57,B34-45,NM`, which never counts the cell itself.
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let radius = self.neighborhood.radius();
+        let von_neumann = matches!(self.neighborhood, Neighborhood::VonNeumann { .. });
+        if radius > 1 {
+            let states = if self.states > 2 { self.states } else { 0 };
+            write!(f, "R{radius},C{states},S")?;
+            write_ranges(f, self.survival)?;
+            write!(f, ",B")?;
+            write_ranges(f, self.birth)?;
+            return write!(f, ",N{}", if von_neumann { 'N' } else { 'M' });
+        }
+
+        let digits = |mask: u128| -> String {
+            (0..=8)
+                .filter(|count| mask & (1 << count) != 0)
+                .map(|count| char::from(b'0' + count as u8))
+                .collect()
+        };
+        write!(f, "B{}/S{}", digits(self.birth), digits(self.survival))?;
+        if self.states > 2 {
+            write!(f, "/C{}", self.states)?;
+        }
+        if von_neumann {
+            write!(f, "V")?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes the counts in `mask` as a comma-separated list of counts and `a-b` ranges.
+fn write_ranges(f: &mut fmt::Formatter<'_>, mask: u128) -> fmt::Result {
+    let mut count = 0;
+    let mut first = true;
+    while count < 128 {
+        if mask & (1 << count) == 0 {
+            count += 1;
+            continue;
+        }
+        let start = count;
+        while count < 128 && mask & (1 << count) != 0 {
+            count += 1;
+        }
+        if !first {
+            write!(f, ",")?;
+        }
+        first = false;
+        match count - 1 {
+            end if end == start => write!(f, "{start}")?,
+            end => write!(f, "{start}-{end}")?,
+        }
+    }
+    Ok(())
+}
+
+/// Rules serialize as their `B3/S23` notation.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Rule {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Rule {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let notation = String::deserialize(deserializer)?;
+        notation.parse().map_err(serde::de::Error::custom)
+    }
+}
@@ -0,0 +1,184 @@
+use alloc::collections::VecDeque;
+use alloc::{boxed::Box, vec::Vec};
+
+use core::fmt;
+use hashbrown::HashSet;
+
+use crate::GameOfLife;
+
+/// When `GameOfLife::run_until` should stop.
+///
+/// Conditions are checked before the first generation and after every one, so a board that
+/// already meets one is not advanced at all.
+pub enum StopCondition {
+    /// The last generation left the board unchanged.
+    Stable,
+    /// No live cells remain.
+    Extinct,
+    /// The board repeats a state it held at most this many generations ago. Still lifes repeat
+    /// with period 1. Spaceships, which repeat elsewhere, do not count.
+    Periodic(u64),
+    /// This many generations have run since `run_until` was called.
+    GenerationLimit(u64),
+    /// The closure returns `true` for the current board.
+    Custom(Box<dyn FnMut(&GameOfLife) -> bool>),
+    /// Any of the conditions holds; the first one listed that does is reported.
+    Any(Vec<StopCondition>),
+}
+
+impl StopCondition {
+    /// Stops when `predicate` returns `true` for the current board.
+    pub fn when(predicate: impl FnMut(&GameOfLife) -> bool + 'static) -> Self {
+        StopCondition::Custom(Box::new(predicate))
+    }
+
+    /// Longest period any nested `Periodic` condition looks for, or 0 if there is none.
+    fn max_period(&self) -> u64 {
+        match self {
+            StopCondition::Periodic(max) => *max,
+            StopCondition::Any(conditions) => conditions
+                .iter()
+                .map(StopCondition::max_period)
+                .max()
+                .unwrap_or(0),
+            _ => 0,
+        }
+    }
+
+    fn check(
+        &mut self,
+        game: &GameOfLife,
+        elapsed: u64,
+        period: Option<u64>,
+    ) -> Option<StopReason> {
+        match self {
+            StopCondition::Stable => game.stable.then_some(StopReason::Stable),
+            StopCondition::Extinct => {
+                (game.population() == 0 && game.decay.is_empty()).then_some(StopReason::Extinct)
+            }
+            StopCondition::Periodic(max) => period
+                .filter(|period| period <= max)
+                .map(|period| StopReason::Periodic { period }),
+            StopCondition::GenerationLimit(limit) => {
+                (elapsed >= *limit).then_some(StopReason::GenerationLimit)
+            }
+            StopCondition::Custom(predicate) => predicate(game).then_some(StopReason::Custom),
+            StopCondition::Any(conditions) => conditions
+                .iter_mut()
+                .find_map(|condition| condition.check(game, elapsed, period)),
+        }
+    }
+}
+
+impl fmt::Debug for StopCondition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StopCondition::Stable => write!(f, "Stable"),
+            StopCondition::Extinct => write!(f, "Extinct"),
+            StopCondition::Periodic(max) => f.debug_tuple("Periodic").field(max).finish(),
+            StopCondition::GenerationLimit(limit) => {
+                f.debug_tuple("GenerationLimit").field(limit).finish()
+            }
+            StopCondition::Custom(_) => write!(f, "Custom(..)"),
+            StopCondition::Any(conditions) => f.debug_tuple("Any").field(conditions).finish(),
+        }
+    }
+}
+
+/// Which `StopCondition` ended a run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StopReason {
+    Stable,
+    Extinct,
+    /// The board repeated itself after `period` generations.
+    Periodic {
+        period: u64,
+    },
+    GenerationLimit,
+    Custom,
+}
+
+/// Why and when `GameOfLife::run_until` stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RunReport {
+    pub reason: StopReason,
+    /// Generations simulated by the run.
+    pub generations: u64,
+    /// The board's generation counter when the run stopped.
+    pub generation: u64,
+}
+
+/// A past board kept for `Periodic` checks.
+struct Seen {
+    generation: u64,
+    hash: u64,
+    state: HashSet<(i32, i32)>,
+    decay: Vec<HashSet<(i32, i32)>>,
+}
+
+impl GameOfLife {
+    /// Advances until `condition` holds, as a single undoable change, and reports why it stopped.
+    ///
+    /// Without a `GenerationLimit`, a board that never meets the condition runs forever.
+    /// `Periodic(n)` keeps the last `n` boards to compare against.
+    pub fn run_until(&mut self, mut condition: StopCondition) -> RunReport {
+        let start = self.generation;
+        let max_period = condition.max_period();
+        let mut recent: VecDeque<Seen> = VecDeque::new();
+        let mut recorded = false;
+
+        loop {
+            let period = (max_period > 0).then(|| self.repeat_period(&mut recent, max_period));
+            let elapsed = self.generation - start;
+            if let Some(reason) = condition.check(self, elapsed, period.flatten()) {
+                return RunReport {
+                    reason,
+                    generations: elapsed,
+                    generation: self.generation,
+                };
+            }
+
+            if !recorded {
+                if self.stable {
+                    self.redo.clear();
+                } else {
+                    self.snapshot();
+                }
+                recorded = true;
+            }
+            if self.stable {
+                self.last_changes = (0, 0);
+                self.last_culled = 0;
+                self.generation += 1;
+                if !self.observers.is_empty() {
+                    self.notify(None, &[], false);
+                }
+            } else {
+                self.step();
+            }
+        }
+    }
+
+    /// Returns how many generations ago the current board last appeared among `recent`, then
+    /// remembers it, keeping at most `max_period` boards.
+    fn repeat_period(&self, recent: &mut VecDeque<Seen>, max_period: u64) -> Option<u64> {
+        let hash = self.state_hash();
+        let period = recent
+            .iter()
+            .rev()
+            .find(|seen| seen.hash == hash && seen.state == self.state && seen.decay == self.decay)
+            .map(|seen| self.generation - seen.generation);
+        if recent.len() as u64 >= max_period {
+            recent.pop_front();
+        }
+        recent.push_back(Seen {
+            generation: self.generation,
+            hash,
+            state: self.state.clone(),
+            decay: self.decay.clone(),
+        });
+        period
+    }
+}
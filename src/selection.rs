@@ -0,0 +1,108 @@
+use alloc::{string::String, vec::Vec};
+use core::ops::RangeInclusive;
+
+use crate::{BoundingBox, GameOfLife, Pattern};
+
+/// A named rectangle of a board with clipboard-style editing.
+///
+/// Copied patterns keep each cell's offset from the selection's minimum corner, so pasting a copy
+/// at that corner restores it exactly. Every editing method records a single undo snapshot.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Selection {
+    bounds: BoundingBox,
+    name: Option<String>,
+}
+
+impl Selection {
+    /// Selects the cells inside both inclusive ranges, accepting either endpoint order.
+    pub fn new(x_range: RangeInclusive<i32>, y_range: RangeInclusive<i32>) -> Self {
+        Self::from_bounds(BoundingBox::from_ranges(&x_range, &y_range))
+    }
+
+    /// Selects the cells inside `bounds`.
+    pub fn from_bounds(bounds: BoundingBox) -> Self {
+        Self { bounds, name: None }
+    }
+
+    /// Sets the selection's name, which copies carry as their pattern name.
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Returns the selection's name, if any.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns the selected rectangle.
+    pub fn bounds(&self) -> BoundingBox {
+        self.bounds
+    }
+
+    /// Returns the live cells inside the selection as a pattern, relative to its minimum corner.
+    /// In a selection wider than the positive half of the plane, the cells too far from that
+    /// corner are left out.
+    pub fn copy(&self, game: &GameOfLife) -> Pattern {
+        let BoundingBox { min_x, min_y, .. } = self.bounds;
+        let cells = game
+            .iter_live()
+            .filter(|&(x, y)| self.bounds.contains(x, y))
+            .filter_map(|(x, y)| Some((x.checked_sub(min_x)?, y.checked_sub(min_y)?)));
+        let pattern = Pattern::new(cells).with_rule(game.rule());
+        match &self.name {
+            Some(name) => pattern.with_name(name.clone()),
+            None => pattern,
+        }
+    }
+
+    /// Copies the selection, then kills every cell inside it.
+    pub fn cut(&self, game: &mut GameOfLife) -> Pattern {
+        let pattern = self.copy(game);
+        self.clear(game);
+        pattern
+    }
+
+    /// Adds `pattern`'s cells with its origin at `(x, y)`, on top of the existing board, and moves
+    /// the selection to cover them together with that origin. Cells that would land past the edge of
+    /// the `i32` plane are dropped.
+    ///
+    /// An empty pattern leaves both the board and the selection unchanged.
+    pub fn paste(&mut self, game: &mut GameOfLife, pattern: &Pattern, x: i32, y: i32) {
+        let cells: Vec<(i32, i32)> = pattern
+            .cells()
+            .iter()
+            .filter_map(|&(cx, cy)| Some((x.checked_add(cx)?, y.checked_add(cy)?)))
+            .collect();
+        if cells.is_empty() {
+            return;
+        }
+        if let Some(bounds) = BoundingBox::of(cells.iter().copied().chain([(x, y)])) {
+            self.bounds = bounds;
+        }
+        game.set_cells(cells);
+    }
+
+    /// Makes every cell inside the selection alive.
+    pub fn fill(&self, game: &mut GameOfLife) {
+        let BoundingBox {
+            min_x,
+            min_y,
+            max_x,
+            max_y,
+        } = self.bounds;
+        game.set_cells((min_y..=max_y).flat_map(|y| (min_x..=max_x).map(move |x| (x, y))));
+    }
+
+    /// Kills every cell inside the selection.
+    pub fn clear(&self, game: &mut GameOfLife) {
+        let BoundingBox {
+            min_x,
+            min_y,
+            max_x,
+            max_y,
+        } = self.bounds;
+        game.clear_region(min_x..=max_x, min_y..=max_y);
+    }
+}
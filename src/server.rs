@@ -0,0 +1,595 @@
+//! A WebSocket server that streams a running board to remote clients and accepts commands.
+//!
+//! Every connected client shares one simulation. After each generation and each command the server
+//! broadcasts a binary frame: a little-endian header of generation (`u64`), population (`u64`),
+//! births (`u32`), deaths (`u32`), width (`u32`), and height (`u32`), followed by the viewport's
+//! cells as `cells_at` bytes, row by row from the viewport's smallest `y`.
+//!
+//! Clients send text messages, one command each:
+//!
+//! - `toggle X Y` flips a cell.
+//! - `pause`, `resume`, and `step` control the simulation.
+//! - `speed MS` sets the time between generations.
+//! - `view X0 Y0 X1 Y1` moves the streamed region, which may hold at most `MAX_VIEW_CELLS` cells.
+//! - `load` followed by a newline and pattern text, in any format `Format::detect` recognizes,
+//!   replaces the board.
+//!
+//! The server uses plain threads, two per client plus one for the simulation, rather than an async
+//! runtime, so the feature adds no dependencies. Each client's frames go through a short queue that
+//! its own writer thread drains, so a slow client misses frames instead of stalling the others. At
+//! most `MAX_CLIENTS` clients are served at once, and oversized handshakes are refused.
+
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{Shutdown, SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver, SyncSender, TrySendError};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread;
+use std::time::Duration;
+
+use crate::encoding::encode_base64;
+use crate::{BoundingBox, CellBuffer, Format, GameOfLife, Pattern, PatternError, Viewport};
+
+/// Appended to a client's key before hashing, per RFC 6455.
+const WEBSOCKET_GUID: &str = "258EAFA5-E914-47DA-95CA-C5AB0DC85B11";
+
+/// Largest message accepted from a client, so a bad client cannot exhaust memory.
+const MAX_MESSAGE_LEN: u64 = 16 << 20;
+
+/// Largest HTTP upgrade request accepted, request line and headers together.
+const MAX_HANDSHAKE_LEN: u64 = 16 << 10;
+
+/// Most clients served at once; later connections are refused until one leaves.
+pub const MAX_CLIENTS: usize = 64;
+
+/// Largest region a client may view, so a `view` command cannot make every frame gigabytes long.
+pub const MAX_VIEW_CELLS: u64 = 1 << 22;
+
+/// Frames a client may fall behind by before further broadcasts skip it.
+const QUEUED_FRAMES: usize = 4;
+
+/// How long a write to a client may block before the client is disconnected.
+const WRITE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A client request, parsed from the text protocol described in the module docs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Toggle(i32, i32),
+    Pause,
+    Resume,
+    Step,
+    Speed(Duration),
+    View(BoundingBox),
+    Load(Pattern),
+}
+
+/// Reasons a client message is not a valid `Command`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommandError {
+    /// The first word is not a known command.
+    Unknown(String),
+    /// The command's arguments are missing or are not numbers.
+    InvalidArguments,
+    /// The pattern text of a `load` command did not parse.
+    Pattern(PatternError),
+    /// A `view` region holds more than `MAX_VIEW_CELLS` cells.
+    ViewTooLarge,
+}
+
+impl fmt::Display for CommandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CommandError::Unknown(command) => write!(f, "unknown command {command:?}"),
+            CommandError::InvalidArguments => write!(f, "invalid arguments"),
+            CommandError::Pattern(err) => write!(f, "invalid pattern: {err}"),
+            CommandError::ViewTooLarge => write!(f, "view larger than {MAX_VIEW_CELLS} cells"),
+        }
+    }
+}
+
+impl Error for CommandError {}
+
+impl FromStr for Command {
+    type Err = CommandError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let (head, body) = text.split_once('\n').unwrap_or((text, ""));
+        let mut words = head.split_whitespace();
+        let command = words.next().unwrap_or_default();
+        let numbers: Vec<i64> = words
+            .map(str::parse)
+            .collect::<Result<_, _>>()
+            .map_err(|_| CommandError::InvalidArguments)?;
+        let coordinate = |n: i64| i32::try_from(n).map_err(|_| CommandError::InvalidArguments);
+
+        match (command, numbers.as_slice()) {
+            ("toggle", &[x, y]) => Ok(Command::Toggle(coordinate(x)?, coordinate(y)?)),
+            ("pause", []) => Ok(Command::Pause),
+            ("resume", []) => Ok(Command::Resume),
+            ("step", []) => Ok(Command::Step),
+            ("speed", &[ms]) => u64::try_from(ms)
+                .map(|ms| Command::Speed(Duration::from_millis(ms)))
+                .map_err(|_| CommandError::InvalidArguments),
+            ("view", &[x0, y0, x1, y1]) => {
+                let bounds = BoundingBox::from_ranges(
+                    &(coordinate(x0)?..=coordinate(x1)?),
+                    &(coordinate(y0)?..=coordinate(y1)?),
+                );
+                view_size(&bounds).ok_or(CommandError::ViewTooLarge)?;
+                Ok(Command::View(bounds))
+            }
+            ("load", []) => Pattern::parse(body, Format::detect(body))
+                .map(Command::Load)
+                .map_err(CommandError::Pattern),
+            ("toggle" | "pause" | "resume" | "step" | "speed" | "view" | "load", _) => {
+                Err(CommandError::InvalidArguments)
+            }
+            (command, _) => Err(CommandError::Unknown(command.to_string())),
+        }
+    }
+}
+
+/// The simulation every client sees.
+struct Session {
+    game: GameOfLife,
+    viewport: Viewport,
+    paused: bool,
+    delay: Duration,
+    buffer: CellBuffer,
+}
+
+impl Session {
+    fn apply(&mut self, command: Command) {
+        match command {
+            Command::Toggle(x, y) => {
+                self.game.toggle(x, y);
+            }
+            Command::Pause => self.paused = true,
+            Command::Resume => self.paused = false,
+            Command::Step => self.game.tick(),
+            Command::Speed(delay) => self.delay = delay,
+            Command::View(bounds) => {
+                self.viewport =
+                    Viewport::new(bounds.min_x..=bounds.max_x, bounds.min_y..=bounds.max_y);
+            }
+            Command::Load(pattern) => {
+                let topology = self.game.topology();
+                self.game = pattern.to_game();
+                self.game.set_topology(topology);
+                self.game.set_history_limit(0);
+            }
+        }
+    }
+
+    /// Encodes the binary frame described in the module docs.
+    fn frame(&mut self) -> Vec<u8> {
+        let bounds = BoundingBox::from_ranges(&self.viewport.x_range(), &self.viewport.y_range());
+        let (width, height) = view_size(&bounds).expect("views are checked when set");
+        self.game
+            .fill_cells_at(&mut self.buffer, width, height, bounds.min_x, bounds.min_y);
+
+        let stats = self.game.stats();
+        let mut frame = Vec::with_capacity(32 + self.buffer.len());
+        frame.extend_from_slice(&stats.generation.to_le_bytes());
+        frame.extend_from_slice(&(stats.population as u64).to_le_bytes());
+        frame.extend_from_slice(&(stats.births as u32).to_le_bytes());
+        frame.extend_from_slice(&(stats.deaths as u32).to_le_bytes());
+        frame.extend_from_slice(&(width as u32).to_le_bytes());
+        frame.extend_from_slice(&(height as u32).to_le_bytes());
+        frame.extend_from_slice(self.buffer.as_slice());
+        frame
+    }
+}
+
+/// The sending end of a client's frame queue, drained by its writer thread.
+struct Client {
+    id: u64,
+    frames: SyncSender<Arc<[u8]>>,
+}
+
+struct Shared {
+    session: Mutex<Session>,
+    clients: Mutex<Vec<Client>>,
+    next_client: AtomicU64,
+    /// Connections with a running client thread, counted from accept rather than from `join` so
+    /// clients still handshaking count too.
+    connections: AtomicUsize,
+}
+
+impl Shared {
+    fn session(&self) -> MutexGuard<'_, Session> {
+        self.session
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn clients(&self) -> MutexGuard<'_, Vec<Client>> {
+        self.clients
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Queues the current frame for every client, dropping those that have disconnected.
+    ///
+    /// Only queueing happens under the locks, so frames stay in order without waiting on sockets.
+    /// A client whose queue is full skips the frame; the next one replaces the whole view anyway.
+    fn broadcast(&self, session: &mut Session) {
+        let message: Arc<[u8]> = encode_frame(OPCODE_BINARY, &session.frame()).into();
+        self.clients().retain(
+            |client| match client.frames.try_send(Arc::clone(&message)) {
+                Ok(()) | Err(TrySendError::Full(_)) => true,
+                Err(TrySendError::Disconnected(_)) => false,
+            },
+        );
+    }
+
+    /// Registers a client's frame queue and queues the current frame for it.
+    fn join(&self, frames: SyncSender<Arc<[u8]>>) -> u64 {
+        let mut session = self.session();
+        let id = self.next_client.fetch_add(1, Ordering::Relaxed);
+        let message = encode_frame(OPCODE_BINARY, &session.frame());
+        // The queue is empty, so this cannot fail for lack of room.
+        let _ = frames.try_send(message.into());
+        self.clients().push(Client { id, frames });
+        id
+    }
+
+    /// Stops broadcasting to a client, letting its writer thread finish once the queue drains.
+    fn leave(&self, id: u64) {
+        self.clients().retain(|client| client.id != id);
+    }
+}
+
+/// Streams a board to WebSocket clients. See the module docs for the protocol.
+pub struct Server {
+    listener: TcpListener,
+    shared: Arc<Shared>,
+}
+
+impl Server {
+    /// Listens on `addr`, serving `game` as seen through `viewport`, one generation every 100 ms.
+    ///
+    /// The board's undo history is disabled, since remote clients have no way to use it. Fails with
+    /// `InvalidInput` if the viewport holds more than `MAX_VIEW_CELLS` cells.
+    pub fn bind(
+        addr: impl ToSocketAddrs,
+        mut game: GameOfLife,
+        viewport: Viewport,
+    ) -> io::Result<Self> {
+        let bounds = BoundingBox::from_ranges(&viewport.x_range(), &viewport.y_range());
+        if view_size(&bounds).is_none() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                CommandError::ViewTooLarge,
+            ));
+        }
+        game.set_history_limit(0);
+        Ok(Self {
+            listener: TcpListener::bind(addr)?,
+            shared: Arc::new(Shared {
+                session: Mutex::new(Session {
+                    game,
+                    viewport,
+                    paused: false,
+                    delay: Duration::from_millis(100),
+                    buffer: CellBuffer::new(),
+                }),
+                clients: Mutex::new(Vec::new()),
+                next_client: AtomicU64::new(0),
+                connections: AtomicUsize::new(0),
+            }),
+        })
+    }
+
+    /// Sets the time between generations until a client sends `speed`.
+    pub fn with_delay(self, delay: Duration) -> Self {
+        self.shared.session().delay = delay;
+        self
+    }
+
+    /// Starts with the simulation paused until a client sends `resume`.
+    pub fn paused(self) -> Self {
+        self.shared.session().paused = true;
+        self
+    }
+
+    /// Returns the address the server is listening on, useful after binding port 0.
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Runs the simulation and serves clients until accepting a connection fails.
+    pub fn run(self) -> io::Result<()> {
+        let shared = Arc::clone(&self.shared);
+        thread::spawn(move || {
+            loop {
+                let delay = shared.session().delay;
+                thread::sleep(delay);
+                let mut session = shared.session();
+                if !session.paused {
+                    session.game.tick();
+                    shared.broadcast(&mut session);
+                }
+            }
+        });
+
+        for stream in self.listener.incoming() {
+            let mut stream = stream?;
+            if self.shared.connections.fetch_add(1, Ordering::Relaxed) >= MAX_CLIENTS {
+                self.shared.connections.fetch_sub(1, Ordering::Relaxed);
+                let _ = stream.write_all(
+                    b"HTTP/1.1 503 Service Unavailable\r\nContent-Length: 0\r\n\r\n",
+                );
+                continue;
+            }
+            let shared = Arc::clone(&self.shared);
+            thread::spawn(move || {
+                // A failed handshake or dropped connection only affects that client.
+                let _ = serve_client(stream, &shared);
+                shared.connections.fetch_sub(1, Ordering::Relaxed);
+            });
+        }
+        Ok(())
+    }
+}
+
+fn serve_client(stream: TcpStream, shared: &Shared) -> io::Result<()> {
+    stream.set_write_timeout(Some(WRITE_TIMEOUT))?;
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut writer = stream;
+    handshake(&mut reader, &mut writer)?;
+
+    let (frames, queue) = mpsc::sync_channel(QUEUED_FRAMES);
+    thread::spawn(move || write_frames(writer, queue));
+    let id = shared.join(frames.clone());
+    let result = serve_commands(&mut reader, &frames, shared);
+    shared.leave(id);
+    result
+}
+
+/// Answers a client's messages until it closes the connection.
+fn serve_commands(
+    reader: &mut impl Read,
+    frames: &SyncSender<Arc<[u8]>>,
+    shared: &Shared,
+) -> io::Result<()> {
+    while let Some(message) = read_message(reader, frames)? {
+        let reply = match String::from_utf8(message) {
+            Ok(text) => text.parse::<Command>().map_err(|err| err.to_string()),
+            Err(_) => Err("commands must be UTF-8 text".to_string()),
+        };
+        match reply {
+            Ok(command) => {
+                let mut session = shared.session();
+                session.apply(command);
+                shared.broadcast(&mut session);
+            }
+            Err(err) => send(frames, encode_frame(OPCODE_TEXT, err.as_bytes()))?,
+        }
+    }
+    Ok(())
+}
+
+/// Writes a client's queued frames until every sender is gone or a write fails, then closes the
+/// connection so its reader stops too.
+fn write_frames(mut stream: TcpStream, queue: Receiver<Arc<[u8]>>) {
+    for frame in queue {
+        if stream.write_all(&frame).is_err() {
+            break;
+        }
+    }
+    let _ = stream.shutdown(Shutdown::Both);
+}
+
+/// Queues a reply for the client, waiting for room rather than dropping it.
+fn send(frames: &SyncSender<Arc<[u8]>>, frame: Vec<u8>) -> io::Result<()> {
+    frames
+        .send(frame.into())
+        .map_err(|_| io::ErrorKind::BrokenPipe.into())
+}
+
+/// The width and height of `bounds`, or `None` if it holds more than `MAX_VIEW_CELLS` cells.
+fn view_size(bounds: &BoundingBox) -> Option<(i32, i32)> {
+    let width = u64::from(bounds.max_x.abs_diff(bounds.min_x)) + 1;
+    let height = u64::from(bounds.max_y.abs_diff(bounds.min_y)) + 1;
+    if width.checked_mul(height)? > MAX_VIEW_CELLS {
+        return None;
+    }
+    Some((i32::try_from(width).ok()?, i32::try_from(height).ok()?))
+}
+
+/// Reads the HTTP upgrade request and answers with `101 Switching Protocols`.
+///
+/// Requests longer than `MAX_HANDSHAKE_LEN` are answered with `431` and rejected.
+fn handshake(reader: &mut impl BufRead, writer: &mut impl Write) -> io::Result<()> {
+    let mut request = Read::take(reader, MAX_HANDSHAKE_LEN);
+    let mut key = None;
+    let mut line = String::new();
+    loop {
+        line.clear();
+        if request.read_line(&mut line)? == 0 || !line.ends_with('\n') {
+            if request.limit() == 0 {
+                writer.write_all(
+                    b"HTTP/1.1 431 Request Header Fields Too Large\r\n\
+                      Content-Length: 0\r\n\r\n",
+                )?;
+                return Err(io::Error::new(io::ErrorKind::InvalidData, "handshake too long"));
+            }
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':')
+            && name.trim().eq_ignore_ascii_case("sec-websocket-key")
+        {
+            key = Some(value.trim().to_string());
+        }
+    }
+
+    let Some(key) = key else {
+        writer.write_all(b"HTTP/1.1 400 Bad Request\r\nContent-Length: 0\r\n\r\n")?;
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "missing Sec-WebSocket-Key",
+        ));
+    };
+    write!(
+        writer,
+        "HTTP/1.1 101 Switching Protocols\r\nUpgrade: websocket\r\nConnection: Upgrade\r\n\
+         Sec-WebSocket-Accept: {}\r\n\r\n",
+        accept_key(&key)
+    )
+}
+
+/// The `Sec-WebSocket-Accept` value for a client's `Sec-WebSocket-Key`.
+fn accept_key(key: &str) -> String {
+    let digest = sha1(format!("{key}{WEBSOCKET_GUID}").as_bytes());
+    // The handshake uses standard padded base64 rather than the URL-safe alphabet.
+    let encoded = encode_base64(&digest).replace('-', "+").replace('_', "/");
+    format!("{encoded}=")
+}
+
+const OPCODE_CONTINUATION: u8 = 0x0;
+const OPCODE_TEXT: u8 = 0x1;
+const OPCODE_BINARY: u8 = 0x2;
+const OPCODE_CLOSE: u8 = 0x8;
+const OPCODE_PING: u8 = 0x9;
+const OPCODE_PONG: u8 = 0xa;
+
+/// Wraps `payload` in a single unmasked frame, as servers send them.
+fn encode_frame(opcode: u8, payload: &[u8]) -> Vec<u8> {
+    let mut frame = vec![0x80 | opcode];
+    match payload.len() {
+        len @ 0..=125 => frame.push(len as u8),
+        len @ 126..=0xffff => {
+            frame.push(126);
+            frame.extend_from_slice(&(len as u16).to_be_bytes());
+        }
+        len => {
+            frame.push(127);
+            frame.extend_from_slice(&(len as u64).to_be_bytes());
+        }
+    }
+    frame.extend_from_slice(payload);
+    frame
+}
+
+/// Reads the next text or binary message, answering pings along the way. Returns `None` once the
+/// client closes the connection.
+fn read_message(
+    reader: &mut impl Read,
+    frames: &SyncSender<Arc<[u8]>>,
+) -> io::Result<Option<Vec<u8>>> {
+    let mut message = Vec::new();
+    loop {
+        let mut header = [0; 2];
+        match reader.read_exact(&mut header) {
+            Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+            result => result?,
+        }
+        let fin = header[0] & 0x80 != 0;
+        let opcode = header[0] & 0x0f;
+        let masked = header[1] & 0x80 != 0;
+        let len = match header[1] & 0x7f {
+            126 => {
+                let mut len = [0; 2];
+                reader.read_exact(&mut len)?;
+                u64::from(u16::from_be_bytes(len))
+            }
+            127 => {
+                let mut len = [0; 8];
+                reader.read_exact(&mut len)?;
+                u64::from_be_bytes(len)
+            }
+            len => u64::from(len),
+        };
+        if message.len() as u64 + len > MAX_MESSAGE_LEN {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "message too large",
+            ));
+        }
+        let mut mask = [0; 4];
+        if masked {
+            reader.read_exact(&mut mask)?;
+        }
+        let mut payload = vec![0; len as usize];
+        reader.read_exact(&mut payload)?;
+        for (i, byte) in payload.iter_mut().enumerate() {
+            *byte ^= mask[i % 4];
+        }
+
+        match opcode {
+            OPCODE_CLOSE => {
+                send(frames, encode_frame(OPCODE_CLOSE, &[]))?;
+                return Ok(None);
+            }
+            OPCODE_PING => send(frames, encode_frame(OPCODE_PONG, &payload))?,
+            OPCODE_PONG => {}
+            OPCODE_TEXT | OPCODE_BINARY | OPCODE_CONTINUATION => {
+                message.extend_from_slice(&payload);
+                if fin {
+                    return Ok(Some(message));
+                }
+            }
+            _ => return Err(io::Error::new(io::ErrorKind::InvalidData, "unknown opcode")),
+        }
+    }
+}
+
+/// SHA-1, needed only for the WebSocket handshake.
+fn sha1(data: &[u8]) -> [u8; 20] {
+    let mut h: [u32; 5] = [
+        0x6745_2301,
+        0xefcd_ab89,
+        0x98ba_dcfe,
+        0x1032_5476,
+        0xc3d2_e1f0,
+    ];
+    let mut padded = data.to_vec();
+    padded.push(0x80);
+    while padded.len() % 64 != 56 {
+        padded.push(0);
+    }
+    padded.extend_from_slice(&(data.len() as u64 * 8).to_be_bytes());
+
+    for block in padded.chunks(64) {
+        let mut w = [0u32; 80];
+        for (i, word) in block.chunks(4).enumerate() {
+            w[i] = u32::from_be_bytes(word.try_into().unwrap());
+        }
+        for i in 16..80 {
+            w[i] = (w[i - 3] ^ w[i - 8] ^ w[i - 14] ^ w[i - 16]).rotate_left(1);
+        }
+        let [mut a, mut b, mut c, mut d, mut e] = h;
+        for (i, &word) in w.iter().enumerate() {
+            let (f, k) = match i {
+                0..20 => ((b & c) | (!b & d), 0x5a82_7999),
+                20..40 => (b ^ c ^ d, 0x6ed9_eba1),
+                40..60 => ((b & c) | (b & d) | (c & d), 0x8f1b_bcdc),
+                _ => (b ^ c ^ d, 0xca62_c1d6),
+            };
+            let temp = a
+                .rotate_left(5)
+                .wrapping_add(f)
+                .wrapping_add(e)
+                .wrapping_add(k)
+                .wrapping_add(word);
+            (e, d, c, b, a) = (d, c, b.rotate_left(30), a, temp);
+        }
+        for (h, v) in h.iter_mut().zip([a, b, c, d, e]) {
+            *h = h.wrapping_add(v);
+        }
+    }
+
+    let mut digest = [0; 20];
+    for (chunk, word) in digest.chunks_mut(4).zip(h) {
+        chunk.copy_from_slice(&word.to_be_bytes());
+    }
+    digest
+}
@@ -0,0 +1,206 @@
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::time::Instant;
+
+use crate::GameOfLife;
+
+/// Generations `update` runs at most at once unless changed with `set_max_catch_up`.
+const MAX_CATCH_UP: u64 = 4;
+
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+/// Drives a board at a steady pace from a frontend's event or frame loop.
+///
+/// Call `update` with the time since the last call, or `update_now` to have it measured, and the
+/// simulation runs however many generations came due. When the host stalls, it catches up by at
+/// most `max_catch_up` generations per update and drops the rest of the backlog, so a slow frame
+/// slows the simulation down instead of making it lurch forward.
+#[derive(Debug, Clone)]
+pub struct Simulation {
+    game: GameOfLife,
+    interval: Duration,
+    running: bool,
+    /// Time passed while running that has not yet been spent on generations.
+    elapsed: Duration,
+    max_catch_up: u64,
+    /// Generation at which a `run_for` run pauses.
+    pause_at: Option<u64>,
+    #[cfg(feature = "std")]
+    last_update: Option<Instant>,
+}
+
+impl Simulation {
+    /// Wraps `game`, paused, at 10 generations per second.
+    pub fn new(game: GameOfLife) -> Self {
+        Self {
+            game,
+            interval: Duration::from_millis(100),
+            running: false,
+            elapsed: Duration::ZERO,
+            max_catch_up: MAX_CATCH_UP,
+            pause_at: None,
+            #[cfg(feature = "std")]
+            last_update: None,
+        }
+    }
+
+    pub fn game(&self) -> &GameOfLife {
+        &self.game
+    }
+
+    /// Returns the board for editing between updates.
+    pub fn game_mut(&mut self) -> &mut GameOfLife {
+        &mut self.game
+    }
+
+    pub fn into_game(self) -> GameOfLife {
+        self.game
+    }
+
+    /// Returns the time between generations.
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Sets the time between generations. A zero interval runs `max_catch_up` generations on
+    /// every update.
+    pub fn set_interval(&mut self, interval: Duration) {
+        self.interval = interval;
+    }
+
+    /// Returns the target speed in generations per second, or infinity for a zero interval.
+    pub fn rate(&self) -> f64 {
+        1.0 / self.interval.as_secs_f64()
+    }
+
+    /// Sets the target speed in generations per second.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rate` is not positive.
+    pub fn set_rate(&mut self, rate: f64) {
+        assert!(rate > 0.0, "rate must be positive");
+        self.interval = Duration::try_from_secs_f64(1.0 / rate).unwrap_or(Duration::MAX);
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.running
+    }
+
+    /// Stops running generations on `update`.
+    pub fn pause(&mut self) {
+        self.running = false;
+        self.pause_at = None;
+    }
+
+    /// Starts running generations on `update`. The first one comes due one interval later.
+    pub fn resume(&mut self) {
+        if !self.running {
+            self.running = true;
+            self.elapsed = Duration::ZERO;
+            #[cfg(feature = "std")]
+            {
+                self.last_update = None;
+            }
+        }
+    }
+
+    /// Pauses if running and resumes otherwise.
+    pub fn toggle(&mut self) {
+        if self.running {
+            self.pause();
+        } else {
+            self.resume();
+        }
+    }
+
+    /// Resumes and pauses again once `generations` more have run.
+    pub fn run_for(&mut self, generations: u64) {
+        if generations == 0 {
+            return;
+        }
+        self.resume();
+        self.pause_at = Some(self.game.generation().saturating_add(generations));
+    }
+
+    /// Pauses and advances exactly one generation.
+    pub fn step(&mut self) {
+        self.pause();
+        self.game.tick();
+    }
+
+    /// Advances `generations` generations at once, as a single undoable change, without changing
+    /// whether the simulation is running.
+    pub fn fast_forward(&mut self, generations: u64) {
+        self.game.advance(generations);
+    }
+
+    /// Returns how many generations one update runs at most.
+    pub fn max_catch_up(&self) -> u64 {
+        self.max_catch_up
+    }
+
+    /// Sets how many generations one update runs at most; 0 is treated as 1.
+    pub fn set_max_catch_up(&mut self, generations: u64) {
+        self.max_catch_up = generations.max(1);
+    }
+
+    /// Accounts for `elapsed` time and runs the generations that came due, each as its own undo
+    /// step. Returns how many ran. While paused, time does not accumulate.
+    pub fn update(&mut self, elapsed: Duration) -> u64 {
+        if !self.running {
+            self.elapsed = Duration::ZERO;
+            return 0;
+        }
+        self.elapsed = self.elapsed.saturating_add(elapsed);
+        let (due, remainder) = match self.interval.as_nanos() {
+            0 => (u128::MAX, 0),
+            interval => (
+                self.elapsed.as_nanos() / interval,
+                self.elapsed.as_nanos() % interval,
+            ),
+        };
+        let mut generations = if due > u128::from(self.max_catch_up) {
+            self.elapsed = Duration::ZERO;
+            self.max_catch_up
+        } else {
+            self.elapsed = Duration::new(
+                (remainder / NANOS_PER_SEC) as u64,
+                (remainder % NANOS_PER_SEC) as u32,
+            );
+            due as u64
+        };
+        if let Some(pause_at) = self.pause_at {
+            generations = generations.min(pause_at.saturating_sub(self.game.generation()));
+        }
+
+        for _ in 0..generations {
+            self.game.tick();
+        }
+        if self
+            .pause_at
+            .is_some_and(|pause_at| self.game.generation() >= pause_at)
+        {
+            self.pause();
+        }
+        generations
+    }
+
+    /// Like `update`, measuring the time since the previous `update_now` call or `resume`.
+    #[cfg(feature = "std")]
+    pub fn update_now(&mut self) -> u64 {
+        let now = Instant::now();
+        let elapsed = self
+            .last_update
+            .map_or(Duration::ZERO, |last| now.duration_since(last));
+        self.last_update = Some(now);
+        self.update(elapsed)
+    }
+
+    /// Returns how long until the next generation comes due, or `None` while paused. Frontends can
+    /// sleep or wait for input this long before the next update.
+    pub fn until_next(&self) -> Option<Duration> {
+        self.running
+            .then(|| self.interval.saturating_sub(self.elapsed))
+    }
+}
@@ -0,0 +1,328 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::error::Error;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::{fs, io, path::Path};
+
+use hashbrown::HashSet;
+
+use crate::encoding::{read_uvarint, read_varint, write_uvarint, write_varint};
+use crate::{ColorMode, CoordLimit, DecodeError, GameOfLife, Rule, RuleRegion, Snapshot, Topology};
+
+const MAGIC: &[u8; 4] = b"GOLS";
+/// Current format version. Version 1 lacked color modes and cell colors.
+const VERSION: u8 = 2;
+
+/// Reasons a session snapshot could not be saved or loaded.
+#[derive(Debug)]
+pub enum SnapshotError {
+    /// Reading or writing the file failed.
+    #[cfg(feature = "std")]
+    Io(io::Error),
+    /// The data does not start with the snapshot signature.
+    NotASnapshot,
+    /// The snapshot was written by a newer, unsupported format version.
+    UnsupportedVersion(u8),
+    /// The data ended early or held a malformed number.
+    Truncated,
+    /// A stored rulestring could not be parsed.
+    InvalidRule,
+    /// A stored topology was unknown or had a non-positive size.
+    InvalidTopology,
+    /// A stored color mode was unknown.
+    InvalidColorMode,
+    /// A stored cell lay off the board or outside its coordinate limit.
+    InvalidCell,
+}
+
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            #[cfg(feature = "std")]
+            SnapshotError::Io(err) => write!(f, "{err}"),
+            SnapshotError::NotASnapshot => write!(f, "not a session snapshot"),
+            SnapshotError::UnsupportedVersion(version) => {
+                write!(f, "unsupported snapshot version {version}")
+            }
+            SnapshotError::Truncated => write!(f, "snapshot is truncated"),
+            SnapshotError::InvalidRule => write!(f, "snapshot holds an invalid rule"),
+            SnapshotError::InvalidTopology => write!(f, "snapshot holds an invalid topology"),
+            SnapshotError::InvalidColorMode => write!(f, "snapshot holds an invalid color mode"),
+            SnapshotError::InvalidCell => write!(f, "snapshot holds a cell off the board"),
+        }
+    }
+}
+
+impl Error for SnapshotError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            #[cfg(feature = "std")]
+            SnapshotError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<io::Error> for SnapshotError {
+    fn from(err: io::Error) -> Self {
+        SnapshotError::Io(err)
+    }
+}
+
+impl From<DecodeError> for SnapshotError {
+    fn from(_: DecodeError) -> Self {
+        SnapshotError::Truncated
+    }
+}
+
+impl GameOfLife {
+    /// Encodes the whole session in a versioned binary format: live, decaying, and colored cells,
+    /// the generation counter, rule, rule regions, topology, coordinate limit, color mode, history
+    /// limit, and the undo/redo history. Call `clear_history` first to save only the current state.
+    ///
+    /// Cell ages, statistics, observers, and the `cells_at` cache are not stored, so a restored
+    /// board starts every cell at age 0.
+    pub fn to_snapshot(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        out.extend_from_slice(MAGIC);
+        out.push(VERSION);
+        write_rule(&mut out, self.rule);
+        match self.topology {
+            Topology::Infinite => out.push(0),
+            Topology::Bounded { width, height } => {
+                out.push(1);
+                write_varint(&mut out, width);
+                write_varint(&mut out, height);
+            }
+            Topology::Torus { width, height } => {
+                out.push(2);
+                write_varint(&mut out, width);
+                write_varint(&mut out, height);
+            }
+        }
+        match self.coord_limit {
+            Some(limit) => {
+                out.push(1);
+                write_uvarint(&mut out, limit.extent().into());
+            }
+            None => out.push(0),
+        }
+        out.push(match self.color_mode {
+            ColorMode::Mono => 0,
+            ColorMode::Immigration => 1,
+            ColorMode::QuadLife => 2,
+        });
+        write_uvarint(&mut out, self.rule_regions.len() as u64);
+        for region in &self.rule_regions {
+            write_varint(&mut out, *region.x_range.start());
+            write_varint(&mut out, *region.x_range.end());
+            write_varint(&mut out, *region.y_range.start());
+            write_varint(&mut out, *region.y_range.end());
+            write_rule(&mut out, region.rule);
+        }
+        write_uvarint(&mut out, self.history_limit as u64);
+        write_uvarint(&mut out, self.generation);
+        write_board(&mut out, &self.state, &self.decay, &self.colors);
+
+        for snapshots in [
+            self.history.iter().collect::<Vec<_>>(),
+            self.redo.iter().collect(),
+        ] {
+            write_uvarint(&mut out, snapshots.len() as u64);
+            for snapshot in snapshots {
+                write_uvarint(&mut out, snapshot.generation);
+                write_board(&mut out, &snapshot.state, &snapshot.decay, &snapshot.colors);
+            }
+        }
+        out
+    }
+
+    /// Restores a session encoded by `to_snapshot`.
+    pub fn from_snapshot(bytes: &[u8]) -> Result<Self, SnapshotError> {
+        let body = bytes
+            .strip_prefix(MAGIC)
+            .ok_or(SnapshotError::NotASnapshot)?;
+        let (&version, body) = body.split_first().ok_or(SnapshotError::Truncated)?;
+        if !(1..=VERSION).contains(&version) {
+            return Err(SnapshotError::UnsupportedVersion(version));
+        }
+        let colored = version >= 2;
+        let bytes = &mut body.iter().copied();
+
+        let mut game = GameOfLife::new();
+        game.rule = read_rule(bytes)?;
+        game.topology = match bytes.next().ok_or(SnapshotError::Truncated)? {
+            0 => Topology::Infinite,
+            kind @ (1 | 2) => {
+                let (width, height) = (read_varint(bytes)?, read_varint(bytes)?);
+                if width <= 0 || height <= 0 {
+                    return Err(SnapshotError::InvalidTopology);
+                }
+                if kind == 1 {
+                    Topology::Bounded { width, height }
+                } else {
+                    Topology::Torus { width, height }
+                }
+            }
+            _ => return Err(SnapshotError::InvalidTopology),
+        };
+        game.coord_limit = match bytes.next().ok_or(SnapshotError::Truncated)? {
+            0 => None,
+            _ => {
+                let extent = read_uvarint(bytes)?;
+                let extent = u32::try_from(extent).map_err(|_| SnapshotError::Truncated)?;
+                Some(CoordLimit::new(extent))
+            }
+        };
+        if colored {
+            game.color_mode = match bytes.next().ok_or(SnapshotError::Truncated)? {
+                0 => ColorMode::Mono,
+                1 => ColorMode::Immigration,
+                2 => ColorMode::QuadLife,
+                _ => return Err(SnapshotError::InvalidColorMode),
+            };
+        }
+        for _ in 0..read_uvarint(bytes)? {
+            let x_range = read_varint(bytes)?..=read_varint(bytes)?;
+            let y_range = read_varint(bytes)?..=read_varint(bytes)?;
+            let rule = read_rule(bytes)?;
+            game.rule_regions.push(RuleRegion {
+                x_range,
+                y_range,
+                rule,
+            });
+        }
+        let history_limit = read_uvarint(bytes)? as usize;
+        game.generation = read_uvarint(bytes)?;
+        game.ages_generation = game.generation;
+        (game.state, game.decay, game.colors) = read_board(bytes, colored)?;
+        check_board(&game, &game.state, &game.decay, &game.colors)?;
+
+        for redo in [false, true] {
+            for _ in 0..read_uvarint(bytes)? {
+                let generation = read_uvarint(bytes)?;
+                let (state, decay, colors) = read_board(bytes, colored)?;
+                check_board(&game, &state, &decay, &colors)?;
+                let snapshot = Snapshot {
+                    state,
+                    decay,
+                    colors,
+                    ages: Default::default(),
+                    generation,
+                };
+                if redo {
+                    game.redo.push(snapshot);
+                } else {
+                    game.history.push_back(snapshot);
+                }
+            }
+        }
+        game.set_history_limit(history_limit);
+        Ok(game)
+    }
+
+    /// Writes the session to `path` as `to_snapshot` encodes it, replacing any existing file.
+    #[cfg(feature = "std")]
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), SnapshotError> {
+        fs::write(path, self.to_snapshot())?;
+        Ok(())
+    }
+
+    /// Reads a session written by `save`.
+    #[cfg(feature = "std")]
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, SnapshotError> {
+        Self::from_snapshot(&fs::read(path)?)
+    }
+}
+
+fn write_rule(out: &mut Vec<u8>, rule: Rule) {
+    let text = rule.to_string();
+    write_uvarint(out, text.len() as u64);
+    out.extend_from_slice(text.as_bytes());
+}
+
+fn read_rule(bytes: &mut impl Iterator<Item = u8>) -> Result<Rule, SnapshotError> {
+    let len = read_uvarint(bytes)?;
+    let text: Vec<u8> = bytes.take(len as usize).collect();
+    if text.len() as u64 != len {
+        return Err(SnapshotError::Truncated);
+    }
+    let text = String::from_utf8(text).map_err(|_| SnapshotError::InvalidRule)?;
+    text.parse().map_err(|_| SnapshotError::InvalidRule)
+}
+
+/// Writes `cells` sorted, so equal boards always encode to the same bytes.
+fn write_cells(out: &mut Vec<u8>, cells: &HashSet<(i32, i32)>) {
+    let mut cells: Vec<(i32, i32)> = cells.iter().copied().collect();
+    cells.sort_unstable();
+    write_uvarint(out, cells.len() as u64);
+    for (x, y) in cells {
+        write_varint(out, x);
+        write_varint(out, y);
+    }
+}
+
+fn read_cells(bytes: &mut impl Iterator<Item = u8>) -> Result<HashSet<(i32, i32)>, SnapshotError> {
+    (0..read_uvarint(bytes)?)
+        .map(|_| Ok((read_varint(bytes)?, read_varint(bytes)?)))
+        .collect()
+}
+
+/// Writes the live cells, then the decay and color layers, each prefixed by its layer count.
+fn write_board(
+    out: &mut Vec<u8>,
+    state: &HashSet<(i32, i32)>,
+    decay: &[HashSet<(i32, i32)>],
+    colors: &[HashSet<(i32, i32)>],
+) {
+    write_cells(out, state);
+    for layers in [decay, colors] {
+        write_uvarint(out, layers.len() as u64);
+        for layer in layers {
+            write_cells(out, layer);
+        }
+    }
+}
+
+/// Checks that every cell of a decoded board is one `game` could hold: normalized for its
+/// topology and within its coordinate limit.
+fn check_board(
+    game: &GameOfLife,
+    state: &HashSet<(i32, i32)>,
+    decay: &[HashSet<(i32, i32)>],
+    colors: &[HashSet<(i32, i32)>],
+) -> Result<(), SnapshotError> {
+    let fits = |&(x, y): &(i32, i32)| {
+        game.topology.normalize(x, y) == Some((x, y))
+            && game.coord_limit.is_none_or(|limit| limit.contains(x, y))
+    };
+    let mut cells = state.iter().chain(decay.iter().chain(colors).flatten());
+    if cells.all(fits) {
+        Ok(())
+    } else {
+        Err(SnapshotError::InvalidCell)
+    }
+}
+
+type Board = (
+    HashSet<(i32, i32)>,
+    Vec<HashSet<(i32, i32)>>,
+    Vec<HashSet<(i32, i32)>>,
+);
+
+/// Reads a board written by `write_board`; version 1 snapshots have no color layers.
+fn read_board(bytes: &mut impl Iterator<Item = u8>, colored: bool) -> Result<Board, SnapshotError> {
+    let state = read_cells(bytes)?;
+    let mut read_layers = || -> Result<Vec<_>, SnapshotError> {
+        (0..read_uvarint(bytes)?)
+            .map(|_| read_cells(bytes))
+            .collect()
+    };
+    let decay = read_layers()?;
+    let colors = if colored { read_layers()? } else { Vec::new() };
+    Ok((state, decay, colors))
+}
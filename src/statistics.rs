@@ -0,0 +1,23 @@
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+/// A snapshot of run statistics for HUDs and logging, returned by `GameOfLife::stats`.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Statistics {
+    /// Generations simulated so far.
+    pub generation: u64,
+    /// Live cells on the board right now.
+    pub population: usize,
+    /// Cells born during the most recent tick.
+    pub births: usize,
+    /// Cells that died during the most recent tick.
+    pub deaths: usize,
+    /// Cells removed during the most recent tick for crossing the board's `CoordLimit`.
+    pub culled: usize,
+    /// Smallest population seen before or after any tick, or the current population if none ran.
+    pub min_population: usize,
+    /// Largest population seen before or after any tick, or the current population if none ran.
+    pub max_population: usize,
+}
@@ -0,0 +1,466 @@
+use alloc::vec::Vec;
+use core::fmt;
+use core::time::Duration;
+
+/// A terminal foreground color, written as an ANSI escape sequence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    /// An entry of the 256-color palette.
+    Fixed(u8),
+    /// A 24-bit color, for terminals with true-color support.
+    Rgb(u8, u8, u8),
+}
+
+impl Color {
+    /// Returns the color's red, green, and blue components, using xterm's default palette for
+    /// the named and 256-color entries.
+    pub fn rgb(self) -> (u8, u8, u8) {
+        const NAMED: [(u8, u8, u8); 16] = [
+            (0, 0, 0),
+            (205, 0, 0),
+            (0, 205, 0),
+            (205, 205, 0),
+            (0, 0, 238),
+            (205, 0, 205),
+            (0, 205, 205),
+            (229, 229, 229),
+            (127, 127, 127),
+            (255, 0, 0),
+            (0, 255, 0),
+            (255, 255, 0),
+            (92, 92, 255),
+            (255, 0, 255),
+            (0, 255, 255),
+            (255, 255, 255),
+        ];
+        let level = |n: u8| if n == 0 { 0 } else { 55 + 40 * n };
+        match self {
+            Color::Black => NAMED[0],
+            Color::Red => NAMED[1],
+            Color::Green => NAMED[2],
+            Color::Yellow => NAMED[3],
+            Color::Blue => NAMED[4],
+            Color::Magenta => NAMED[5],
+            Color::Cyan => NAMED[6],
+            Color::White => NAMED[7],
+            Color::Fixed(n @ 0..=15) => NAMED[usize::from(n)],
+            Color::Fixed(n @ 16..=231) => {
+                let n = n - 16;
+                (level(n / 36), level(n / 6 % 6), level(n % 6))
+            }
+            Color::Fixed(n) => {
+                let gray = 8 + 10 * (n - 232);
+                (gray, gray, gray)
+            }
+            Color::Rgb(r, g, b) => (r, g, b),
+        }
+    }
+
+    /// Writes `text` wrapped in this color's escape sequence and a reset.
+    pub(crate) fn paint(self, f: &mut fmt::Formatter<'_>, text: char) -> fmt::Result {
+        let basic = match self {
+            Color::Black => 30,
+            Color::Red => 31,
+            Color::Green => 32,
+            Color::Yellow => 33,
+            Color::Blue => 34,
+            Color::Magenta => 35,
+            Color::Cyan => 36,
+            Color::White => 37,
+            Color::Fixed(n) => return write!(f, "\x1b[38;5;{n}m{text}\x1b[0m"),
+            Color::Rgb(r, g, b) => return write!(f, "\x1b[38;2;{r};{g};{b}m{text}\x1b[0m"),
+        };
+        write!(f, "\x1b[{basic}m{text}\x1b[0m")
+    }
+}
+
+/// How many cells `Viewport::render_styled` packs into each character.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Packing {
+    /// One glyph per cell, drawn with the style's live, dead, decay, and off-board glyphs.
+    #[default]
+    Single,
+    /// Two vertically stacked cells per character, drawn with `▀`, `▄`, `█`, or a space.
+    HalfBlock,
+    /// A 2 by 4 block of cells per character, drawn as a Unicode braille pattern.
+    Braille,
+}
+
+impl Packing {
+    /// Returns the columns and rows of cells one character covers.
+    pub fn cell_size(self) -> (usize, usize) {
+        match self {
+            Packing::Single => (1, 1),
+            Packing::HalfBlock => (1, 2),
+            Packing::Braille => (2, 4),
+        }
+    }
+
+    /// Returns the character for a block whose cell `column` right and `row` down of its top-left
+    /// corner is alive when `alive(column, row)` is.
+    pub(crate) fn glyph(self, alive: impl Fn(usize, usize) -> bool) -> char {
+        match self {
+            Packing::Single => {
+                if alive(0, 0) {
+                    '█'
+                } else {
+                    ' '
+                }
+            }
+            Packing::HalfBlock => match (alive(0, 0), alive(0, 1)) {
+                (false, false) => ' ',
+                (true, false) => '▀',
+                (false, true) => '▄',
+                (true, true) => '█',
+            },
+            Packing::Braille => {
+                // Dots 1-3 and 7 run down the left column, 4-6 and 8 down the right.
+                const DOTS: [[u32; 4]; 2] = [[0x01, 0x02, 0x04, 0x40], [0x08, 0x10, 0x20, 0x80]];
+                let mut bits = 0;
+                for (column, dots) in DOTS.iter().enumerate() {
+                    for (row, dot) in dots.iter().enumerate() {
+                        if alive(column, row) {
+                            bits |= dot;
+                        }
+                    }
+                }
+                char::from_u32(0x2800 + bits).unwrap_or(' ')
+            }
+        }
+    }
+}
+
+/// Glyphs and colors used by `Viewport::render_styled`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RenderStyle {
+    live: char,
+    dead: char,
+    live_color: Option<Color>,
+    dead_color: Option<Color>,
+    /// Live-cell colors indexed by age, overriding `live_color` when non-empty.
+    heat_map: Vec<Color>,
+    /// Glyphs for Generations decay states, starting at state 2; empty draws them as dead.
+    decay: Vec<char>,
+    /// Live-cell colors indexed by cell color under a `ColorMode`, overriding the heat map and
+    /// `live_color` when non-empty.
+    #[cfg_attr(feature = "serde", serde(default))]
+    palette: Vec<Color>,
+    /// Glyph for positions past the edge of a bounded or toroidal board.
+    off_board: char,
+    #[cfg_attr(feature = "serde", serde(default))]
+    packing: Packing,
+}
+
+impl Default for RenderStyle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RenderStyle {
+    /// The default style: `◼` for live cells, `◻` for dead ones, and `░` past the board's edge,
+    /// uncolored.
+    pub const fn new() -> Self {
+        Self {
+            live: '◼',
+            dead: '◻',
+            live_color: None,
+            dead_color: None,
+            heat_map: Vec::new(),
+            decay: Vec::new(),
+            palette: Vec::new(),
+            off_board: '░',
+            packing: Packing::Single,
+        }
+    }
+
+    /// Plain ASCII, `#` for live cells, `.` for dead ones, and `~` past the board's edge, for fonts
+    /// without the box glyphs.
+    pub const fn ascii() -> Self {
+        Self {
+            live: '#',
+            dead: '.',
+            live_color: None,
+            dead_color: None,
+            heat_map: Vec::new(),
+            decay: Vec::new(),
+            palette: Vec::new(),
+            off_board: '~',
+            packing: Packing::Single,
+        }
+    }
+
+    /// Packs 2 by 4 blocks of cells into braille characters, showing eight times as much of the
+    /// board in the same space.
+    pub fn braille() -> Self {
+        Self::new().with_packing(Packing::Braille)
+    }
+
+    /// Packs pairs of vertically stacked cells into half-block characters.
+    pub fn half_block() -> Self {
+        Self::new().with_packing(Packing::HalfBlock)
+    }
+
+    /// Uses `glyph` for live cells.
+    pub fn with_live(mut self, glyph: char) -> Self {
+        self.live = glyph;
+        self
+    }
+
+    /// Uses `glyph` for dead cells.
+    pub fn with_dead(mut self, glyph: char) -> Self {
+        self.dead = glyph;
+        self
+    }
+
+    /// Colors live cells.
+    pub fn with_live_color(mut self, color: Color) -> Self {
+        self.live_color = Some(color);
+        self
+    }
+
+    /// Colors dead cells.
+    pub fn with_dead_color(mut self, color: Color) -> Self {
+        self.dead_color = Some(color);
+        self
+    }
+
+    /// Colors live cells by age: a cell of age `n` uses `colors[n]`, and cells older than the
+    /// gradient use its last color.
+    pub fn with_heat_map(mut self, colors: impl IntoIterator<Item = Color>) -> Self {
+        self.heat_map = colors.into_iter().collect();
+        self
+    }
+
+    /// Draws cells decaying under a Generations rule: state `n` uses `glyphs[n - 2]`, and later
+    /// states use the last glyph. Without decay glyphs, decaying cells are drawn as dead.
+    pub fn with_decay(mut self, glyphs: impl IntoIterator<Item = char>) -> Self {
+        self.decay = glyphs.into_iter().collect();
+        self
+    }
+
+    /// Colors live cells by their color under a multi-color `ColorMode`: a cell of color `n` uses
+    /// `colors[n]`, and later colors use the last one. At larger scales a block takes the highest
+    /// color among its live cells.
+    pub fn with_palette(mut self, colors: impl IntoIterator<Item = Color>) -> Self {
+        self.palette = colors.into_iter().collect();
+        self
+    }
+
+    /// Marks positions past the edge of a bounded or toroidal board with `glyph`.
+    pub fn with_off_board(mut self, glyph: char) -> Self {
+        self.off_board = glyph;
+        self
+    }
+
+    /// Packs several cells into each character. Packed characters take the live color if any of
+    /// their cells is alive and the dead color otherwise; glyphs, heat maps, decay states, and
+    /// the off-board glyph only apply to `Packing::Single`.
+    pub fn with_packing(mut self, packing: Packing) -> Self {
+        self.packing = packing;
+        self
+    }
+
+    pub fn packing(&self) -> Packing {
+        self.packing
+    }
+
+    /// Returns the glyph for live cells.
+    pub fn live(&self) -> char {
+        self.live
+    }
+
+    /// Returns the glyph for dead cells.
+    pub fn dead(&self) -> char {
+        self.dead
+    }
+
+    /// Returns the glyph for positions past the board's edge.
+    pub fn off_board(&self) -> char {
+        self.off_board
+    }
+
+    /// Returns whether `write_cell` needs real ages rather than a placeholder for live cells.
+    pub(crate) fn uses_ages(&self) -> bool {
+        !self.heat_map.is_empty()
+    }
+
+    /// Returns whether live cells are colored by their cell color.
+    pub(crate) fn uses_palette(&self) -> bool {
+        !self.palette.is_empty()
+    }
+
+    /// Writes the live glyph in the palette color for cell color `color`.
+    pub(crate) fn write_colored(&self, f: &mut fmt::Formatter<'_>, color: u8) -> fmt::Result {
+        match self.palette.get(usize::from(color)).or(self.palette.last()) {
+            Some(color) => color.paint(f, self.live),
+            None => self.write_cell(f, Some(0)),
+        }
+    }
+
+    /// Returns whether `write_decaying` draws anything other than the dead glyph.
+    pub(crate) fn uses_decay(&self) -> bool {
+        !self.decay.is_empty()
+    }
+
+    /// Writes the glyph for a cell in decay state `state` (2 or more).
+    pub(crate) fn write_decaying(&self, f: &mut fmt::Formatter<'_>, state: u8) -> fmt::Result {
+        let index = usize::from(state.saturating_sub(2));
+        match self.decay.get(index).or(self.decay.last()) {
+            Some(glyph) => write!(f, "{glyph}"),
+            None => self.write_cell(f, None),
+        }
+    }
+
+    /// Writes the uncolored off-board glyph.
+    pub(crate) fn write_off_board(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.off_board)
+    }
+
+    /// Writes a packed character, colored as live if any of its cells is.
+    pub(crate) fn write_packed(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        glyph: char,
+        alive: bool,
+    ) -> fmt::Result {
+        match if alive {
+            self.live_color
+        } else {
+            self.dead_color
+        } {
+            Some(color) => color.paint(f, glyph),
+            None => write!(f, "{glyph}"),
+        }
+    }
+
+    /// Writes the glyph for one cell, given its age if alive.
+    pub(crate) fn write_cell(&self, f: &mut fmt::Formatter<'_>, age: Option<u32>) -> fmt::Result {
+        let (glyph, color) = match age {
+            Some(age) => {
+                let heat = self.heat_map.get(age as usize).or(self.heat_map.last());
+                (self.live, heat.copied().or(self.live_color))
+            }
+            None => (self.dead, self.dead_color),
+        };
+        match color {
+            Some(color) => color.paint(f, glyph),
+            None => write!(f, "{glyph}"),
+        }
+    }
+}
+
+/// Pixel size, colors, grid lines, and animation timing for image snapshots and exports.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ImageStyle {
+    cell_size: u32,
+    live: Color,
+    dead: Color,
+    /// Color of the 1-pixel lines drawn around every cell, if any.
+    grid: Option<Color>,
+    frame_delay: Duration,
+}
+
+impl Default for ImageStyle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ImageStyle {
+    /// Black 4-pixel cells on white without grid lines, advancing every 100 ms.
+    pub const fn new() -> Self {
+        Self {
+            cell_size: 4,
+            live: Color::Black,
+            dead: Color::Rgb(255, 255, 255),
+            grid: None,
+            frame_delay: Duration::from_millis(100),
+        }
+    }
+
+    /// Draws each rendered cell as a `size` by `size` pixel square; 0 is treated as 1.
+    pub fn with_cell_size(mut self, size: u32) -> Self {
+        self.cell_size = size.max(1);
+        self
+    }
+
+    /// Fills live cells with `color`.
+    pub fn with_live(mut self, color: Color) -> Self {
+        self.live = color;
+        self
+    }
+
+    /// Fills dead cells with `color`.
+    pub fn with_dead(mut self, color: Color) -> Self {
+        self.dead = color;
+        self
+    }
+
+    /// Separates cells with 1-pixel lines of `color`, which add to the image size.
+    pub fn with_grid(mut self, color: Color) -> Self {
+        self.grid = Some(color);
+        self
+    }
+
+    /// Shows each frame of an animation for `delay`. GIF rounds it down to hundredths of a second.
+    pub fn with_frame_delay(mut self, delay: Duration) -> Self {
+        self.frame_delay = delay;
+        self
+    }
+
+    pub fn cell_size(&self) -> u32 {
+        self.cell_size
+    }
+
+    pub fn live(&self) -> Color {
+        self.live
+    }
+
+    pub fn dead(&self) -> Color {
+        self.dead
+    }
+
+    pub fn grid(&self) -> Option<Color> {
+        self.grid
+    }
+
+    pub fn frame_delay(&self) -> Duration {
+        self.frame_delay
+    }
+
+    /// Dead, live, and grid colors, in palette order. Without a grid, its entry repeats the dead
+    /// color.
+    #[cfg(feature = "image")]
+    pub(crate) fn palette(&self) -> [(u8, u8, u8); 3] {
+        let dead = self.dead.rgb();
+        [dead, self.live.rgb(), self.grid.map_or(dead, Color::rgb)]
+    }
+
+    /// Pixels from one cell's start to the next, including any grid line.
+    pub(crate) fn stride(&self) -> u32 {
+        self.cell_size + u32::from(self.grid.is_some())
+    }
+
+    /// Pixel offset of the `index`th cell along an axis.
+    pub(crate) fn cell_offset(&self, index: usize) -> u32 {
+        index as u32 * self.stride() + u32::from(self.grid.is_some())
+    }
+
+    /// Pixel length of `cells` cells along an axis, including the closing grid line.
+    pub(crate) fn image_len(&self, cells: usize) -> u32 {
+        self.cell_offset(cells)
+    }
+}
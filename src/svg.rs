@@ -0,0 +1,77 @@
+use alloc::{format, string::String};
+use core::fmt::Write;
+
+use crate::{Color, GameOfLife, ImageStyle, Viewport};
+
+fn hex(color: Color) -> String {
+    let (r, g, b) = color.rgb();
+    format!("#{r:02x}{g:02x}{b:02x}")
+}
+
+impl Viewport {
+    /// Renders the viewport of `game` as an SVG document with the default `ImageStyle`.
+    pub fn render_svg(&self, game: &GameOfLife) -> String {
+        self.render_svg_styled(game, &ImageStyle::new())
+    }
+
+    /// Renders the viewport of `game` as an SVG document, drawn with `style`.
+    ///
+    /// The image has the same pixel size as `render_png_styled` would produce. Runs of live cells
+    /// within a row are merged into single rectangles to keep the document small.
+    pub fn render_svg_styled(&self, game: &GameOfLife, style: &ImageStyle) -> String {
+        let (columns, cells) = self.glyph_grid(game);
+        let rows = cells.len() / columns.max(1);
+        let (width, height) = (style.image_len(columns), style.image_len(rows));
+
+        let mut out = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+             viewBox=\"0 0 {width} {height}\" shape-rendering=\"crispEdges\">\n"
+        );
+        let _ = writeln!(
+            out,
+            "<rect width=\"100%\" height=\"100%\" fill=\"{}\"/>",
+            hex(style.dead())
+        );
+
+        let _ = writeln!(out, "<g fill=\"{}\">", hex(style.live()));
+        for (row, glyphs) in cells.chunks(columns.max(1)).enumerate() {
+            let mut column = 0;
+            while column < glyphs.len() {
+                if !glyphs[column] {
+                    column += 1;
+                    continue;
+                }
+                let run = glyphs[column..].iter().take_while(|&&alive| alive).count();
+                let run_width = (run as u32 - 1) * style.stride() + style.cell_size();
+                let _ = writeln!(
+                    out,
+                    "<rect x=\"{}\" y=\"{}\" width=\"{run_width}\" height=\"{}\"/>",
+                    style.cell_offset(column),
+                    style.cell_offset(row),
+                    style.cell_size()
+                );
+                column += run;
+            }
+        }
+        out.push_str("</g>\n");
+
+        if let Some(grid) = style.grid() {
+            let mut path = String::new();
+            for column in 0..=columns {
+                let x = column as u32 * style.stride();
+                let _ = write!(path, "M{x}.5 0V{height}");
+            }
+            for row in 0..=rows {
+                let y = row as u32 * style.stride();
+                let _ = write!(path, "M0 {y}.5H{width}");
+            }
+            let _ = writeln!(
+                out,
+                "<path d=\"{path}\" stroke=\"{}\" stroke-width=\"1\"/>",
+                hex(grid)
+            );
+        }
+        out.push_str("</svg>\n");
+        out
+    }
+}
@@ -0,0 +1,63 @@
+use alloc::vec::Vec;
+
+use crate::{GameOfLife, Pattern, Transform, pattern::transformed_offsets};
+
+impl Pattern {
+    /// Returns whether `other` has the same live cells shifted by some offset. Names and rules are
+    /// ignored.
+    pub fn equivalent_up_to_translation(&self, other: &Pattern) -> bool {
+        self.cells().len() == other.cells().len()
+            && normalized(self, Transform::Identity) == normalized(other, Transform::Identity)
+    }
+
+    /// Returns whether some rotation or reflection of `other` is a translated copy of the pattern.
+    pub fn equivalent_up_to_symmetry(&self, other: &Pattern) -> bool {
+        if self.cells().len() != other.cells().len() {
+            return false;
+        }
+        let cells = normalized(self, Transform::Identity);
+        Transform::ALL
+            .into_iter()
+            .any(|transform| normalized(other, transform) == cells)
+    }
+
+    /// Returns the rotations and reflections that map the pattern onto a translated copy of
+    /// itself, in `Transform::ALL` order. `Identity` is always included; a pattern with all 8, such
+    /// as a block, has the full symmetry of the square.
+    pub fn symmetries(&self) -> Vec<Transform> {
+        let cells = normalized(self, Transform::Identity);
+        Transform::ALL
+            .into_iter()
+            .filter(|&transform| normalized(self, transform) == cells)
+            .collect()
+    }
+}
+impl GameOfLife {
+    /// Returns whether `other`'s live cells are those of this board shifted by some offset, as
+    /// `Pattern::equivalent_up_to_translation` compares them. Decaying cells, rules, and topology
+    /// are ignored.
+    pub fn equivalent_up_to_translation(&self, other: &GameOfLife) -> bool {
+        Pattern::from_game(self).equivalent_up_to_translation(&Pattern::from_game(other))
+    }
+
+    /// Returns whether some rotation or reflection of `other`'s live cells is a translated copy of
+    /// this board's, as `Pattern::equivalent_up_to_symmetry` compares them.
+    pub fn equivalent_up_to_symmetry(&self, other: &GameOfLife) -> bool {
+        Pattern::from_game(self).equivalent_up_to_symmetry(&Pattern::from_game(other))
+    }
+
+    /// Returns the rotations and reflections that map the live cells onto a translated copy of
+    /// themselves, as `Pattern::symmetries` reports them.
+    pub fn symmetries(&self) -> Vec<Transform> {
+        Pattern::from_game(self).symmetries()
+    }
+}
+
+/// The pattern's cells after `transform`, as sorted offsets from their minimum corner. They are
+/// kept wide rather than moved back into the `i32` plane, so no cell is lost however far the
+/// pattern spans.
+fn normalized(pattern: &Pattern, transform: Transform) -> Vec<(i64, i64)> {
+    let mut cells = transformed_offsets(pattern.cells(), transform);
+    cells.sort_unstable();
+    cells
+}
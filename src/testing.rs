@@ -0,0 +1,197 @@
+//! Helpers for testing code built on the crate: ASCII fixtures, oscillator checks, golden-file
+//! comparison of rendered viewports, and reproducible random boards for property tests.
+//!
+//! Golden files hold the expected text of a rendering. When one is missing or out of date, rerun
+//! the tests with the `UPDATE_GOLDEN` environment variable set to write the current output, then
+//! review the change before committing it.
+//!
+//! Random boards come from `BoardGen`, which derives each board from a seed rather than pulling in
+//! a property-testing framework. `BoardGen::check` runs a property over many seeds and, when it
+//! fails, reports the seed and the offending board so the case can be replayed on its own.
+
+use std::any::Any;
+use std::fs;
+use std::io::ErrorKind;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+
+use crate::{Format, GameOfLife, Pattern, Rule, Topology, Transform, Viewport, patterns};
+
+/// Environment variable that makes `assert_golden` write its output instead of comparing it.
+pub const UPDATE_GOLDEN: &str = "UPDATE_GOLDEN";
+
+/// Builds a board from rows of text, one cell per character. `#`, `O`, `o`, and `X` are live
+/// cells and anything else is dead; the first character of the first row is `(0, 0)`.
+pub fn pattern_from_ascii(rows: &[&str]) -> GameOfLife {
+    let mut game = GameOfLife::new();
+    for (y, row) in rows.iter().enumerate() {
+        for (x, ch) in row.chars().enumerate() {
+            if matches!(ch, '#' | 'O' | 'o' | 'X') {
+                game.set(x as i32, y as i32);
+            }
+        }
+    }
+    game
+}
+
+/// Asserts that `game` returns to its current state after exactly `period` generations and not
+/// before. `name` identifies the pattern in the failure message.
+pub fn assert_period(mut game: GameOfLife, period: usize, name: &str) {
+    let baseline = game.clone();
+    for step in 1..period {
+        game.tick();
+        assert_ne!(
+            baseline, game,
+            "{name} unexpectedly returned to its baseline after {step} tick(s)"
+        );
+    }
+
+    game.tick();
+    assert_eq!(
+        baseline, game,
+        "{name} did not return to its baseline after {period} tick(s)"
+    );
+}
+
+/// Asserts that `actual` matches the contents of the golden file at `path`, or writes it there,
+/// creating missing directories, when `UPDATE_GOLDEN` is set.
+///
+/// # Panics
+///
+/// Panics if the file is missing or differs from `actual`, naming the first differing line.
+pub fn assert_golden(path: impl AsRef<Path>, actual: &str) {
+    let path = path.as_ref();
+    if std::env::var_os(UPDATE_GOLDEN).is_some() {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).expect("failed to create the golden file's directory");
+        }
+        fs::write(path, actual).expect("failed to write the golden file");
+        return;
+    }
+
+    let expected = match fs::read_to_string(path) {
+        Ok(expected) => expected,
+        Err(err) if err.kind() == ErrorKind::NotFound => panic!(
+            "golden file {} is missing; rerun with {UPDATE_GOLDEN}=1 to create it",
+            path.display()
+        ),
+        Err(err) => panic!("failed to read golden file {}: {err}", path.display()),
+    };
+    if expected == actual {
+        return;
+    }
+    let mut expected_lines = expected.lines();
+    let mut actual_lines = actual.lines();
+    for line in 1.. {
+        match (expected_lines.next(), actual_lines.next()) {
+            (Some(want), Some(got)) if want == got => continue,
+            (None, None) => break,
+            (want, got) => panic!(
+                "output differs from golden file {} at line {line}\n  expected: {}\n    actual: {}\n\
+                 rerun with {UPDATE_GOLDEN}=1 to accept the new output",
+                path.display(),
+                want.unwrap_or("<end of file>"),
+                got.unwrap_or("<end of output>"),
+            ),
+        }
+    }
+    panic!(
+        "output differs from golden file {} in trailing newlines; rerun with {UPDATE_GOLDEN}=1 \
+         to accept the new output",
+        path.display()
+    );
+}
+
+/// Renders `game` through `viewport` and compares the text with the golden file at `path`, as
+/// `assert_golden` does.
+pub fn assert_golden_render(game: &GameOfLife, viewport: &Viewport, path: impl AsRef<Path>) {
+    assert_golden(path, &viewport.render(game).to_string());
+}
+
+/// Generates random boards for property tests, each reproducible from its seed.
+///
+/// Boards are soups from `patterns::soup` placed with their top-left corner at the origin, under
+/// the configured rule and topology.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoardGen {
+    width: u32,
+    height: u32,
+    density: f64,
+    rule: Rule,
+    topology: Topology,
+}
+
+impl BoardGen {
+    /// Generates `width` by `height` soups at density 0.5 on an infinite Conway board.
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            density: 0.5,
+            rule: Rule::conway(),
+            topology: Topology::Infinite,
+        }
+    }
+
+    /// Sets the chance of each cell starting alive.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `density` is not within `0.0..=1.0`.
+    pub fn with_density(mut self, density: f64) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&density),
+            "density must be between 0 and 1"
+        );
+        self.density = density;
+        self
+    }
+
+    pub fn with_rule(mut self, rule: Rule) -> Self {
+        self.rule = rule;
+        self
+    }
+
+    pub fn with_topology(mut self, topology: Topology) -> Self {
+        self.topology = topology;
+        self
+    }
+
+    /// Returns the board for `seed`; the same seed always gives the same board.
+    pub fn board(&self, seed: u64) -> GameOfLife {
+        let mut game = GameOfLife::with_topology(self.topology);
+        game.set_rule(self.rule);
+        let soup = patterns::soup(self.width, self.height, self.density, seed);
+        game.stamp(&soup, 0, 0, Transform::Identity);
+        game.clear_history();
+        game
+    }
+
+    /// Runs `property` on the boards for seeds `0..cases`.
+    ///
+    /// # Panics
+    ///
+    /// Re-raises the first failure of `property` with the seed and the board in plaintext format.
+    pub fn check(&self, cases: u64, mut property: impl FnMut(GameOfLife)) {
+        for seed in 0..cases {
+            let game = self.board(seed);
+            let board = Pattern::from_game(&game).write(Format::Plaintext);
+            if let Err(payload) = panic::catch_unwind(AssertUnwindSafe(|| property(game))) {
+                panic!(
+                    "property failed for board seed {seed}: {}\n{board}",
+                    panic_message(&*payload)
+                );
+            }
+        }
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "non-string panic payload"
+    }
+}
@@ -0,0 +1,145 @@
+use alloc::{collections::VecDeque, vec::Vec};
+use core::ops::RangeInclusive;
+
+use hashbrown::HashSet;
+
+use crate::{GameOfLife, Topology};
+
+/// Side length of the square tiles `GameOfLife::changed_tiles_since` reports. Tile `(tx, ty)`
+/// covers cells `tx * TILE_SIZE ..= tx * TILE_SIZE + TILE_SIZE - 1` across and likewise down.
+pub const TILE_SIZE: i32 = 64;
+
+/// Tiles touched by recent steps, kept while tile tracking is on.
+#[derive(Debug, Clone)]
+pub(crate) struct TileLog {
+    /// Oldest generation the log can answer for; edits move it past the current generation.
+    since: u64,
+    /// Steps that changed some cell: the generation each started from and the tiles it touched.
+    steps: VecDeque<(u64, HashSet<(i32, i32)>)>,
+    capacity: usize,
+}
+
+impl GameOfLife {
+    /// Returns the live cells with `x` in `x_range` and `y` in `y_range`, sorted, for frontends
+    /// that draw sparse views without scanning a dense `cells_at` buffer.
+    ///
+    /// Like `cells_at`, a view extending past the edge of a torus sees the wrapped copies of its
+    /// cells, at the coordinates they are drawn at.
+    pub fn live_cells_in(
+        &self,
+        x_range: RangeInclusive<i32>,
+        y_range: RangeInclusive<i32>,
+    ) -> Vec<(i32, i32)> {
+        let (x0, x1, y0, y1) = (
+            *x_range.start(),
+            *x_range.end(),
+            *y_range.start(),
+            *y_range.end(),
+        );
+        if x0 > x1 || y0 > y1 {
+            return Vec::new();
+        }
+        let area = (i64::from(x1) - i64::from(x0) + 1) * (i64::from(y1) - i64::from(y0) + 1);
+        let mut cells: Vec<(i32, i32)> = if area <= self.state.len() as i64 {
+            (y0..=y1)
+                .flat_map(|y| (x0..=x1).map(move |x| (x, y)))
+                .filter(|&(x, y)| self.get(x, y))
+                .collect()
+        } else if let Topology::Torus { width, height } = self.topology {
+            self.state
+                .iter()
+                .flat_map(|&(x, y)| {
+                    copies(x, width, x0, x1)
+                        .flat_map(move |x| copies(y, height, y0, y1).map(move |y| (x, y)))
+                })
+                .collect()
+        } else {
+            self.state
+                .iter()
+                .copied()
+                .filter(|(x, y)| x_range.contains(x) && y_range.contains(y))
+                .collect()
+        };
+        cells.sort_unstable();
+        cells
+    }
+
+    /// Starts or stops recording which tiles each step changes, remembering up to `steps` of the
+    /// most recent steps that changed anything; 0 turns tracking off.
+    pub fn set_tile_tracking(&mut self, steps: usize) {
+        self.tile_log = (steps > 0).then(|| TileLog {
+            since: self.generation,
+            steps: VecDeque::new(),
+            capacity: steps,
+        });
+    }
+
+    /// Returns the `TILE_SIZE` tiles holding a cell that was born, died, decayed, or was culled
+    /// since `generation`, sorted, so a frontend that drew that generation only redraws those.
+    /// Coordinates are those of the board; on a torus, they lie within it.
+    ///
+    /// Returns `None` when the answer is unknown and the whole view must be redrawn: when tile
+    /// tracking is off, when `generation` is older than the recorded steps or newer than the
+    /// board, or when the board was edited, restored, or had its rules changed at or after
+    /// `generation`.
+    pub fn changed_tiles_since(&self, generation: u64) -> Option<Vec<(i32, i32)>> {
+        let log = self.tile_log.as_ref()?;
+        if generation < log.since || generation > self.generation {
+            return None;
+        }
+        let tiles: HashSet<(i32, i32)> = log
+            .steps
+            .iter()
+            .filter(|(step, _)| *step >= generation)
+            .flat_map(|(_, tiles)| tiles.iter().copied())
+            .collect();
+        let mut tiles: Vec<(i32, i32)> = tiles.into_iter().collect();
+        tiles.sort_unstable();
+        Some(tiles)
+    }
+
+    /// Records the tiles of `cells`, which changed in the step from the current generation.
+    pub(crate) fn record_tiles(&mut self, cells: &HashSet<(i32, i32)>) {
+        let generation = self.generation;
+        let Some(log) = &mut self.tile_log else {
+            return;
+        };
+        if cells.is_empty() {
+            return;
+        }
+        let tiles = cells
+            .iter()
+            .map(|&(x, y)| (x.div_euclid(TILE_SIZE), y.div_euclid(TILE_SIZE)))
+            .collect();
+        if log.steps.len() == log.capacity
+            && let Some((oldest, _)) = log.steps.pop_front()
+        {
+            log.since = oldest + 1;
+        }
+        log.steps.push_back((generation, tiles));
+    }
+
+    /// Forgets the recorded steps after an edit, so earlier generations report `None`.
+    pub(crate) fn restart_tiles(&mut self) {
+        let generation = self.generation;
+        if let Some(log) = &mut self.tile_log {
+            log.steps.clear();
+            log.since = generation + 1;
+        }
+    }
+}
+
+/// Iterates the copies of `coord` on a torus axis of length `size` that fall in `start..=end`.
+fn copies(coord: i32, size: i32, start: i32, end: i32) -> impl Iterator<Item = i32> {
+    let (coord, size) = (i64::from(coord), i64::from(size));
+    let first = coord + (i64::from(start) - coord).div_euclid(size) * size;
+    let first = if first < i64::from(start) {
+        first + size
+    } else {
+        first
+    };
+    (0..)
+        .map(move |k| first + k * size)
+        .take_while(move |&value| value <= i64::from(end))
+        .map(|value| value as i32)
+}
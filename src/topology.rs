@@ -0,0 +1,97 @@
+use hashbrown::HashMap;
+
+/// The shape of the plane a `GameOfLife` board lives on.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Topology {
+    /// An unbounded plane; every `i32` coordinate is addressable.
+    #[default]
+    Infinite,
+    /// A fixed `width` by `height` rectangle anchored at `(0, 0)`; cells outside it are always dead.
+    Bounded { width: i32, height: i32 },
+    /// A `width` by `height` rectangle anchored at `(0, 0)` whose opposite edges wrap around.
+    Torus { width: i32, height: i32 },
+}
+
+impl Topology {
+    /// Maps `(x, y)` onto the board, wrapping on a torus and returning `None` off a bounded board.
+    pub fn normalize(&self, x: i32, y: i32) -> Option<(i32, i32)> {
+        match *self {
+            Topology::Infinite => Some((x, y)),
+            Topology::Bounded { width, height } => {
+                ((0..width).contains(&x) && (0..height).contains(&y)).then_some((x, y))
+            }
+            Topology::Torus { width, height } => Some((x.rem_euclid(width), y.rem_euclid(height))),
+        }
+    }
+
+    /// Returns the board's `(width, height)`, or `None` for the infinite plane.
+    pub fn size(&self) -> Option<(i32, i32)> {
+        match *self {
+            Topology::Infinite => None,
+            Topology::Bounded { width, height } | Topology::Torus { width, height } => {
+                Some((width, height))
+            }
+        }
+    }
+
+    fn validate(&self) {
+        if let Some((width, height)) = self.size() {
+            assert!(
+                width > 0 && height > 0,
+                "bounded and toroidal boards need a positive width and height"
+            );
+        }
+    }
+}
+
+impl crate::GameOfLife {
+    /// Creates an empty board on the given topology.
+    pub fn with_topology(topology: Topology) -> Self {
+        topology.validate();
+        Self {
+            topology,
+            ..Self::default()
+        }
+    }
+
+    /// Returns the board's topology.
+    pub fn topology(&self) -> Topology {
+        self.topology
+    }
+
+    /// Changes the topology, wrapping live cells onto a torus or dropping those outside a bounded board.
+    pub fn set_topology(&mut self, topology: Topology) {
+        topology.validate();
+        self.invalidate();
+        self.topology = topology;
+        self.state = self
+            .state
+            .iter()
+            .filter_map(|&(x, y)| topology.normalize(x, y))
+            .collect();
+        let mut ages = HashMap::new();
+        for ((x, y), age) in self.ages.drain() {
+            if let Some(cell) = topology.normalize(x, y) {
+                let merged: &mut u32 = ages.entry(cell).or_default();
+                *merged = (*merged).max(age);
+            }
+        }
+        self.ages = ages;
+        for layer in &mut self.colors {
+            *layer = layer
+                .iter()
+                .filter_map(|&(x, y)| topology.normalize(x, y))
+                .collect();
+        }
+        for (layer, state) in core::mem::take(&mut self.decay).into_iter().zip(2u8..) {
+            for (x, y) in layer {
+                if let Some(cell) = topology.normalize(x, y)
+                    && self.cell_state_of(cell) == 0
+                {
+                    self.insert_decaying(cell, state);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,262 @@
+//! Interactive terminal simulator.
+//!
+//! Rows grow downwards, matching the pattern file formats and the web client. Each board cell takes
+//! two terminal columns so the grid looks roughly square. Cells decaying under a Generations rule
+//! are drawn shaded.
+
+use std::io;
+use std::time::Duration;
+
+use gameoflife::{GameOfLife, Simulation};
+use ratatui::DefaultTerminal;
+use ratatui::Frame;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Paragraph};
+
+const MIN_DELAY: Duration = Duration::from_millis(10);
+const MAX_DELAY: Duration = Duration::from_secs(2);
+const MAX_SCALE: i32 = 16;
+const HELP: &str = "space run/pause  n step  arrows cursor  enter toggle  wasd pan  +/- zoom  \
+                    [/] speed  u/r undo/redo  c clear  q quit";
+
+/// Start-up options for the simulator.
+pub struct Settings {
+    /// Time between generations while running.
+    pub delay: Duration,
+    /// When set, start running and pause after this many generations.
+    pub steps: Option<u64>,
+    /// World coordinates to show in the top-left corner, instead of centering the live cells.
+    pub origin: Option<(i32, i32)>,
+}
+
+/// Runs the simulator until the user quits, restoring the terminal afterwards, and returns the
+/// board as it was left.
+pub fn run(game: GameOfLife, settings: Settings) -> io::Result<GameOfLife> {
+    let mut terminal = ratatui::init();
+    let mut app = App::new(game, settings);
+    let result = app.run(&mut terminal);
+    ratatui::restore();
+    result.map(|()| app.sim.into_game())
+}
+
+struct App {
+    sim: Simulation,
+    /// World coordinates of the top-left board cell on screen.
+    origin: (i32, i32),
+    cursor: (i32, i32),
+    /// Board cells per screen cell along each axis; a block shows as live if any cell in it is.
+    scale: i32,
+    /// Board cells visible on screen at the current scale, as of the last draw.
+    view: (i32, i32),
+    centered: bool,
+    quit: bool,
+}
+
+impl App {
+    fn new(game: GameOfLife, settings: Settings) -> Self {
+        let mut sim = Simulation::new(game);
+        sim.set_interval(settings.delay.clamp(MIN_DELAY, MAX_DELAY));
+        sim.set_max_catch_up(1);
+        sim.run_for(settings.steps.unwrap_or(0));
+        Self {
+            sim,
+            origin: settings.origin.unwrap_or_default(),
+            cursor: settings.origin.unwrap_or_default(),
+            scale: 1,
+            view: (0, 0),
+            centered: settings.origin.is_some(),
+            quit: false,
+        }
+    }
+
+    fn run(&mut self, terminal: &mut DefaultTerminal) -> io::Result<()> {
+        while !self.quit {
+            terminal.draw(|frame| self.draw(frame))?;
+
+            let timeout = self.sim.until_next().unwrap_or(Duration::from_secs(1));
+            if event::poll(timeout)?
+                && let Event::Key(key) = event::read()?
+                && key.kind == KeyEventKind::Press
+            {
+                self.handle_key(key);
+            }
+            self.sim.update_now();
+        }
+        Ok(())
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => self.quit = true,
+            KeyCode::Char(' ') => self.sim.toggle(),
+            KeyCode::Char('n') | KeyCode::Char('.') => self.sim.step(),
+            KeyCode::Up => self.move_cursor(0, -1),
+            KeyCode::Down => self.move_cursor(0, 1),
+            KeyCode::Left => self.move_cursor(-1, 0),
+            KeyCode::Right => self.move_cursor(1, 0),
+            KeyCode::Enter | KeyCode::Char('t') => {
+                let (x, y) = self.cursor;
+                self.sim.game_mut().toggle(x, y);
+            }
+            KeyCode::Char('w') => self.pan(0, -1),
+            KeyCode::Char('s') => self.pan(0, 1),
+            KeyCode::Char('a') => self.pan(-1, 0),
+            KeyCode::Char('d') => self.pan(1, 0),
+            KeyCode::Char('+') | KeyCode::Char('=') => self.zoom(self.scale / 2),
+            KeyCode::Char('-') => self.zoom(self.scale * 2),
+            KeyCode::Char('[') => {
+                let delay = (self.sim.interval() * 2).min(MAX_DELAY);
+                self.sim.set_interval(delay);
+            }
+            KeyCode::Char(']') => {
+                let delay = (self.sim.interval() / 2).max(MIN_DELAY);
+                self.sim.set_interval(delay);
+            }
+            KeyCode::Char('u') => {
+                self.sim.game_mut().undo();
+            }
+            KeyCode::Char('r') => {
+                self.sim.game_mut().redo();
+            }
+            KeyCode::Char('c') => self.sim.game_mut().clear(),
+            _ => {}
+        }
+    }
+
+    /// Moves the cursor by one screen cell, scrolling when it leaves the view.
+    fn move_cursor(&mut self, dx: i32, dy: i32) {
+        self.cursor.0 += dx * self.scale;
+        self.cursor.1 += dy * self.scale;
+        self.scroll_to_cursor();
+    }
+
+    /// Shifts the view by a quarter of its size.
+    fn pan(&mut self, dx: i32, dy: i32) {
+        self.origin.0 += dx * (self.view.0 / 4).max(self.scale);
+        self.origin.1 += dy * (self.view.1 / 4).max(self.scale);
+    }
+
+    /// Changes the scale while keeping the cursor at the same place on screen.
+    fn zoom(&mut self, scale: i32) {
+        let scale = scale.clamp(1, MAX_SCALE);
+        let screen = (
+            (self.cursor.0 - self.origin.0).div_euclid(self.scale),
+            (self.cursor.1 - self.origin.1).div_euclid(self.scale),
+        );
+        self.scale = scale;
+        self.origin = (
+            self.cursor.0 - screen.0 * scale,
+            self.cursor.1 - screen.1 * scale,
+        );
+    }
+
+    fn scroll_to_cursor(&mut self) {
+        let ((width, height), scale) = (self.view, self.scale);
+        if width == 0 || height == 0 {
+            return;
+        }
+        let scroll = |origin: &mut i32, cursor: i32, extent: i32| {
+            if cursor < *origin {
+                *origin = cursor;
+            } else if cursor >= *origin + extent {
+                *origin = cursor - extent + scale;
+            }
+        };
+        scroll(&mut self.origin.0, self.cursor.0, width);
+        scroll(&mut self.origin.1, self.cursor.1, height);
+    }
+
+    /// Puts the live cells, or the origin on an empty board, in the middle of the view.
+    fn center(&mut self) {
+        let center = self.sim.game().bounding_box().map_or((0, 0), |bounds| {
+            (
+                bounds.min_x + (bounds.max_x - bounds.min_x) / 2,
+                bounds.min_y + (bounds.max_y - bounds.min_y) / 2,
+            )
+        });
+        self.cursor = center;
+        self.origin = (center.0 - self.view.0 / 2, center.1 - self.view.1 / 2);
+    }
+
+    fn block_alive(&self, x: i32, y: i32) -> bool {
+        (0..self.scale).any(|dy| (0..self.scale).any(|dx| self.sim.game().get(x + dx, y + dy)))
+    }
+
+    /// Whether any cell in the block is decaying under a Generations rule.
+    fn block_decaying(&self, x: i32, y: i32) -> bool {
+        (0..self.scale)
+            .any(|dy| (0..self.scale).any(|dx| self.sim.game().cell_state(x + dx, y + dy) >= 2))
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [board_area, status_area, help_area] = Layout::vertical([
+            Constraint::Min(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .areas(frame.area());
+
+        let block = Block::bordered().title(" Game of Life ");
+        let inner = block.inner(board_area);
+        let (columns, rows) = (i32::from(inner.width / 2), i32::from(inner.height));
+        self.view = (columns * self.scale, rows * self.scale);
+        if !self.centered {
+            self.center();
+            self.centered = true;
+        }
+
+        let cursor_cell = (
+            (self.cursor.0 - self.origin.0).div_euclid(self.scale),
+            (self.cursor.1 - self.origin.1).div_euclid(self.scale),
+        );
+        let lines: Vec<Line> = (0..rows)
+            .map(|row| {
+                Line::from(
+                    (0..columns)
+                        .map(|column| {
+                            let x = self.origin.0 + column * self.scale;
+                            let y = self.origin.1 + row * self.scale;
+                            let glyph = if self.block_alive(x, y) {
+                                "██"
+                            } else if self.block_decaying(x, y) {
+                                "▒▒"
+                            } else {
+                                " ·"
+                            };
+                            let style = if (column, row) == cursor_cell {
+                                Style::new().add_modifier(Modifier::REVERSED)
+                            } else {
+                                Style::new()
+                            };
+                            Span::styled(glyph, style)
+                        })
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect();
+        frame.render_widget(Paragraph::new(lines).block(block), board_area);
+
+        let status = format!(
+            "{} · generation {} · population {} · {} ms · 1:{} · cursor ({}, {})",
+            if self.sim.is_running() {
+                "running"
+            } else {
+                "paused"
+            },
+            self.sim.game().generation(),
+            self.sim.game().population(),
+            self.sim.interval().as_millis(),
+            self.scale,
+            self.cursor.0,
+            self.cursor.1,
+        );
+        frame.render_widget(Line::from(status), status_area);
+        frame.render_widget(
+            Line::styled(HELP, Style::new().add_modifier(Modifier::DIM)),
+            help_area,
+        );
+    }
+}
@@ -0,0 +1,380 @@
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::RangeInclusive;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+
+use crate::{GameOfLife, Packing, RenderStyle, Topology};
+
+/// Style used by `Viewport::render`.
+static DEFAULT_STYLE: RenderStyle = RenderStyle::new();
+
+/// Stores reusable viewing bounds for rendering `GameOfLife` states.
+///
+/// Bounds are inclusive world coordinates. At a scale above 1, each rendered glyph stands for a
+/// `scale` by `scale` block of cells and shows as alive if any cell in the block is.
+///
+/// On bounded and toroidal boards, glyphs wholly past the board's edge are drawn with the style's
+/// off-board glyph. With wrapping enabled, a torus is instead tiled across the whole viewport.
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Viewport {
+    x_range: RangeInclusive<i32>,
+    y_range: RangeInclusive<i32>,
+    scale: u32,
+    /// Whether a torus repeats past its edges instead of showing them as off the board.
+    #[cfg_attr(feature = "serde", serde(default))]
+    wrap: bool,
+}
+
+impl Viewport {
+    pub fn new(x_range: RangeInclusive<i32>, y_range: RangeInclusive<i32>) -> Self {
+        Self {
+            x_range,
+            y_range,
+            scale: 1,
+            wrap: false,
+        }
+    }
+
+    pub fn render<'a>(&'a self, game: &'a GameOfLife) -> ViewportRender<'a> {
+        self.render_styled(game, &DEFAULT_STYLE)
+    }
+
+    /// Renders like `render`, drawing cells with the glyphs and colors of `style`.
+    pub fn render_styled<'a>(
+        &'a self,
+        game: &'a GameOfLife,
+        style: &'a RenderStyle,
+    ) -> ViewportRender<'a> {
+        ViewportRender {
+            viewport: self,
+            game,
+            style,
+        }
+    }
+
+    /// Returns the horizontal bounds.
+    pub fn x_range(&self) -> RangeInclusive<i32> {
+        self.x_range.clone()
+    }
+
+    /// Returns the vertical bounds.
+    pub fn y_range(&self) -> RangeInclusive<i32> {
+        self.y_range.clone()
+    }
+
+    /// Returns the number of glyphs per row and whether each glyph is alive, in display order.
+    pub(crate) fn glyph_grid(&self, game: &GameOfLife) -> (usize, Vec<bool>) {
+        let cells: Vec<(i32, i32, bool)> = self.render(game).cells().collect();
+        let columns = cells.iter().take_while(|cell| cell.1 == cells[0].1).count();
+        (
+            columns,
+            cells.into_iter().map(|(_, _, alive)| alive).collect(),
+        )
+    }
+
+    fn ordered_bounds(range: &RangeInclusive<i32>) -> (i32, i32) {
+        let start = *range.start();
+        let end = *range.end();
+        if start <= end {
+            (start, end)
+        } else {
+            (end, start)
+        }
+    }
+
+    /// Replaces the bounds with `len` cells centered on `center` along one axis.
+    fn span(center: i32, len: i64) -> RangeInclusive<i32> {
+        let min = (i64::from(center) - (len - 1) / 2).clamp(i32::MIN.into(), i32::MAX.into());
+        let max = (min + len - 1).min(i32::MAX.into());
+        min as i32..=max as i32
+    }
+
+    fn extent(range: &RangeInclusive<i32>) -> (i32, i64) {
+        let (min, max) = Self::ordered_bounds(range);
+        let len = i64::from(max) - i64::from(min) + 1;
+        ((i64::from(min) + (len - 1) / 2) as i32, len)
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen)]
+impl Viewport {
+    /// Returns how many cells along each axis one rendered glyph covers.
+    pub fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    /// Sets how many cells along each axis one rendered glyph covers; 0 is treated as 1.
+    pub fn set_scale(&mut self, scale: u32) {
+        self.scale = scale.max(1);
+    }
+
+    /// Returns whether toroidal boards are tiled past their edges.
+    pub fn wrap(&self) -> bool {
+        self.wrap
+    }
+
+    /// Tiles toroidal boards across the viewport when `wrap` is set, so patterns crossing an edge
+    /// show on both sides; otherwise cells past the edge render as off the board.
+    pub fn set_wrap(&mut self, wrap: bool) {
+        self.wrap = wrap;
+    }
+
+    /// Shrinks the bounds to fit inside a bounded or toroidal board, leaving them unchanged on the
+    /// infinite plane. Bounds that miss the board entirely collapse onto its nearest edge.
+    pub fn clamp_to(&mut self, game: &GameOfLife) {
+        let Some((width, height)) = game.topology().size() else {
+            return;
+        };
+        let clamp = |range: &RangeInclusive<i32>, len: i32| {
+            let (min, max) = Self::ordered_bounds(range);
+            min.clamp(0, len - 1)..=max.clamp(0, len - 1)
+        };
+        self.x_range = clamp(&self.x_range, width);
+        self.y_range = clamp(&self.y_range, height);
+    }
+
+    /// Shifts the bounds by `(dx, dy)` cells.
+    pub fn pan(&mut self, dx: i32, dy: i32) {
+        let (x_min, x_max) = Self::ordered_bounds(&self.x_range);
+        let (y_min, y_max) = Self::ordered_bounds(&self.y_range);
+        self.x_range = x_min.saturating_add(dx)..=x_max.saturating_add(dx);
+        self.y_range = y_min.saturating_add(dy)..=y_max.saturating_add(dy);
+    }
+
+    /// Zooms about the center: factors above 1 show fewer cells and factors below 1 show more.
+    ///
+    /// The bounds and the block scale shrink or grow together, so the rendered size stays the same
+    /// until zooming in reaches one cell per glyph; past that the bounds keep shrinking.
+    pub fn zoom(&mut self, factor: f64) {
+        assert!(
+            factor.is_finite() && factor > 0.0,
+            "zoom factor must be positive"
+        );
+        let (center_x, width) = Self::extent(&self.x_range);
+        let (center_y, height) = Self::extent(&self.y_range);
+        let resize = |len: i64| (round(len as f64 / factor) as i64).clamp(1, 1 << 32);
+        self.x_range = Self::span(center_x, resize(width));
+        self.y_range = Self::span(center_y, resize(height));
+        self.scale = round(f64::from(self.scale) / factor).clamp(1.0, u32::MAX.into()) as u32;
+    }
+
+    /// Moves the bounds, keeping their size, so `(x, y)` is in the middle.
+    pub fn center_on(&mut self, x: i32, y: i32) {
+        let (_, width) = Self::extent(&self.x_range);
+        let (_, height) = Self::extent(&self.y_range);
+        self.x_range = Self::span(x, width);
+        self.y_range = Self::span(y, height);
+    }
+
+    /// Sets the bounds to the bounding box of `game`'s live cells, leaving them unchanged on an
+    /// empty board.
+    pub fn fit_to(&mut self, game: &GameOfLife) {
+        if let Some(bounds) = game.bounding_box() {
+            self.x_range = bounds.min_x..=bounds.max_x;
+            self.y_range = bounds.min_y..=bounds.max_y;
+        }
+    }
+}
+
+pub struct ViewportRender<'a> {
+    viewport: &'a Viewport,
+    game: &'a GameOfLife,
+    style: &'a RenderStyle,
+}
+
+impl<'a> ViewportRender<'a> {
+    /// Iterates `(x, y, alive)` for every glyph in display order: top row first, left to right.
+    ///
+    /// At scale 1 each item is a cell. At larger scales `(x, y)` is the top-left cell of a block that
+    /// extends towards larger x and smaller y, clipped to the bounds.
+    pub fn cells(&self) -> ViewportCells<'a> {
+        let (x_min, x_max) = Viewport::ordered_bounds(&self.viewport.x_range);
+        let (y_min, y_max) = Viewport::ordered_bounds(&self.viewport.y_range);
+        ViewportCells {
+            game: self.game,
+            x_min,
+            x_max,
+            y_min,
+            scale: self.viewport.scale.max(1),
+            wrap: self.viewport.wrap,
+            next: Some((x_min, y_max)),
+        }
+    }
+}
+
+impl<'a> IntoIterator for &ViewportRender<'a> {
+    type Item = (i32, i32, bool);
+    type IntoIter = ViewportCells<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.cells()
+    }
+}
+
+impl<'a> ViewportRender<'a> {
+    /// Writes the glyph grid several glyphs to a character, padding the last row and column of
+    /// characters with dead glyphs.
+    fn write_packed(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let packing = self.style.packing();
+        let (columns, glyphs) = self.viewport.glyph_grid(self.game);
+        let rows = glyphs.len() / columns.max(1);
+        let (width, height) = packing.cell_size();
+        let alive = |column: usize, row: usize| {
+            column < columns && row < rows && glyphs[row * columns + column]
+        };
+        for row in (0..rows).step_by(height) {
+            for column in (0..columns).step_by(width) {
+                let block = |dx, dy| alive(column + dx, row + dy);
+                let any = (0..width).any(|dx| (0..height).any(|dy| block(dx, dy)));
+                self.style.write_packed(f, packing.glyph(block), any)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> fmt::Display for ViewportRender<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.style.packing() != Packing::Single {
+            return self.write_packed(f);
+        }
+        let cells = self.cells();
+        let (x_max, scale) = (cells.x_max, i64::from(cells.scale));
+        let ages = self.style.uses_ages().then(|| self.cells());
+
+        let states = self.style.uses_decay().then(|| self.cells());
+        let colors = self.style.uses_palette().then(|| self.cells());
+        let board = self.game.topology().size().is_some().then(|| self.cells());
+
+        for (x, y, alive) in cells {
+            let decay = match &states {
+                Some(states) if !alive => states.block_decay(x, y),
+                _ => None,
+            };
+            if board
+                .as_ref()
+                .is_some_and(|board| !board.block_on_board(x, y))
+            {
+                self.style.write_off_board(f)?;
+            } else if let Some(state) = decay {
+                self.style.write_decaying(f, state)?;
+            } else if let Some(colors) = colors.as_ref().filter(|_| alive) {
+                self.style.write_colored(f, colors.block_color(x, y))?;
+            } else {
+                let age = match &ages {
+                    Some(ages) if alive => ages.block_age(x, y),
+                    _ => alive.then_some(0),
+                };
+                self.style.write_cell(f, age)?;
+            }
+            if i64::from(x) + scale > i64::from(x_max) {
+                writeln!(f)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Iterator over the cells of a `ViewportRender`, produced by `ViewportRender::cells`.
+pub struct ViewportCells<'a> {
+    game: &'a GameOfLife,
+    x_min: i32,
+    x_max: i32,
+    y_min: i32,
+    scale: u32,
+    wrap: bool,
+    next: Option<(i32, i32)>,
+}
+
+impl<'a> ViewportCells<'a> {
+    /// Cells of the block anchored at `(x, y)`, clipped to the bounds.
+    fn block(&self, x: i32, y: i32) -> impl Iterator<Item = (i32, i32)> {
+        let step = i64::from(self.scale) - 1;
+        let x_end = (i64::from(x) + step).min(self.x_max.into()) as i32;
+        let y_end = (i64::from(y) - step).max(self.y_min.into()) as i32;
+        (y_end..=y).flat_map(move |cy| (x..=x_end).map(move |cx| (cx, cy)))
+    }
+
+    /// Returns whether `(x, y)` is drawn from the board: always on the infinite plane and on a
+    /// wrapped torus, and otherwise only inside the board's rectangle.
+    fn on_board(&self, x: i32, y: i32) -> bool {
+        match self.game.topology() {
+            Topology::Infinite => true,
+            Topology::Torus { .. } if self.wrap => true,
+            Topology::Bounded { width, height } | Topology::Torus { width, height } => {
+                (0..width).contains(&x) && (0..height).contains(&y)
+            }
+        }
+    }
+
+    /// Returns whether any cell of the block anchored at `(x, y)` is on the board.
+    fn block_on_board(&self, x: i32, y: i32) -> bool {
+        self.block(x, y).any(|(cx, cy)| self.on_board(cx, cy))
+    }
+
+    /// Cells of the block anchored at `(x, y)` that are on the board.
+    fn visible_block(&self, x: i32, y: i32) -> impl Iterator<Item = (i32, i32)> {
+        self.block(x, y).filter(|&(cx, cy)| self.on_board(cx, cy))
+    }
+
+    fn block_alive(&self, x: i32, y: i32) -> bool {
+        if self.scale == 1 {
+            return self.on_board(x, y) && self.game.get(x, y);
+        }
+        self.visible_block(x, y)
+            .any(|(cx, cy)| self.game.get(cx, cy))
+    }
+
+    /// Earliest decay state in the block anchored at `(x, y)`, or `None` if none of it is decaying.
+    fn block_decay(&self, x: i32, y: i32) -> Option<u8> {
+        self.visible_block(x, y)
+            .map(|(cx, cy)| self.game.cell_state(cx, cy))
+            .filter(|&state| state >= 2)
+            .min()
+    }
+
+    /// Highest color among the live cells of the block anchored at `(x, y)`.
+    fn block_color(&self, x: i32, y: i32) -> u8 {
+        self.visible_block(x, y)
+            .filter_map(|(cx, cy)| self.game.color(cx, cy))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Oldest age in the block anchored at `(x, y)`, or `None` if the whole block is dead.
+    fn block_age(&self, x: i32, y: i32) -> Option<u32> {
+        self.visible_block(x, y)
+            .filter_map(|(cx, cy)| self.game.age(cx, cy))
+            .max()
+    }
+}
+
+impl<'a> Iterator for ViewportCells<'a> {
+    type Item = (i32, i32, bool);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (x, y) = self.next?;
+        let step = i64::from(self.scale);
+        let next_x = i64::from(x) + step;
+        let next_y = i64::from(y) - step;
+        self.next = if next_x <= i64::from(self.x_max) {
+            Some((next_x as i32, y))
+        } else if next_y >= i64::from(self.y_min) {
+            Some((self.x_min, next_y as i32))
+        } else {
+            None
+        };
+        Some((x, y, self.block_alive(x, y)))
+    }
+}
+
+/// Rounds a non-negative `value` half away from zero, as `f64::round` does, which needs `std`.
+fn round(value: f64) -> f64 {
+    (value + 0.5) as u64 as f64
+}
@@ -0,0 +1,141 @@
+//! JavaScript-facing wrappers for APIs whose Rust signatures wasm-bindgen cannot export.
+//!
+//! Ranges, enums with data, and coordinate tuples are flattened into numbers, strings, and typed
+//! arrays. Coordinate arrays are interleaved as `[x0, y0, x1, y1, ...]`.
+
+use wasm_bindgen::prelude::*;
+
+use crate::{BoardDiff, Format, GameOfLife, Pattern, Rule, Topology, Transform, Viewport};
+
+#[wasm_bindgen]
+impl GameOfLife {
+    /// Returns the rule in `B3/S23` notation.
+    pub fn rule_notation(&self) -> String {
+        self.rule.to_string()
+    }
+
+    /// Parses `notation` (such as `B36/S23`) and evolves under it from now on.
+    pub fn set_rule_notation(&mut self, notation: &str) -> Result<(), JsValue> {
+        let rule: Rule = notation.parse()?;
+        self.set_rule(rule);
+        Ok(())
+    }
+
+    /// Makes the board infinite.
+    pub fn set_infinite(&mut self) {
+        self.set_topology(Topology::Infinite);
+    }
+
+    /// Bounds the board to `width` by `height` cells, dropping cells outside it.
+    pub fn set_bounded(&mut self, width: i32, height: i32) {
+        self.set_topology(Topology::Bounded { width, height });
+    }
+
+    /// Wraps the board into a `width` by `height` torus.
+    pub fn set_torus(&mut self, width: i32, height: i32) {
+        self.set_topology(Topology::Torus { width, height });
+    }
+
+    /// Returns every live cell, sorted, as an `Int32Array` of interleaved coordinates.
+    pub fn live_cells_flat(&self) -> Vec<i32> {
+        let mut cells: Vec<(i32, i32)> = self.iter_live().collect();
+        cells.sort_unstable();
+        cells.into_iter().flat_map(|(x, y)| [x, y]).collect()
+    }
+
+    /// Returns the live cells in the inclusive rectangle, sorted, as an `Int32Array` of
+    /// interleaved coordinates.
+    pub fn live_cells_in_rect(&self, x_min: i32, y_min: i32, x_max: i32, y_max: i32) -> Vec<i32> {
+        self.live_cells_in(x_min..=x_max, y_min..=y_max)
+            .into_iter()
+            .flat_map(|(x, y)| [x, y])
+            .collect()
+    }
+
+    /// Returns the tiles changed since `generation` as interleaved tile coordinates, or
+    /// `undefined` when the whole view must be redrawn.
+    pub fn changed_tiles_since_flat(&self, generation: u64) -> Option<Vec<i32>> {
+        self.changed_tiles_since(generation)
+            .map(|tiles| tiles.into_iter().flat_map(|(x, y)| [x, y]).collect())
+    }
+
+    /// Marks every cell in an interleaved coordinate array as alive, as one undoable edit.
+    pub fn set_cells_flat(&mut self, coords: &[i32]) {
+        self.set_cells(coords.chunks_exact(2).map(|pair| (pair[0], pair[1])));
+    }
+
+    /// Kills every cell in the inclusive rectangle.
+    pub fn clear_rect(&mut self, x_min: i32, y_min: i32, x_max: i32, y_max: i32) {
+        self.clear_region(x_min..=x_max, y_min..=y_max);
+    }
+
+    /// Moves every live cell by `(dx, dy)`.
+    #[wasm_bindgen(js_name = translate)]
+    pub fn translate_js(&mut self, dx: i32, dy: i32) {
+        self.translate(dx, dy);
+    }
+
+    /// Rotates the pattern a quarter turn counter-clockwise within its bounding box.
+    #[wasm_bindgen(js_name = rotate90)]
+    pub fn rotate90_js(&mut self) {
+        self.rotate90();
+    }
+
+    /// Mirrors the pattern left-to-right within its bounding box.
+    #[wasm_bindgen(js_name = flip_horizontal)]
+    pub fn flip_horizontal_js(&mut self) {
+        self.flip_horizontal();
+    }
+
+    /// Mirrors the pattern top-to-bottom within its bounding box.
+    #[wasm_bindgen(js_name = flip_vertical)]
+    pub fn flip_vertical_js(&mut self) {
+        self.flip_vertical();
+    }
+
+    /// Parses RLE, plaintext, Life 1.06, or macrocell text and stamps it with its minimum corner at `(x, y)`.
+    pub fn load_pattern(&mut self, text: &str, x: i32, y: i32) -> Result<(), JsValue> {
+        let pattern = Pattern::parse(text, Format::detect(text))?;
+        self.stamp(&pattern, x, y, Transform::Identity);
+        Ok(())
+    }
+
+    /// Serializes the live cells as `"rle"`, `"plaintext"`, `"life106"`, or `"macrocell"` text.
+    pub fn export_pattern(&self, format: &str) -> Result<String, JsValue> {
+        let format = match format {
+            "rle" => Format::Rle,
+            "plaintext" => Format::Plaintext,
+            "life106" => Format::Life106,
+            "macrocell" => Format::Macrocell,
+            other => return Err(JsValue::from_str(&format!("unknown format {other:?}"))),
+        };
+        Ok(Pattern::from_game(self).write(format))
+    }
+}
+
+#[wasm_bindgen]
+impl Viewport {
+    /// Creates a viewport over the inclusive bounds.
+    #[wasm_bindgen(constructor)]
+    pub fn from_bounds(x_min: i32, x_max: i32, y_min: i32, y_max: i32) -> Viewport {
+        Viewport::new(x_min..=x_max, y_min..=y_max)
+    }
+
+    /// Renders `game` as text, top row first.
+    pub fn render_text(&self, game: &GameOfLife) -> String {
+        self.render(game).to_string()
+    }
+}
+
+#[wasm_bindgen]
+impl BoardDiff {
+    /// Returns the births as an `Int32Array` of interleaved coordinates.
+    pub fn births_flat(&self) -> Vec<i32> {
+        self.births().iter().flat_map(|&(x, y)| [x, y]).collect()
+    }
+
+    /// Returns the deaths as an `Int32Array` of interleaved coordinates.
+    pub fn deaths_flat(&self) -> Vec<i32> {
+        self.deaths().iter().flat_map(|&(x, y)| [x, y]).collect()
+    }
+}
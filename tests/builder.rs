@@ -0,0 +1,80 @@
+use gameoflife::{Backend, CoordLimit, GameOfLife, Rule, Topology, patterns};
+
+#[test]
+fn builds_a_configured_board() {
+    let highlife: Rule = "B36/S23".parse().unwrap();
+    let topology = Topology::Torus {
+        width: 16,
+        height: 16,
+    };
+    let game = GameOfLife::builder()
+        .with_rule(highlife)
+        .with_topology(topology)
+        .with_history_limit(4)
+        .with_coord_limit(CoordLimit::new(100))
+        .with_pattern(patterns::glider())
+        .build();
+
+    assert_eq!(game.rule(), highlife);
+    assert_eq!(game.topology(), topology);
+    assert_eq!(game.history_limit(), 4);
+    assert_eq!(game.coord_limit(), Some(CoordLimit::new(100)));
+    assert_eq!(game, patterns::glider().to_game());
+    assert_eq!(game.generation(), 0);
+    assert!(!game.can_undo());
+}
+
+#[test]
+fn defaults_match_a_new_board() {
+    let game = GameOfLife::builder().build();
+    assert_eq!(game, GameOfLife::new());
+    assert_eq!(game.rule(), Rule::conway());
+    assert_eq!(game.history_limit(), GameOfLife::new().history_limit());
+
+    // Without an explicit rule, the pattern's own rule applies.
+    let seeds: Rule = "B2/S".parse().unwrap();
+    let game = GameOfLife::builder()
+        .with_pattern(patterns::block().with_rule(seeds))
+        .build();
+    assert_eq!(game.rule(), seeds);
+}
+
+#[test]
+fn soups_are_seeded_and_patterns_drawn_over_them() {
+    let soup = |seed| {
+        GameOfLife::builder()
+            .with_soup(20, 20, 0.5)
+            .with_seed(seed)
+            .build()
+    };
+    assert_eq!(soup(3), GameOfLife::random_soup(20, 20, 0.5, 3));
+    assert_eq!(
+        soup(0),
+        GameOfLife::builder().with_soup(20, 20, 0.5).build()
+    );
+    assert_ne!(soup(3), soup(4));
+
+    let game = GameOfLife::builder()
+        .with_soup(20, 20, 0.5)
+        .with_pattern(patterns::block())
+        .build();
+    assert!(game.get(0, 0) && game.get(1, 1));
+}
+
+#[test]
+fn engines_use_the_chosen_backend() {
+    let builder = GameOfLife::builder()
+        .with_backend(Backend::Dense)
+        .with_pattern(patterns::blinker());
+    assert!(builder.build_engine().is_none());
+
+    let mut engine = builder
+        .with_topology(Topology::Bounded {
+            width: 8,
+            height: 8,
+        })
+        .build_engine()
+        .unwrap();
+    engine.advance(1);
+    assert_eq!(engine.live_cells(), [(0, 1), (1, 1)]);
+}
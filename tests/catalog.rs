@@ -0,0 +1,98 @@
+use gameoflife::catalog::{self, Catalog, Entry, Kind, Speed};
+use gameoflife::{GameOfLife, Pattern, Transform};
+
+#[test]
+fn finds_patterns_by_name() {
+    let gun = catalog::find("glider gun").unwrap();
+    assert_eq!(gun.name(), "Gosper glider gun");
+    assert_eq!(gun.kind(), Kind::Gun);
+    assert_eq!(gun.discoverer(), Some("Bill Gosper"));
+    assert_eq!(gun.bounding_box().unwrap().width(), 36);
+
+    assert_eq!(
+        catalog::find("GLIDER").unwrap().name(),
+        "Glider",
+        "exact names win over longer matches"
+    );
+    assert!(catalog::find("caterpillar").is_none());
+    assert_eq!(catalog::builtin().search("glider").count(), 2);
+}
+
+#[test]
+fn filters_by_period_and_kind() {
+    let builtin = catalog::builtin();
+    let period_two: Vec<&str> = builtin.with_period(2).map(Entry::name).collect();
+    assert_eq!(period_two, ["Blinker", "Toad", "Beacon"]);
+    assert_eq!(builtin.of_kind(Kind::StillLife).count(), 5);
+    assert_eq!(builtin.of_kind(Kind::Methuselah).count(), 3);
+}
+
+#[test]
+fn builtin_metadata_matches_the_simulation() {
+    for entry in catalog::builtin().entries() {
+        let (Some(period), Kind::StillLife | Kind::Oscillator | Kind::Spaceship) =
+            (entry.period(), entry.kind())
+        else {
+            continue;
+        };
+        let mut game = entry.pattern().to_game();
+        game.advance(period);
+
+        let mut expected = GameOfLife::new();
+        let bounds = entry.bounding_box().unwrap();
+        let (dx, dy) = entry.speed().map_or((0, 0), |speed| (speed.dx, speed.dy));
+        expected.stamp(
+            entry.pattern(),
+            bounds.min_x + dx,
+            bounds.min_y + dy,
+            Transform::Identity,
+        );
+        assert_eq!(game, expected, "{}", entry.name());
+    }
+}
+
+#[test]
+fn speeds_use_c_notation() {
+    assert_eq!(
+        catalog::find("glider")
+            .unwrap()
+            .speed()
+            .unwrap()
+            .to_string(),
+        "c/4 diagonal"
+    );
+    assert_eq!(
+        catalog::find("lightweight")
+            .unwrap()
+            .speed()
+            .unwrap()
+            .to_string(),
+        "c/2 orthogonal"
+    );
+    let knightship = Speed {
+        dx: 2,
+        dy: 1,
+        period: 6,
+    };
+    assert_eq!(knightship.to_string(), "c/3 oblique");
+    assert!(catalog::find("pulsar").unwrap().speed().is_none());
+}
+
+#[test]
+fn user_patterns_join_the_catalog() {
+    let mut catalog = Catalog::new();
+    catalog.extend(catalog::builtin().entries().iter().cloned());
+    let pattern = Pattern::parse(
+        "#N Clock\nx = 4, y = 4\n2bo$obo$bobo$bo!",
+        gameoflife::Format::Rle,
+    )
+    .unwrap();
+    catalog.add(
+        Entry::new(pattern)
+            .with_kind(Kind::Oscillator)
+            .with_period(2)
+            .with_discoverer("Simon Norton"),
+    );
+    assert_eq!(catalog.find("clock").unwrap().period(), Some(2));
+    assert_eq!(catalog.with_period(2).count(), 4);
+}
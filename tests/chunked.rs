@@ -0,0 +1,92 @@
+use gameoflife::{Backend, ChunkedGrid, Engine, GameOfLife, Rule, Topology, Transform, patterns};
+
+fn assert_engines_agree(game: GameOfLife, generations: u64) {
+    let mut reference = game.clone();
+    let mut chunked = ChunkedGrid::from_game(&game).unwrap();
+    for _ in 0..generations {
+        reference.advance(1);
+        chunked.advance(1);
+        assert_eq!(Engine::live_cells(&chunked), Engine::live_cells(&reference));
+    }
+    assert_eq!(chunked.generation(), reference.generation());
+    assert_eq!(chunked.population(), reference.population());
+}
+
+#[test]
+fn matches_sparse_engine_on_soups_straddling_chunk_edges() {
+    for (x, y) in [(0, 0), (-40, -40), (50, -90), (-130, 60)] {
+        let mut game = GameOfLife::new();
+        game.stamp(&patterns::soup(80, 80, 0.4, 7), x, y, Transform::Identity);
+        assert_engines_agree(game, 40);
+    }
+}
+
+#[test]
+fn matches_sparse_engine_on_methuselahs_and_guns() {
+    assert_engines_agree(patterns::acorn().to_game(), 300);
+    assert_engines_agree(patterns::gosper_glider_gun().to_game(), 240);
+}
+
+#[test]
+fn matches_sparse_engine_under_other_rules() {
+    let mut game = GameOfLife::with_rule(Rule::highlife());
+    game.stamp(
+        &patterns::soup(70, 70, 0.5, 3),
+        -35,
+        -35,
+        Transform::Identity,
+    );
+    assert_engines_agree(game, 30);
+}
+
+#[test]
+fn gliders_cross_into_new_chunks_and_free_old_ones() {
+    let mut grid = ChunkedGrid::from_game(&patterns::glider().to_game()).unwrap();
+    assert_eq!(grid.chunk_count(), 1);
+
+    // A glider shifts one cell diagonally every four generations.
+    grid.advance(4 * 200);
+    let mut expected = patterns::glider().to_game();
+    expected.translate(200, 200);
+    assert_eq!(Engine::live_cells(&grid), Engine::live_cells(&expected));
+    assert!(grid.chunk_count() <= 2);
+}
+
+#[test]
+fn set_and_unset_round_trip_through_games() {
+    let mut grid = ChunkedGrid::new(Rule::conway());
+    for (x, y) in [(0, 0), (-1, -1), (63, 64), (-64, 100), (i32::MIN, i32::MAX)] {
+        grid.set(x, y);
+        assert!(grid.get(x, y));
+    }
+    assert_eq!(grid.population(), 5);
+    grid.unset(-1, -1);
+    assert!(!grid.get(-1, -1));
+    assert_eq!(grid.population(), 4);
+
+    let game = grid.to_game();
+    assert_eq!(Engine::live_cells(&game), Engine::live_cells(&grid));
+    assert_eq!(ChunkedGrid::from_game(&game).unwrap(), grid);
+}
+
+#[test]
+fn backend_builds_only_for_infinite_two_state_rules() {
+    let torus = GameOfLife::with_topology(Topology::Torus {
+        width: 10,
+        height: 10,
+    });
+    assert!(Backend::Chunked.build(&torus).is_none());
+    assert!(
+        Backend::Chunked
+            .build(&GameOfLife::with_rule("B03/S23".parse().unwrap()))
+            .is_none()
+    );
+
+    let mut engine = Backend::Chunked
+        .build(&patterns::r_pentomino().to_game())
+        .unwrap();
+    engine.advance(100);
+    let mut reference = patterns::r_pentomino().to_game();
+    reference.advance(100);
+    assert_eq!(engine.live_cells(), Engine::live_cells(&reference));
+}
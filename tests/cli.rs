@@ -0,0 +1,158 @@
+use std::fs;
+use std::process::{Command, Output};
+
+use gameoflife::GameOfLife;
+
+fn run(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_gameoflife"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+fn stdout(args: &[&str]) -> String {
+    let output = run(args);
+    assert!(output.status.success(), "{output:?}");
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn headless_prints_the_final_bounding_box() {
+    assert_eq!(stdout(&["--headless"]), "OOO\n");
+    assert_eq!(stdout(&["--headless", "--steps", "1"]), "O\nO\nO\n");
+}
+
+#[test]
+fn headless_honors_viewport_rule_and_pattern() {
+    let path = std::env::temp_dir().join(format!("cli-glider-{}.rle", std::process::id()));
+    fs::write(&path, "x = 3, y = 3\nbo$2bo$3o!\n").unwrap();
+    let pattern = path.to_str().unwrap();
+
+    assert_eq!(
+        stdout(&[
+            "--headless",
+            "--pattern",
+            pattern,
+            "--steps",
+            "4",
+            "--viewport",
+            "0,0,3,3"
+        ]),
+        "....\n..O.\n...O\n.OOO\n"
+    );
+    // Seeds (B2/S) kills every live cell and births none in this corner.
+    assert_eq!(
+        stdout(&[
+            "--headless",
+            "--pattern",
+            pattern,
+            "--rule",
+            "B2/S",
+            "--steps",
+            "1",
+            "--viewport",
+            "0,0,1,1"
+        ]),
+        "..\n..\n"
+    );
+    fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn bad_arguments_are_reported() {
+    assert!(!run(&["--headless", "--rule", "B9/S"]).status.success());
+    assert!(!run(&["--headless", "--viewport", "1,2,3"]).status.success());
+
+    let missing = run(&["--headless", "--pattern", "/nonexistent/pattern.rle"]);
+    assert!(!missing.status.success());
+    assert!(String::from_utf8_lossy(&missing.stderr).contains("cannot read"));
+}
+
+#[test]
+fn soups_are_reproducible() {
+    let args = [
+        "--headless",
+        "--soup",
+        "12x8",
+        "--seed",
+        "3",
+        "--density",
+        "0.4",
+    ];
+    let soup = stdout(&args);
+    assert_eq!(soup, stdout(&args));
+    assert!(soup.contains('O'));
+    assert!(soup.lines().count() <= 8);
+}
+
+#[test]
+fn bench_reports_rate_population_and_memory() {
+    let report = stdout(&[
+        "--bench",
+        "--soup",
+        "32x32",
+        "--torus",
+        "32x32",
+        "--backend",
+        "dense",
+        "--steps",
+        "10",
+    ]);
+    assert!(report.contains("backend:     Dense"), "{report}");
+    assert!(report.contains("generations: 10"), "{report}");
+    for field in ["rate:", "population:", "memory:"] {
+        assert!(report.contains(field), "{report}");
+    }
+
+    // The dense backend needs a finite board.
+    let output = run(&["--bench", "--backend", "dense"]);
+    assert!(!output.status.success());
+}
+
+#[test]
+fn sessions_resume_where_they_were_saved() {
+    let path = std::env::temp_dir().join(format!("gol-cli-session-{}.bin", std::process::id()));
+    let session = path.to_str().unwrap();
+    assert_eq!(
+        stdout(&["--headless", "--steps", "3", "--save", session]),
+        "O\nO\nO\n"
+    );
+    assert_eq!(
+        stdout(&["--headless", "--resume", session, "--steps", "1"]),
+        "OOO\n"
+    );
+    fs::remove_file(&path).unwrap();
+
+    let missing = run(&["--headless", "--resume", session]);
+    assert!(!missing.status.success());
+    assert!(String::from_utf8_lossy(&missing.stderr).contains("cannot resume"));
+}
+
+#[test]
+fn headless_runs_keep_resumed_history() {
+    let dir = std::env::temp_dir();
+    let first = dir.join(format!("gol-cli-history-a-{}.bin", std::process::id()));
+    let second = dir.join(format!("gol-cli-history-b-{}.bin", std::process::id()));
+    let (first_path, second_path) = (first.to_str().unwrap(), second.to_str().unwrap());
+    stdout(&["--headless", "--steps", "3", "--save", first_path]);
+    stdout(&[
+        "--headless",
+        "--resume",
+        first_path,
+        "--steps",
+        "2",
+        "--save",
+        second_path,
+    ]);
+
+    // Each headless run is one undoable step, on top of the history it resumed.
+    let mut game = GameOfLife::load(&second).unwrap();
+    assert_eq!(game.generation(), 5);
+    assert!(game.undo());
+    assert_eq!(game.generation(), 3);
+    assert!(game.undo());
+    assert_eq!(game.generation(), 0);
+    assert!(!game.undo());
+    fs::remove_file(&first).unwrap();
+    fs::remove_file(&second).unwrap();
+}
@@ -0,0 +1,86 @@
+use gameoflife::{Color, ColorMode, GameOfLife, RenderStyle, Viewport};
+
+fn blinker(mode: ColorMode, colors: [u8; 3]) -> GameOfLife {
+    let mut game = GameOfLife::new();
+    game.set_color_mode(mode);
+    for (x, color) in (0..3).zip(colors) {
+        game.set_colored(x, 0, color);
+    }
+    game
+}
+
+#[test]
+fn newborns_take_their_parents_majority_color() {
+    let mut game = blinker(ColorMode::Immigration, [1, 1, 0]);
+    game.tick();
+    assert_eq!(game.color(1, -1), Some(1));
+    assert_eq!(game.color(1, 0), Some(1));
+    assert_eq!(game.color(1, 1), Some(1));
+    assert_eq!(game.color(0, 0), None);
+
+    game.tick();
+    assert_eq!(game.color(0, 0), Some(1));
+    assert_eq!(game.color(2, 0), Some(1));
+}
+
+#[test]
+fn quadlife_births_from_three_colors_take_the_fourth() {
+    let mut game = blinker(ColorMode::QuadLife, [0, 1, 2]);
+    game.tick();
+    assert_eq!(game.color(1, -1), Some(3));
+    assert_eq!(game.color(1, 1), Some(3));
+    assert_eq!(game.color(1, 0), Some(1));
+
+    let mut game = blinker(ColorMode::QuadLife, [2, 9, 0]);
+    assert_eq!(game.color(1, 0), Some(3));
+    game.tick();
+    assert_eq!(game.color(1, 1), Some(1));
+}
+
+#[test]
+fn colors_never_change_how_the_board_evolves() {
+    let mono = GameOfLife::random_soup(24, 24, 0.4, 7);
+    let mut colored = mono.clone();
+    colored.set_color_mode(ColorMode::QuadLife);
+    let cells: Vec<(i32, i32)> = colored.iter_live().collect();
+    for (index, (x, y)) in cells.into_iter().enumerate() {
+        colored.set_colored(x, y, index as u8 % 4);
+    }
+
+    let mut mono = mono;
+    for _ in 0..60 {
+        mono.tick();
+        colored.tick();
+        assert_eq!(colored, mono);
+    }
+    assert!(
+        colored
+            .iter_live()
+            .any(|(x, y)| colored.color(x, y) != Some(0))
+    );
+}
+
+#[test]
+fn colors_render_and_survive_undo_and_snapshots() {
+    let mut game = blinker(ColorMode::Immigration, [1, 1, 0]);
+    assert_eq!(game.colors_at(4, 1, 0, 0), [2, 2, 1, 0]);
+
+    let style = RenderStyle::ascii().with_palette([Color::Red, Color::Blue]);
+    let viewport = Viewport::new(0..=2, 0..=0);
+    assert_eq!(
+        viewport.render_styled(&game, &style).to_string(),
+        "\x1b[34m#\x1b[0m\x1b[34m#\x1b[0m\x1b[31m#\x1b[0m\n"
+    );
+
+    let restored = GameOfLife::from_snapshot(&game.to_snapshot()).unwrap();
+    assert_eq!(restored.color_mode(), ColorMode::Immigration);
+    assert_eq!(restored.colors_at(4, 1, 0, 0), [2, 2, 1, 0]);
+
+    game.set_colored(2, 0, 1);
+    assert_eq!(game.color(2, 0), Some(1));
+    assert!(game.undo());
+    assert_eq!(game.color(2, 0), Some(0));
+
+    game.set_color_mode(ColorMode::Mono);
+    assert_eq!(game.colors_at(4, 1, 0, 0), [1, 1, 1, 0]);
+}
@@ -0,0 +1,77 @@
+use gameoflife::{Cycle, GameOfLife, Transform, patterns};
+
+#[test]
+fn advance_runs_many_generations_as_one_undo_step() {
+    let mut stepped = patterns::r_pentomino().to_game();
+    let mut advanced = stepped.clone();
+    for _ in 0..50 {
+        stepped.tick();
+    }
+    advanced.advance(50);
+
+    assert_eq!(advanced, stepped);
+    assert_eq!(advanced.generation(), 50);
+    assert!(advanced.undo());
+    assert_eq!(advanced, patterns::r_pentomino().to_game());
+    assert_eq!(advanced.generation(), 0);
+}
+
+#[test]
+fn advance_skips_ahead_once_stable() {
+    let mut game = patterns::block().to_game();
+    game.advance(u64::MAX / 2);
+    assert_eq!(game.generation(), u64::MAX / 2);
+    assert_eq!(game, patterns::block().to_game());
+}
+
+#[test]
+fn detects_still_lifes_and_oscillators() {
+    let block = patterns::block().to_game().detect_cycle(10).unwrap();
+    assert_eq!(
+        block,
+        Cycle {
+            offset: 0,
+            period: 1
+        }
+    );
+    assert!(block.is_still_life());
+
+    let pulsar = patterns::pulsar().to_game();
+    assert_eq!(
+        pulsar.detect_cycle(10),
+        Some(Cycle {
+            offset: 0,
+            period: 3
+        })
+    );
+    assert_eq!(
+        patterns::pentadecathlon().to_game().detect_cycle(40),
+        Some(Cycle {
+            offset: 0,
+            period: 15
+        })
+    );
+}
+
+#[test]
+fn detects_transients_before_the_cycle() {
+    // Three cells in an L become a block after one generation.
+    let mut game = GameOfLife::new();
+    game.set_cells([(0, 0), (1, 0), (0, 1)]);
+    assert_eq!(
+        game.detect_cycle(10),
+        Some(Cycle {
+            offset: 1,
+            period: 1
+        })
+    );
+    assert_eq!(game.generation(), 0, "detection does not mutate the board");
+}
+
+#[test]
+fn gliders_and_long_transients_are_not_cycles() {
+    let mut game = GameOfLife::new();
+    game.stamp(&patterns::glider(), 0, 0, Transform::Identity);
+    assert_eq!(game.detect_cycle(100), None);
+    assert_eq!(patterns::r_pentomino().to_game().detect_cycle(100), None);
+}
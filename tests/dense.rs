@@ -0,0 +1,111 @@
+use gameoflife::{Backend, DenseGrid, Engine, GameOfLife, Rule, Topology, Transform, patterns};
+
+/// Fills roughly a third of the board with a fixed xorshift sequence.
+fn soup(topology: Topology, rule: Rule) -> GameOfLife {
+    let (width, height) = topology.size().unwrap();
+    let mut game = GameOfLife::with_topology(topology);
+    game.set_rule(rule);
+    let mut seed = 0x2545_f491_4f6c_dd1d_u64;
+    game.set_cells(
+        (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .filter(|_| {
+                seed ^= seed << 13;
+                seed ^= seed >> 7;
+                seed ^= seed << 17;
+                seed.is_multiple_of(3)
+            }),
+    );
+    game
+}
+
+fn assert_engines_agree(game: GameOfLife, generations: u64) {
+    let mut reference = game.clone();
+    let mut dense = DenseGrid::from_game(&game).unwrap();
+    for _ in 0..generations {
+        reference.advance(1);
+        dense.advance(1);
+        assert_eq!(Engine::live_cells(&dense), Engine::live_cells(&reference));
+    }
+    assert_eq!(dense.generation(), reference.generation());
+}
+
+#[test]
+fn matches_sparse_engine_on_tori_of_awkward_widths() {
+    for width in [1, 3, 63, 64, 65, 130] {
+        let topology = Topology::Torus { width, height: 17 };
+        assert_engines_agree(soup(topology, Rule::conway()), 20);
+    }
+}
+
+#[test]
+fn matches_sparse_engine_on_bounded_boards() {
+    for width in [5, 64, 100] {
+        let topology = Topology::Bounded { width, height: 40 };
+        assert_engines_agree(soup(topology, Rule::conway()), 20);
+        assert_engines_agree(soup(topology, Rule::day_and_night()), 10);
+    }
+}
+
+#[test]
+fn gliders_wrap_around_a_torus() {
+    let mut game = GameOfLife::with_topology(Topology::Torus {
+        width: 70,
+        height: 20,
+    });
+    game.stamp(&patterns::glider(), 66, 16, Transform::Identity);
+    let start = Engine::live_cells(&game);
+
+    // A glider shifts one cell diagonally every four generations; 560 returns it home on 70x20.
+    let mut dense = DenseGrid::from_game(&game).unwrap();
+    dense.advance(560);
+    assert_eq!(dense.live_cells(), start);
+}
+
+#[test]
+fn converts_to_and_from_game() {
+    let game = soup(
+        Topology::Bounded {
+            width: 20,
+            height: 20,
+        },
+        Rule::highlife(),
+    );
+    let mut dense = DenseGrid::from_game(&game).unwrap();
+    assert_eq!(dense.population(), game.population());
+    dense.set(25, 3);
+    assert!(!dense.get(25, 3));
+    dense.set(19, 19);
+    assert!(dense.get(19, 19));
+    dense.unset(19, 19);
+    assert_eq!(dense.to_game(), game);
+    assert_eq!(dense.to_game().rule(), Rule::highlife());
+
+    assert!(DenseGrid::from_game(&GameOfLife::new()).is_none());
+}
+
+#[test]
+fn backends_are_selected_by_topology() {
+    let torus = soup(
+        Topology::Torus {
+            width: 30,
+            height: 30,
+        },
+        Rule::conway(),
+    );
+    assert!(Backend::HashLife.build(&torus).is_none());
+    assert!(Backend::Dense.build(&GameOfLife::new()).is_none());
+    assert!(
+        Backend::HashLife
+            .build(&GameOfLife::with_rule(Rule::seeds()))
+            .is_some()
+    );
+
+    let mut expected = torus.clone();
+    expected.advance(12);
+    for backend in [Backend::Sparse, Backend::Dense] {
+        let mut engine = backend.build(&torus).unwrap();
+        engine.advance(12);
+        assert_eq!(engine.live_cells(), Engine::live_cells(&expected));
+    }
+}
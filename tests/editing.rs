@@ -0,0 +1,117 @@
+use gameoflife::GameOfLife;
+
+fn live_cells(game: &GameOfLife, extent: i32) -> Vec<(i32, i32)> {
+    let mut cells = Vec::new();
+    for y in -extent..=extent {
+        for x in -extent..=extent {
+            if game.get(x, y) {
+                cells.push((x, y));
+            }
+        }
+    }
+    cells
+}
+
+/// An L-tromino: two cells along the bottom and one above the left end.
+fn l_shape() -> GameOfLife {
+    let mut game = GameOfLife::new();
+    game.set_cells([(0, 0), (1, 0), (0, 1)]);
+    game
+}
+
+#[test]
+fn set_cells_records_one_snapshot() {
+    let mut game = GameOfLife::new();
+    game.set_cells((0..10).map(|x| (x, 0)));
+    assert_eq!(game.history_len(), 1);
+    assert_eq!(live_cells(&game, 10).len(), 10);
+
+    game.set_cells([(0, 0), (1, 0)]);
+    assert_eq!(game.history_len(), 1, "setting live cells is a no-op");
+
+    assert!(game.undo());
+    assert!(live_cells(&game, 10).is_empty());
+}
+
+#[test]
+fn clear_region_only_touches_the_region() {
+    let mut game = GameOfLife::new();
+    game.set_cells((0..10).map(|x| (x, 0)));
+    game.clear_region(2..=4, -1..=1);
+    assert_eq!(
+        live_cells(&game, 10),
+        vec![(0, 0), (1, 0), (5, 0), (6, 0), (7, 0), (8, 0), (9, 0)]
+    );
+}
+
+#[test]
+fn translate_moves_every_cell() {
+    let mut game = l_shape();
+    game.translate(3, -2);
+    assert_eq!(live_cells(&game, 5), vec![(3, -2), (4, -2), (3, -1)]);
+
+    let mut partial = l_shape();
+    partial.translate_region(1..=1, 0..=0, 0, 4);
+    assert_eq!(live_cells(&partial, 5), vec![(0, 0), (0, 1), (1, 4)]);
+}
+
+#[test]
+fn rotate90_turns_counter_clockwise_in_place() {
+    let mut game = l_shape();
+    game.rotate90();
+    assert_eq!(live_cells(&game, 5), vec![(0, 0), (1, 0), (1, 1)]);
+
+    for _ in 0..3 {
+        game.rotate90();
+    }
+    assert_eq!(game, l_shape());
+}
+
+#[test]
+fn flips_mirror_within_bounding_box() {
+    let mut horizontal = l_shape();
+    horizontal.flip_horizontal();
+    assert_eq!(live_cells(&horizontal, 5), vec![(0, 0), (1, 0), (1, 1)]);
+
+    let mut vertical = l_shape();
+    vertical.flip_vertical();
+    assert_eq!(live_cells(&vertical, 5), vec![(0, 0), (0, 1), (1, 1)]);
+
+    let mut region = l_shape();
+    region.set(5, 5);
+    region.flip_vertical_region(0..=1, 0..=1);
+    assert_eq!(live_cells(&region, 5), vec![(0, 0), (0, 1), (1, 1), (5, 5)]);
+}
+
+#[test]
+fn moved_cells_keep_their_ages() {
+    let mut game = GameOfLife::new();
+    game.set_cells([(0, 0), (0, 1), (1, 0), (1, 1)]);
+    game.advance(4);
+    game.set(5, 5);
+
+    game.translate(10, 0);
+    assert_eq!(game.age(10, 0), Some(4));
+    assert_eq!(game.age(15, 5), Some(0));
+
+    game.rotate90_region(10..=11, 0..=1);
+    assert_eq!(game.age(11, 1), Some(4));
+}
+
+#[test]
+fn cells_moved_past_the_edge_of_the_plane_are_dropped() {
+    let mut game = GameOfLife::new();
+    game.set_cells([(i32::MAX, 0), (0, 0)]);
+    game.translate(1, 0);
+    assert_eq!(game.iter_live().collect::<Vec<_>>(), [(1, 0)]);
+
+    let mut wide = GameOfLife::new();
+    wide.set_cells([(i32::MIN, 0), (i32::MAX, 1)]);
+    wide.flip_horizontal();
+    let mut cells: Vec<_> = wide.iter_live().collect();
+    cells.sort_unstable();
+    assert_eq!(cells, [(i32::MIN, 1), (i32::MAX, 0)]);
+    // Turning a box wider than the plane is tall sends its far cells past the edge.
+    wide.rotate90();
+    assert_eq!(wide.iter_live().collect::<Vec<_>>(), [(i32::MIN, 0)]);
+}
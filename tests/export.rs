@@ -0,0 +1,169 @@
+#![cfg(feature = "image")]
+
+use std::time::Duration;
+
+use gameoflife::ImageStyle;
+use gameoflife::export;
+use gameoflife::{Color, Viewport, patterns};
+
+/// Splits a PNG into `(type, data)` chunks, checking the signature and chunk lengths.
+fn png_chunks(bytes: &[u8]) -> Vec<(String, Vec<u8>)> {
+    assert_eq!(&bytes[..8], b"\x89PNG\r\n\x1a\n");
+    let mut chunks = Vec::new();
+    let mut rest = &bytes[8..];
+    while !rest.is_empty() {
+        let len = u32::from_be_bytes(rest[..4].try_into().unwrap()) as usize;
+        let kind = String::from_utf8(rest[4..8].to_vec()).unwrap();
+        chunks.push((kind, rest[8..8 + len].to_vec()));
+        rest = &rest[12 + len..];
+    }
+    chunks
+}
+
+/// Decodes the first frame of a GIF written by `export::gif` into palette indices.
+fn gif_first_frame(bytes: &[u8]) -> Vec<u8> {
+    // Header, screen descriptor, four-entry color table, Netscape and graphic control extensions.
+    let descriptor = &bytes[13 + 12 + 19 + 8..];
+    assert_eq!(descriptor[0], 0x2c);
+    assert_eq!(descriptor[10], 2, "minimum code size");
+    let mut data = Vec::new();
+    let mut rest = &descriptor[11..];
+    while rest[0] != 0 {
+        let len = rest[0] as usize;
+        data.extend_from_slice(&rest[1..=len]);
+        rest = &rest[1 + len..];
+    }
+
+    let (clear, end) = (4, 5);
+    let mut table: Vec<Vec<u8>> = Vec::new();
+    let mut width = 3;
+    let mut previous: Option<Vec<u8>> = None;
+    let mut pixels = Vec::new();
+    let mut bit = 0;
+    loop {
+        let code = (0..width).fold(0, |code, i| {
+            let at = bit + i;
+            code | (usize::from(data[at / 8] >> (at % 8) & 1) << i)
+        });
+        bit += width;
+        if code == clear {
+            table = (0..4).map(|index| vec![index]).chain([vec![], vec![]]).collect();
+            width = 3;
+            previous = None;
+            continue;
+        }
+        if code == end {
+            return pixels;
+        }
+        let entry = match (table.get(code), &previous) {
+            (Some(entry), _) => entry.clone(),
+            (None, Some(previous)) => [previous.as_slice(), &previous[..1]].concat(),
+            (None, None) => panic!("code {code} before any entry"),
+        };
+        if let Some(previous) = previous {
+            table.push([previous.as_slice(), &entry[..1]].concat());
+            if table.len() == 1 << width && width < 12 {
+                width += 1;
+            }
+        }
+        pixels.extend_from_slice(&entry);
+        previous = Some(entry);
+    }
+}
+
+#[test]
+fn gif_has_one_frame_per_generation() {
+    let game = patterns::glider().to_game();
+    let viewport = Viewport::new(0..=9, 0..=4);
+    let style = ImageStyle::new()
+        .with_cell_size(3)
+        .with_live(Color::Red)
+        .with_frame_delay(Duration::from_millis(250));
+    let gif = export::gif(&game, &viewport, 8, &style);
+
+    assert_eq!(&gif[..6], b"GIF89a");
+    assert_eq!(&gif[6..10], &[30, 0, 15, 0]);
+    assert_eq!(&gif[13..19], &[255, 255, 255, 205, 0, 0]);
+    let delays = gif
+        .windows(6)
+        .filter(|window| window[..4] == [0x21, 0xf9, 4, 0] && window[4..] == [25, 0])
+        .count();
+    assert_eq!(delays, 8);
+    assert_eq!(gif.last(), Some(&0x3b));
+    assert_eq!(game.generation(), 0, "the board itself is not advanced");
+}
+
+#[test]
+fn gif_frames_decode_to_the_board() {
+    let game = patterns::glider().to_game();
+    let viewport = Viewport::new(0..=9, 0..=4);
+    let gif = export::gif(&game, &viewport, 1, &ImageStyle::new().with_cell_size(3));
+
+    let pixels = gif_first_frame(&gif);
+    assert_eq!(pixels.len(), 30 * 15);
+    for (index, &pixel) in pixels.iter().enumerate() {
+        let (x, y) = ((index % 30 / 3) as i32, 4 - (index / 30 / 3) as i32);
+        assert_eq!(pixel, u8::from(game.get(x, y)), "pixel {index}");
+    }
+}
+
+#[test]
+#[should_panic(expected = "too large for a GIF")]
+fn gif_rejects_images_wider_than_the_format_allows() {
+    let game = patterns::block().to_game();
+    let viewport = Viewport::new(0..=65535, 0..=0);
+    export::gif(&game, &viewport, 1, &ImageStyle::new().with_cell_size(1));
+}
+
+#[test]
+fn apng_declares_its_frames() {
+    let game = patterns::blinker().to_game();
+    let viewport = Viewport::new(-1..=1, 0..=2);
+    let apng = export::apng(&game, &viewport, 4, &ImageStyle::new().with_cell_size(2));
+    let chunks = png_chunks(&apng);
+
+    let kinds: Vec<&str> = chunks.iter().map(|(kind, _)| kind.as_str()).collect();
+    assert_eq!(
+        kinds,
+        [
+            "IHDR", "PLTE", "acTL", "fcTL", "IDAT", "fcTL", "fdAT", "fcTL", "fdAT", "fcTL", "fdAT",
+            "IEND"
+        ]
+    );
+    assert_eq!(&chunks[0].1[..8], &[0, 0, 0, 6, 0, 0, 0, 6]);
+    assert_eq!(&chunks[2].1[..4], &[0, 0, 0, 4]);
+
+    // The blinker has period 2, so alternate frames match.
+    assert_eq!(chunks[6].1[4..], chunks[10].1[4..]);
+    assert_ne!(chunks[4].1, chunks[6].1[4..]);
+    assert_eq!(chunks[4].1, chunks[8].1[4..]);
+}
+
+#[test]
+fn colors_convert_to_rgb() {
+    assert_eq!(Color::Fixed(196).rgb(), (255, 0, 0));
+    assert_eq!(Color::Fixed(244).rgb(), (128, 128, 128));
+    assert_eq!(Color::Fixed(9).rgb(), (255, 0, 0));
+    assert_eq!(Color::Rgb(1, 2, 3).rgb(), (1, 2, 3));
+}
+
+#[test]
+fn png_snapshots_draw_grid_lines() {
+    let game = patterns::block().to_game();
+    let viewport = Viewport::new(0..=1, 0..=1);
+    let style = ImageStyle::new().with_cell_size(2).with_grid(Color::Blue);
+    let chunks = png_chunks(&viewport.render_png_styled(&game, &style));
+
+    let kinds: Vec<&str> = chunks.iter().map(|(kind, _)| kind.as_str()).collect();
+    assert_eq!(kinds, ["IHDR", "PLTE", "IDAT", "IEND"]);
+    assert_eq!(&chunks[0].1[..8], &[0, 0, 0, 7, 0, 0, 0, 7]);
+    assert_eq!(&chunks[1].1[6..], &[0, 0, 238]);
+
+    // Stored deflate: a 2-byte zlib header and 5-byte block header precede the scanlines.
+    let scanlines = &chunks[2].1[7..7 + 7 * 8];
+    assert_eq!(scanlines[..8], [0, 2, 2, 2, 2, 2, 2, 2]);
+    assert_eq!(scanlines[8..16], [0, 2, 1, 1, 2, 1, 1, 2]);
+
+    let plain = png_chunks(&viewport.render_png(&game));
+    assert_eq!(&plain[0].1[..8], &[0, 0, 0, 8, 0, 0, 0, 8]);
+}
@@ -0,0 +1,51 @@
+#![cfg(feature = "ffi")]
+
+use gameoflife::ffi::*;
+
+#[test]
+fn boards_run_through_the_c_api() {
+    let game = gol_new();
+    unsafe {
+        for (x, y) in [(0, 1), (1, 1), (2, 1)] {
+            gol_set(game, x, y, true);
+        }
+        assert!(gol_get(game, 1, 1));
+        assert_eq!(gol_population(game), 3);
+
+        gol_tick(game, 1);
+        assert_eq!(gol_generation(game), 1);
+
+        let mut buffer = [9u8; 10];
+        assert_eq!(gol_cells_at(game, 0, 0, 3, 3, buffer.as_mut_ptr(), 9), 9);
+        assert_eq!(buffer, [0, 1, 0, 0, 1, 0, 0, 1, 0, 9]);
+        assert_eq!(gol_cells_at(game, 0, 0, 3, 3, buffer.as_mut_ptr(), 8), -1);
+        assert_eq!(gol_cells_at(game, 0, 0, -1, 3, buffer.as_mut_ptr(), 10), -1);
+
+        gol_set(game, 1, 1, false);
+        assert_eq!(gol_population(game), 2);
+
+        assert!(gol_set_rule(game, c"B36/S23".as_ptr()));
+        assert!(!gol_set_rule(game, c"B9".as_ptr()));
+        gol_free(game);
+        gol_free(std::ptr::null_mut());
+    }
+    assert!(gol_new_torus(0, 4).is_null());
+}
+
+#[test]
+fn header_declares_every_exported_function() {
+    let source = include_str!("../src/ffi.rs");
+    let header = include_str!("../include/gameoflife.h");
+    let exported: Vec<&str> = source
+        .split("extern \"C\" fn ")
+        .skip(1)
+        .map(|rest| &rest[..rest.find('(').unwrap()])
+        .collect();
+    assert_eq!(exported.len(), 10);
+    for name in exported {
+        assert!(
+            header.contains(&format!(" *{name}(")) || header.contains(&format!(" {name}(")),
+            "{name} is missing from include/gameoflife.h"
+        );
+    }
+}
@@ -1,4 +1,7 @@
-use gameoflife::GameOfLife;
+use gameoflife::{
+    BoundingBox, CellBuffer, DecodeError, GameOfLife, Rule, Statistics, Topology, Transform,
+    Viewport, patterns,
+};
 
 /*
  * HELPER UTILITIES
@@ -33,59 +36,36 @@ fn assert_period(mut game: GameOfLife, period: usize, name: &str) {
 }
 
 /*
- * SHAPES (sourced from https://en.wikipedia.org/wiki/Conway%27s_Game_of_Life#Examples_of_patterns)
+ * SHAPES (sourced from the crate's pattern library)
  */
 fn blinker() -> GameOfLife {
-    pattern_from_ascii(&[".#.", ".#.", ".#."])
+    let mut game = GameOfLife::new();
+    game.stamp(&patterns::blinker(), 1, 0, Transform::Identity);
+    game
 }
 
 fn square() -> GameOfLife {
-    pattern_from_ascii(&["##", "##"])
+    patterns::block().to_game()
 }
 
 fn tub() -> GameOfLife {
-    pattern_from_ascii(&[".#.", "#.#", ".#."])
+    patterns::tub().to_game()
 }
 
 fn toad() -> GameOfLife {
-    pattern_from_ascii(&[".###", "###."])
+    patterns::toad().to_game()
 }
 
 fn beacon() -> GameOfLife {
-    pattern_from_ascii(&["##..", "##..", "..##", "..##"])
+    patterns::beacon().to_game()
 }
 
 fn pulsar() -> GameOfLife {
-    pattern_from_ascii(&[
-        "..###...###..",
-        ".............",
-        "#....#.#....#",
-        "#....#.#....#",
-        "#....#.#....#",
-        "..###...###..",
-        ".............",
-        "..###...###..",
-        "#....#.#....#",
-        "#....#.#....#",
-        "#....#.#....#",
-        ".............",
-        "..###...###..",
-    ])
+    patterns::pulsar().to_game()
 }
 
 fn pentadecathlon() -> GameOfLife {
-    pattern_from_ascii(&[
-        "..#..",
-        ".#.#.",
-        "#...#",
-        "#...#",
-        "#...#",
-        "#...#",
-        "#...#",
-        "#...#",
-        ".#.#.",
-        "..#..",
-    ])
+    patterns::pentadecathlon().to_game()
 }
 
 /*
@@ -167,3 +147,503 @@ fn oscillators_have_known_periods() {
         assert_period(oscillator, period, name);
     }
 }
+
+#[test]
+fn rule_regions_apply_their_own_rule() {
+    let seeds = Rule::new(&[2], &[]);
+    let mut game = GameOfLife::new();
+    game.add_rule_region(10..=19, 0..=9, seeds);
+    for (x, y) in [
+        (0, 0),
+        (1, 0),
+        (0, 1),
+        (1, 1),
+        (12, 2),
+        (13, 2),
+        (12, 3),
+        (13, 3),
+    ] {
+        game.set(x, y);
+    }
+
+    game.tick();
+
+    // The Conway block is a still life, while every cell of the Seeds block dies.
+    for (x, y) in [(0, 0), (1, 0), (0, 1), (1, 1)] {
+        assert!(game.get(x, y));
+    }
+    for (x, y) in [(12, 2), (13, 2), (12, 3), (13, 3)] {
+        assert!(!game.get(x, y));
+    }
+    // Seeds births happen next to the region's block.
+    assert!(game.get(12, 1));
+    assert!(game.get(14, 3));
+}
+
+#[test]
+fn state_hash_ignores_insertion_order_and_history() {
+    let mut a = GameOfLife::new();
+    a.set(0, 0);
+    a.set(5, -3);
+    a.set(2, 7);
+
+    let mut b = GameOfLife::new();
+    b.set(2, 7);
+    b.set(5, -3);
+    b.set(0, 0);
+    assert_eq!(a.state_hash(), b.state_hash());
+
+    let mut blinker_a = blinker();
+    let blinker_b = blinker();
+    blinker_a.tick();
+    blinker_a.tick();
+    assert_eq!(blinker_a.state_hash(), blinker_b.state_hash());
+
+    blinker_a.tick();
+    assert_ne!(blinker_a.state_hash(), blinker_b.state_hash());
+}
+
+#[test]
+fn fingerprints_are_stable_and_canonical_ones_ignore_position() {
+    let glider = patterns::glider().to_game();
+    assert_eq!(
+        glider.fingerprint(),
+        0x7bb3_6c1f_62c1_0df0,
+        "fingerprints must not change between versions"
+    );
+    assert_eq!(GameOfLife::new().fingerprint(), 0);
+
+    let mut moved = GameOfLife::new();
+    moved.stamp(&patterns::glider(), -40, 17, Transform::Identity);
+    assert_ne!(moved.fingerprint(), glider.fingerprint());
+    assert_eq!(
+        moved.canonical_fingerprint(),
+        glider.canonical_fingerprint()
+    );
+
+    // A glider repeats its shape, one cell over, every four generations.
+    let mut flown = glider.clone();
+    flown.advance(4);
+    assert_eq!(
+        flown.canonical_fingerprint(),
+        glider.canonical_fingerprint()
+    );
+    flown.tick();
+    assert_ne!(
+        flown.canonical_fingerprint(),
+        glider.canonical_fingerprint()
+    );
+}
+
+#[test]
+fn canonical_phase_matches_across_blinker_phases() {
+    let vertical = blinker();
+    let mut horizontal = blinker();
+    horizontal.tick();
+    assert_ne!(vertical, horizontal);
+
+    assert_eq!(vertical.canonical_phase(2), horizontal.canonical_phase(2));
+    assert_eq!(
+        vertical.canonical_phase(2),
+        pattern_from_ascii(&["#", "#", "#"]),
+        "the vertical phase sorts first once translated to the origin"
+    );
+}
+
+#[test]
+fn next_state_predicts_tick() {
+    let mut game = blinker();
+    assert_eq!(game.live_neighbors(1, 1), 2);
+    assert_eq!(game.live_neighbors(0, 1), 3);
+
+    let predicted: Vec<bool> = (-2..=4)
+        .flat_map(|y| (-2..=4).map(move |x| (x, y)))
+        .map(|(x, y)| game.next_state(x, y))
+        .collect();
+    game.tick();
+    let actual: Vec<bool> = (-2..=4)
+        .flat_map(|y| (-2..=4).map(move |x| (x, y)))
+        .map(|(x, y)| game.get(x, y))
+        .collect();
+
+    assert_eq!(predicted, actual);
+}
+
+#[test]
+fn generation_counts_ticks_and_can_be_overridden() {
+    let mut game = blinker();
+    assert_eq!(game.generation(), 0);
+    game.tick();
+    game.tick();
+    assert_eq!(game.generation(), 2);
+    game.undo();
+    assert_eq!(game.generation(), 1);
+
+    game.set_generation(1_000);
+    assert_eq!(game.generation(), 1_000);
+    game.tick();
+    assert_eq!(game.generation(), 1_001);
+}
+
+#[test]
+fn tick_candidates_cover_live_cells_and_neighbors() {
+    let mut game = GameOfLife::new();
+    game.set(4, -2);
+
+    let candidates = game.tick_candidates();
+    assert_eq!(candidates.len(), 9);
+    assert!(candidates.contains(&(4, -2)));
+    assert!(candidates.contains(&(3, -3)));
+    assert!(candidates.contains(&(5, -1)));
+}
+
+#[test]
+fn url_hash_round_trips_patterns() {
+    let mut game = pulsar();
+    game.set(-70_000, 12);
+    game.set(i32::MAX, i32::MIN);
+
+    let hash = game.to_url_hash();
+    assert!(
+        hash.chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == '-' || ch == '_')
+    );
+    assert_eq!(GameOfLife::from_url_hash(&hash), Ok(game.clone()));
+    assert_eq!(GameOfLife::from_url_hash(&format!("#{hash}")), Ok(game));
+    assert_eq!(GameOfLife::from_url_hash(""), Ok(GameOfLife::new()));
+    assert_eq!(
+        GameOfLife::from_url_hash("#not valid!"),
+        Err(DecodeError::InvalidCharacter(' '))
+    );
+}
+
+#[test]
+fn viewport_cells_follow_display_order() {
+    let game = blinker();
+    let viewport = Viewport::new(0..=2, 0..=1);
+    let render = viewport.render(&game);
+
+    let cells: Vec<(i32, i32, bool)> = render.cells().collect();
+    assert_eq!(
+        cells,
+        vec![
+            (0, 1, false),
+            (1, 1, true),
+            (2, 1, false),
+            (0, 0, false),
+            (1, 0, true),
+            (2, 0, false),
+        ]
+    );
+    assert_eq!((&render).into_iter().count(), 6);
+    assert_eq!(render.to_string(), "◻◼◻\n◻◼◻\n");
+}
+
+#[test]
+fn still_lifes_tick_cheaply_until_edited() {
+    let mut game = square();
+    game.tick();
+    assert_eq!(game.history_len(), 1);
+
+    // Settled ticks skip the step entirely: no snapshots, no flips, and derived caches survive.
+    game.set_cells_cache(true);
+    game.cells_at(4, 4, 0, 0);
+    for _ in 1..1_000_000 {
+        game.tick();
+    }
+    assert_eq!(game.history_len(), 1);
+    assert!(game.has_cached_cells());
+    let stats = game.stats();
+    assert_eq!((stats.births, stats.deaths), (0, 0));
+    assert_eq!(game, square());
+    assert_eq!(game.generation(), 1_000_000);
+
+    // Editing the board must resume real simulation.
+    game.set(2, 1);
+    game.tick();
+    assert_ne!(game, square());
+}
+
+#[test]
+fn cells_cache_is_reused_until_mutation() {
+    let mut game = blinker();
+    game.set_cells_cache(true);
+    assert!(!game.has_cached_cells());
+
+    let first = game.cells_at(3, 3, 0, 0);
+    assert!(game.has_cached_cells());
+    assert_eq!(game.cells_at(3, 3, 0, 0), first);
+    assert_eq!(first, blinker().cells_at(3, 3, 0, 0));
+
+    game.set(0, 0);
+    assert!(!game.has_cached_cells());
+    let edited = game.cells_at(3, 3, 0, 0);
+    assert_ne!(edited, first);
+    assert_eq!(edited[0], 1);
+
+    game.tick();
+    assert!(!game.has_cached_cells());
+}
+
+#[test]
+fn cell_buffers_match_cells_at_and_keep_their_allocation() {
+    let mut game = blinker();
+    let mut buffer = CellBuffer::new();
+    assert!(buffer.is_empty());
+
+    game.fill_cells_at(&mut buffer, 4, 3, -1, 0);
+    assert_eq!(buffer.as_slice(), game.cells_at(4, 3, -1, 0).as_slice());
+    assert_eq!((buffer.width(), buffer.height(), buffer.len()), (4, 3, 12));
+    let ptr = buffer.ptr();
+
+    game.tick();
+    game.fill_cells_at(&mut buffer, 3, 4, 0, -1);
+    assert_eq!(buffer.as_slice(), game.cells_at(3, 4, 0, -1).as_slice());
+    assert_eq!(buffer.ptr(), ptr);
+}
+
+#[test]
+fn ages_count_consecutive_survivals() {
+    let mut game = GameOfLife::new();
+    game.stamp(&patterns::block(), 0, 0, Transform::Identity);
+    game.set(10, 0);
+    assert_eq!(game.age(0, 0), Some(0));
+    assert_eq!(game.age(5, 5), None);
+
+    game.tick();
+    assert_eq!(game.age(0, 0), Some(1));
+    assert_eq!(game.age(10, 0), None);
+
+    // Once the board is a still life, skipped generations still age every cell.
+    game.advance(10);
+    assert_eq!(game.age(1, 1), Some(11));
+
+    game.undo();
+    assert_eq!(game.age(1, 1), Some(1));
+    game.unset(1, 1);
+    game.set(1, 1);
+    assert_eq!(game.age(1, 1), Some(0));
+}
+
+#[test]
+fn oscillator_cells_reset_their_age() {
+    let mut game = blinker();
+    game.advance(3);
+    // The blinker's center survives every phase; its ends are reborn each generation.
+    let (cx, cy) = game
+        .iter_live()
+        .fold((0, 0), |(x, y), (cx, cy)| (x + cx, y + cy));
+    let center = (cx / 3, cy / 3);
+    for (x, y) in game.iter_live().collect::<Vec<_>>() {
+        let expected = if (x, y) == center { 3 } else { 0 };
+        assert_eq!(game.age(x, y), Some(expected));
+    }
+}
+
+#[test]
+fn ages_at_matches_cells_at_layout() {
+    let mut game = blinker();
+    game.tick();
+    game.set(0, 0);
+    let ages = game.ages_at(3, 3, 0, 0);
+    let cells = game.cells_at(3, 3, 0, 0);
+    for (age, cell) in ages.iter().zip(&cells) {
+        assert_eq!(*age > 0, *cell == 1);
+    }
+    assert_eq!(ages[0], 1);
+    assert_eq!(*ages.iter().max().unwrap(), 2);
+}
+
+#[test]
+fn edits_are_undoable_and_redoable() {
+    let mut game = GameOfLife::new();
+    game.set(0, 0);
+    game.set(0, 0);
+    game.toggle(1, 0);
+    assert_eq!(game.history_len(), 2, "no-op edits are not recorded");
+
+    game.tick();
+    assert!(game.undo());
+    assert_eq!(game.generation(), 0);
+    assert!(game.get(0, 0) && game.get(1, 0));
+    assert!(game.undo());
+    assert!(!game.get(1, 0));
+
+    assert!(game.can_redo());
+    assert!(game.redo());
+    assert!(game.get(1, 0));
+    assert!(game.redo());
+    assert_eq!(game.generation(), 1);
+    assert!(!game.redo());
+
+    game.undo();
+    game.clear();
+    assert!(!game.can_redo(), "new edits discard redo states");
+    assert!(game.undo());
+    assert!(game.get(0, 0));
+}
+
+#[test]
+fn history_limit_is_configurable() {
+    let mut game = blinker();
+    assert_eq!(game.history_limit(), 255);
+    for _ in 0..10 {
+        game.tick();
+    }
+    game.set_history_limit(4);
+    assert_eq!(game.history_len(), 4);
+
+    for _ in 0..4 {
+        assert!(game.undo());
+    }
+    assert!(!game.undo());
+    assert_eq!(game.generation(), 6);
+
+    game.set_history_limit(0);
+    game.tick();
+    assert!(!game.can_undo());
+}
+
+#[test]
+fn stats_track_population_and_changes() {
+    let mut game = GameOfLife::new();
+    for x in 0..4 {
+        game.set(x, 0);
+    }
+    assert_eq!(
+        game.stats(),
+        Statistics {
+            generation: 0,
+            population: 4,
+            births: 0,
+            deaths: 0,
+            culled: 0,
+            min_population: 4,
+            max_population: 4,
+        }
+    );
+
+    // A row of four becomes a 2x3 block of six cells, then a beehive.
+    game.tick();
+    let stats = game.stats();
+    assert_eq!(stats.generation, 1);
+    assert_eq!(stats.population, 6);
+    assert_eq!((stats.births, stats.deaths), (4, 2));
+
+    game.tick();
+    let stats = game.stats();
+    assert_eq!(stats.population, 6);
+    assert_eq!((stats.min_population, stats.max_population), (4, 6));
+}
+
+#[test]
+fn live_cells_and_bounding_box() {
+    let mut game = GameOfLife::new();
+    assert_eq!(game.population(), 0);
+    assert_eq!(game.bounding_box(), None);
+
+    game.set_cells([(-3, 4), (2, -1), (0, 0)]);
+    let mut live: Vec<(i32, i32)> = game.iter_live().collect();
+    live.sort_unstable();
+    assert_eq!(live, vec![(-3, 4), (0, 0), (2, -1)]);
+    assert_eq!(game.population(), 3);
+
+    let bounds = game.bounding_box().unwrap();
+    assert_eq!(
+        bounds,
+        BoundingBox {
+            min_x: -3,
+            min_y: -1,
+            max_x: 2,
+            max_y: 4,
+        }
+    );
+    assert_eq!((bounds.width(), bounds.height()), (6, 6));
+    assert!(bounds.contains(-3, -1) && !bounds.contains(3, 0));
+}
+
+#[test]
+fn large_soups_tick_deterministically() {
+    // Large enough to cross the parallel evaluation threshold when the `rayon` feature is enabled.
+    let mut seed = 0x2545_f491_u32;
+    let mut game = GameOfLife::new();
+    for y in 0..128 {
+        for x in 0..128 {
+            seed ^= seed << 13;
+            seed ^= seed >> 17;
+            seed ^= seed << 5;
+            if seed.is_multiple_of(3) {
+                game.set(x, y);
+            }
+        }
+    }
+
+    let mut reference = game.clone();
+    game.advance(8);
+    for _ in 0..8 {
+        let next: Vec<(i32, i32)> = reference
+            .tick_candidates()
+            .into_iter()
+            .filter(|&(x, y)| reference.next_state(x, y))
+            .collect();
+        reference.clear();
+        reference.set_cells(next);
+    }
+    assert_eq!(game, reference);
+}
+
+#[test]
+fn incremental_ticks_match_full_recomputation() {
+    let boards = [
+        GameOfLife::random_soup(32, 32, 0.4, 7),
+        {
+            let mut game = GameOfLife::random_soup(32, 32, 0.3, 8);
+            game.set_rule("B2/S/C3".parse().unwrap());
+            game
+        },
+        {
+            let mut game = GameOfLife::with_topology(Topology::Torus {
+                width: 24,
+                height: 24,
+            });
+            game.set_cells(patterns::soup(24, 24, 0.4, 9).cells().iter().copied());
+            game
+        },
+    ];
+    for mut game in boards {
+        let mut reference = game.clone();
+        for generation in 0..80 {
+            if generation == 40 {
+                // Edits must widen the next tick back to the whole board.
+                game.toggle(3, 3);
+                reference.toggle(3, 3);
+            }
+            game.tick();
+            // Resetting the rule discards what the last tick changed, forcing a full evaluation.
+            reference.set_rule(reference.rule());
+            reference.tick();
+            assert_eq!(game, reference, "generation {generation}");
+        }
+        assert_eq!(game.stats(), reference.stats());
+    }
+}
+
+#[test]
+fn diffs_list_sorted_births_and_deaths() {
+    let mut game = GameOfLife::new();
+    game.set_cells([(0, 1), (1, 1), (2, 1)]);
+    let before = game.clone();
+
+    let diff = game.tick_diff();
+    assert_eq!(diff.births(), &[(1, 0), (1, 2)]);
+    assert_eq!(diff.deaths(), &[(0, 1), (2, 1)]);
+    assert_eq!(diff.len(), 4);
+    assert_eq!(before.diff(&game), diff);
+    assert_eq!(game.generation(), 1);
+
+    game.clear();
+    game.set_cells([(0, 0), (0, 1), (1, 0), (1, 1)]);
+    game.tick();
+    assert!(game.tick_diff().is_empty());
+    assert_eq!(game.generation(), 3);
+}
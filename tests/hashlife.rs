@@ -0,0 +1,93 @@
+use gameoflife::{Engine, GameOfLife, HashLife, Rule, Transform, patterns};
+
+fn assert_engines_agree(game: GameOfLife, generations: u64) {
+    let mut reference = game.clone();
+    let mut hashlife = HashLife::from_game(&game);
+    reference.advance(generations);
+    hashlife.advance(generations);
+
+    assert_eq!(hashlife.generation(), reference.generation());
+    assert_eq!(hashlife.population(), reference.population());
+    assert_eq!(
+        Engine::live_cells(&hashlife),
+        Engine::live_cells(&reference)
+    );
+}
+
+#[test]
+fn matches_sparse_engine_on_small_steps() {
+    for generations in [1, 2, 3, 7, 16] {
+        assert_engines_agree(patterns::r_pentomino().to_game(), generations);
+    }
+}
+
+#[test]
+fn matches_sparse_engine_on_methuselahs_and_guns() {
+    assert_engines_agree(patterns::acorn().to_game(), 300);
+    assert_engines_agree(patterns::gosper_glider_gun().to_game(), 240);
+}
+
+#[test]
+fn supports_other_life_like_rules() {
+    let mut game = GameOfLife::with_rule(Rule::highlife());
+    game.stamp(&patterns::r_pentomino(), -4, 7, Transform::Rotate90);
+    assert_engines_agree(game, 100);
+}
+
+#[test]
+fn power_of_two_jumps_reach_huge_generations() {
+    let mut hashlife = HashLife::from_game(&patterns::gosper_glider_gun().to_game());
+    hashlife.advance_power_of_two(20);
+    assert_eq!(hashlife.generation(), 1 << 20);
+    // The gun emits one five-cell glider every 30 generations, and none are destroyed.
+    let gliders = (1u64 << 20) / 30;
+    let population = hashlife.population() as u64;
+    assert!((36 + 5 * gliders..=48 + 5 * (gliders + 1)).contains(&population));
+}
+
+#[test]
+fn jumps_past_two_to_the_sixty_second_do_not_overflow() {
+    let mut game = patterns::glider().to_game();
+    game.stamp(&patterns::block(), -20, -20, Transform::Identity);
+    let mut hashlife = HashLife::from_game(&game);
+    hashlife.advance_power_of_two(62);
+    assert_eq!(hashlife.generation(), 1 << 62);
+    // The glider has crashed into the corner of the plane and settled there as a block.
+    let corner = i32::MAX - 1;
+    assert_eq!(
+        hashlife.live_cells(),
+        [
+            (-20, -20),
+            (-20, -19),
+            (-19, -20),
+            (-19, -19),
+            (corner, corner),
+            (corner, i32::MAX),
+            (i32::MAX, corner),
+            (i32::MAX, i32::MAX),
+        ]
+    );
+
+    hashlife.advance(u64::MAX);
+    assert_eq!(hashlife.generation(), u64::MAX);
+    assert_eq!(hashlife.population(), 8);
+    assert!(hashlife.get(-19, -19));
+}
+
+#[test]
+fn converts_to_and_from_game() {
+    let mut game = patterns::pulsar().to_game();
+    game.set(-1_000, 2_000);
+    game.advance(5);
+
+    let mut hashlife = HashLife::from_game(&game);
+    assert_eq!(hashlife.get(-1_000, 2_000), game.get(-1_000, 2_000));
+    hashlife.unset(-1_000, 2_000);
+    hashlife.set(3, -3);
+    assert!(hashlife.get(3, -3));
+
+    let round_trip = hashlife.to_game();
+    assert_eq!(round_trip.generation(), 5);
+    assert!(round_trip.get(3, -3));
+    assert!(!round_trip.get(-1_000, 2_000));
+}
@@ -0,0 +1,87 @@
+use gameoflife::{ColorMode, CoordLimit, GameOfLife, Rule, Transform, patterns};
+
+fn glider_run(generations: u64) -> GameOfLife {
+    let mut game = GameOfLife::new();
+    game.stamp(&patterns::glider(), 0, 0, Transform::Identity);
+    for _ in 0..generations {
+        game.tick();
+    }
+    game
+}
+
+#[test]
+fn history_lists_previous_states_most_recent_first() {
+    let game = glider_run(3);
+    let generations: Vec<u64> = game.history().map(|entry| entry.generation()).collect();
+    // The stamp's snapshot holds the empty board from before it.
+    assert_eq!(generations, vec![2, 1, 0, 0]);
+    assert_eq!(game.history().last().unwrap().population(), 0);
+
+    let mut replayed = glider_run(0);
+    let oldest_tick = game.history().nth(2).unwrap();
+    assert_eq!(oldest_tick.to_game(), replayed);
+    replayed.tick();
+    assert_eq!(game.history().nth(1).unwrap().to_game(), replayed);
+    assert_eq!(game.history_len(), 4);
+}
+
+#[test]
+fn rewind_pairs_with_fast_forward() {
+    let mut game = glider_run(5);
+    let end = game.clone();
+
+    assert_eq!(game.rewind(3), 3);
+    assert_eq!(game.generation(), 2);
+    assert_eq!(game, glider_run(2));
+    assert_eq!(game.redo_len(), 3);
+
+    assert_eq!(game.fast_forward(2), 2);
+    assert_eq!(game.generation(), 4);
+    assert_eq!(game.fast_forward(10), 1);
+    assert_eq!(game, end);
+    assert_eq!(game.generation(), 5);
+
+    assert_eq!(game.rewind(100), 6);
+    assert_eq!(game.population(), 0);
+    assert!(!game.can_undo());
+}
+
+#[test]
+fn history_entries_answer_cell_queries() {
+    let mut game = GameOfLife::new();
+    game.set(4, 4);
+    game.set(9, 9);
+    let entry = game.history().next().unwrap();
+    assert!(entry.get(4, 4));
+    assert!(!entry.get(9, 9));
+    assert_eq!(entry.iter_live().collect::<Vec<_>>(), vec![(4, 4)]);
+}
+
+#[test]
+fn history_entries_rebuild_the_state_undo_restores() {
+    let mut game = patterns::glider().to_game();
+    game.set_rule(Rule::brians_brain());
+    game.set_coord_limit(Some(CoordLimit::new(50)));
+    game.tick();
+    game.tick();
+    let entry = game.history().next().unwrap().to_game();
+    game.undo();
+    assert_eq!(entry, game);
+    let decaying = (-5..5)
+        .flat_map(|x| (-5..5).map(move |y| (x, y)))
+        .filter(|&(x, y)| entry.cell_state(x, y) == 2)
+        .count();
+    assert_eq!(decaying, 5, "the glider's cells all decay after one Brian's Brain tick");
+    assert_eq!(entry.rule(), Rule::brians_brain());
+    assert_eq!(entry.coord_limit(), Some(CoordLimit::new(50)));
+
+    let mut colored = GameOfLife::new();
+    colored.set_color_mode(ColorMode::QuadLife);
+    colored.set_colored(0, 0, 3);
+    colored.set_colored(1, 0, 2);
+    colored.set_colored(5, 5, 1);
+    let entry = colored.history().next().unwrap().to_game();
+    assert_eq!(entry.color_mode(), ColorMode::QuadLife);
+    assert_eq!((entry.color(0, 0), entry.color(1, 0)), (Some(3), Some(2)));
+    assert_eq!(entry.color(5, 5), None);
+}
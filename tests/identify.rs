@@ -0,0 +1,126 @@
+use gameoflife::catalog::Kind;
+use gameoflife::identify::{self, Census};
+use gameoflife::{GameOfLife, Rule, Transform, patterns};
+
+#[test]
+fn apgcodes_match_the_published_codes() {
+    let code = |pattern| identify::apgcode(&pattern, Rule::conway()).unwrap();
+    assert_eq!(code(patterns::block()), "xs4_33");
+    assert_eq!(code(patterns::beehive()), "xs6_696");
+    assert_eq!(code(patterns::loaf()), "xs7_2596");
+    assert_eq!(code(patterns::boat()), "xs5_253");
+    assert_eq!(code(patterns::tub()), "xs4_252");
+    assert_eq!(code(patterns::blinker()), "xp2_7");
+    assert_eq!(code(patterns::toad()), "xp2_7e");
+    assert_eq!(code(patterns::beacon()), "xp2_318c");
+    assert_eq!(code(patterns::pentadecathlon()), "xp15_4r4z4r4");
+    assert_eq!(code(patterns::glider()), "xq4_153");
+    assert_eq!(code(patterns::lwss()), "xq4_6frc");
+    assert_eq!(
+        code(patterns::glider().transformed(Transform::Rotate90)),
+        "xq4_153"
+    );
+}
+
+#[test]
+fn census_counts_separate_objects() {
+    let mut game = GameOfLife::new();
+    game.stamp(&patterns::block(), 0, 0, Transform::Identity);
+    game.stamp(&patterns::block(), 20, 0, Transform::Identity);
+    game.stamp(&patterns::blinker(), 0, 20, Transform::Identity);
+    game.stamp(&patterns::glider(), 20, 20, Transform::Identity);
+
+    let census = identify::census(&game);
+    assert_eq!(census.to_string(), "2 blocks, 1 blinker, 1 glider");
+    assert_eq!(census.count("xs4_33"), 2);
+    assert_eq!(census.count("Glider"), 1);
+    assert_eq!(census.count("xs6_696"), 0);
+    assert_eq!(census.total(), 4);
+    assert_eq!(
+        census.iter().collect::<Vec<_>>(),
+        [("xs4_33", 2), ("xp2_7", 1), ("xq4_153", 1)]
+    );
+
+    let objects = identify::objects(&game);
+    let glider = objects
+        .iter()
+        .find(|object| object.code() == "xq4_153")
+        .unwrap();
+    assert_eq!(glider.kind(), Kind::Spaceship);
+    assert_eq!(glider.period(), Some(4));
+    assert_eq!(glider.displacement(), (1, 1));
+    assert_eq!(glider.label(), "Glider");
+    assert_eq!(glider.pattern().bounding_box().unwrap().min_x, 20);
+}
+
+#[test]
+fn close_objects_split_only_when_independent() {
+    // Two blocks two cells apart never interact, but the beacon's halves do.
+    let mut bi_block = GameOfLife::new();
+    bi_block.stamp(&patterns::block(), 0, 0, Transform::Identity);
+    bi_block.stamp(&patterns::block(), 3, 0, Transform::Identity);
+    assert_eq!(identify::census(&bi_block).count("Block"), 2);
+
+    let mut beacon = patterns::beacon().to_game();
+    beacon.tick();
+    let objects = identify::objects(&beacon);
+    assert_eq!(objects.len(), 1);
+    assert_eq!(objects[0].kind(), Kind::Oscillator);
+    assert_eq!(objects[0].label(), "Beacon");
+}
+
+#[test]
+fn unnamed_and_unsettled_objects_use_codes() {
+    let mut game = patterns::r_pentomino().to_game();
+    let pond = [
+        (1, 0),
+        (2, 0),
+        (0, 1),
+        (3, 1),
+        (0, 2),
+        (3, 2),
+        (1, 3),
+        (2, 3),
+    ];
+    game.set_cells(pond.map(|(x, y)| (x + 50, y)));
+
+    let census = identify::census(&game);
+    assert_eq!(census.to_string(), "1 PATHOLOGICAL, 1 xs8_6996");
+    let objects = identify::objects(&game);
+    assert_eq!(objects[0].kind(), Kind::Other);
+    assert_eq!(objects[0].period(), None);
+
+    // Names are only given under Conway's rule.
+    let mut game = GameOfLife::with_rule("B36/S23".parse().unwrap());
+    game.stamp(&patterns::block(), 0, 0, Transform::Identity);
+    let census = identify::census(&game);
+    assert_eq!(census.to_string(), "1 xs4_33");
+    assert_eq!(Census::new(&[]), Census::default());
+    assert!(Census::default().is_empty());
+}
+
+#[test]
+fn spaceships_report_their_velocity() {
+    let mut game = GameOfLife::new();
+    game.stamp(&patterns::glider(), 0, 0, Transform::Identity);
+    game.stamp(&patterns::lwss(), 30, 0, Transform::Identity);
+    game.stamp(&patterns::glider(), 0, 30, Transform::Rotate180);
+    game.stamp(&patterns::block(), 30, 30, Transform::Identity);
+
+    let spaceships = identify::spaceships(&game);
+    let speeds: Vec<String> = spaceships
+        .iter()
+        .map(|ship| ship.speed().unwrap().to_string())
+        .collect();
+    assert_eq!(speeds, ["c/4 diagonal", "c/4 diagonal", "c/2 orthogonal"]);
+    let displacements: Vec<(i32, i32)> =
+        spaceships.iter().map(|ship| ship.displacement()).collect();
+    assert_eq!(displacements, [(1, 1), (-1, -1), (-2, 0)]);
+
+    // Gliders leaving a gun are tracked once they are clear of it.
+    let mut gun = patterns::gosper_glider_gun().to_game();
+    gun.advance(120);
+    let gliders = identify::spaceships(&gun);
+    assert!(gliders.len() >= 2);
+    assert!(gliders.iter().all(|glider| glider.label() == "Glider"));
+}
@@ -0,0 +1,55 @@
+use gameoflife::{GameOfLife, Layers, RenderStyle, Viewport};
+
+fn scratch_stack() -> Layers {
+    let mut layers = Layers::new();
+    let mut main = GameOfLife::new();
+    main.set_cells([(0, 0), (1, 0), (2, 0)]);
+    layers.push("main", main, RenderStyle::ascii());
+    let mut scratch = GameOfLife::new();
+    scratch.set_cells([(4, 0), (5, 0), (4, 1), (5, 1)]);
+    layers.push("scratch", scratch, RenderStyle::ascii().with_live('@'));
+    layers
+}
+
+#[test]
+fn layers_tick_independently_and_pause() {
+    let mut layers = scratch_stack();
+    let scratch = layers.position("scratch").unwrap();
+    layers.get_mut(scratch).unwrap().set_paused(true);
+    layers.advance(3);
+    assert_eq!(layers.get(0).unwrap().game().generation(), 3);
+    assert_eq!(layers.get(scratch).unwrap().game().generation(), 0);
+    assert_eq!(layers.len(), 2);
+}
+
+#[test]
+fn layers_composite_top_to_bottom() {
+    let mut layers = scratch_stack();
+    layers.get_mut(1).unwrap().game_mut().set(1, 0);
+    let viewport = Viewport::new(0..=5, 0..=1);
+    assert_eq!(layers.render(&viewport).to_string(), "....@@\n#@#.@@\n");
+    assert_eq!(layers.cells_at(6, 1, 0, 0), [1, 2, 1, 0, 2, 2]);
+
+    layers.get_mut(1).unwrap().set_visible(false);
+    assert_eq!(layers.render(&viewport).to_string(), "......\n###...\n");
+    assert_eq!(layers.cells_at(6, 1, 0, 0), [1, 1, 1, 0, 0, 0]);
+    assert_eq!(Layers::new().render(&viewport).to_string(), "");
+}
+
+#[test]
+fn scratch_layers_commit_into_the_main_board() {
+    let mut layers = scratch_stack();
+    assert_eq!(layers.flatten().population(), 7);
+    assert!(!layers.merge(0, 0));
+    assert!(!layers.merge(0, 5));
+
+    assert!(layers.commit(1, 0));
+    assert_eq!(layers.get(0).unwrap().game().population(), 7);
+    assert_eq!(layers.get(1).unwrap().game().population(), 0);
+
+    let main = layers.get_mut(0).unwrap().game_mut();
+    assert!(main.undo());
+    assert_eq!(main.population(), 3);
+    assert_eq!(layers.remove(1).unwrap().name(), "scratch");
+    assert!(layers.remove(1).is_none());
+}
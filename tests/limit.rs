@@ -0,0 +1,141 @@
+use std::sync::mpsc;
+
+use gameoflife::{
+    Backend, CoordLimit, Engine, GameOfLife, SimulationEvent, Topology, Transform, patterns,
+};
+
+#[test]
+fn patterns_at_the_edge_of_the_plane_do_not_overflow() {
+    let mut game = GameOfLife::new();
+    game.stamp(
+        &patterns::glider(),
+        i32::MAX - 4,
+        i32::MAX - 4,
+        Transform::Identity,
+    );
+    game.stamp(
+        &patterns::glider(),
+        i32::MIN,
+        i32::MIN,
+        Transform::Rotate180,
+    );
+    for _ in 0..40 {
+        game.tick();
+    }
+    // The gliders crash into the edge of the plane and settle there.
+    assert!(game.population() > 0);
+    assert!(game.iter_live().any(|(x, _)| x == i32::MAX));
+}
+
+#[test]
+fn cells_beyond_the_limit_are_culled_and_reported() {
+    let mut game = GameOfLife::new();
+    game.set_coord_limit(Some(CoordLimit::new(20)));
+    game.stamp(&patterns::glider(), 10, 10, Transform::Identity);
+    let (sender, receiver) = mpsc::channel();
+    game.add_observer(sender);
+
+    let mut reference = game.clone();
+    let mut culled = 0;
+    for _ in 0..60 {
+        game.tick();
+        culled += game.stats().culled;
+        // Resetting the rule forces the reference to evaluate the whole board every tick.
+        reference.set_rule(reference.rule());
+        reference.tick();
+        assert_eq!(game, reference);
+    }
+    assert!(culled > 0);
+    assert!(game.iter_live().all(|(x, y)| x <= 20 && y <= 20));
+    let events = receiver
+        .try_iter()
+        .filter(|event| matches!(event, SimulationEvent::CellCulled(..)))
+        .count();
+    assert_eq!(events, culled);
+}
+
+#[test]
+fn setting_a_limit_drops_cells_outside_it() {
+    let mut game = GameOfLife::new();
+    game.set_cells([(0, 0), (5, -5), (6, 0), (0, -7)]);
+    game.set_coord_limit(Some(CoordLimit::new(5)));
+    assert_eq!(game.coord_limit().map(|limit| limit.extent()), Some(5));
+    let mut cells: Vec<_> = game.iter_live().collect();
+    cells.sort_unstable();
+    assert_eq!(cells, [(0, 0), (5, -5)]);
+
+    assert!(CoordLimit::new(5).contains(-5, 5));
+    assert!(CoordLimit::new(i32::MAX as u32).contains(i32::MAX, -i32::MAX));
+    assert!(!CoordLimit::new(i32::MAX as u32).contains(i32::MIN, 0));
+}
+
+/// Runs `game` on every backend that accepts it, checking each generation against the sparse one.
+fn assert_backends_agree(game: &GameOfLife, generations: u64) {
+    let mut reference = game.clone();
+    let mut engines: Vec<_> = [Backend::Dense, Backend::HashLife, Backend::Chunked]
+        .into_iter()
+        .filter_map(|backend| backend.build(game))
+        .collect();
+    assert!(!engines.is_empty());
+    for _ in 0..generations {
+        reference.tick();
+        for engine in &mut engines {
+            engine.advance(1);
+            assert_eq!(engine.live_cells(), Engine::live_cells(&reference));
+        }
+    }
+}
+
+#[test]
+fn every_backend_stops_at_the_edge_of_the_plane() {
+    let mut game = GameOfLife::new();
+    game.stamp(
+        &patterns::glider(),
+        i32::MAX - 70,
+        i32::MAX - 70,
+        Transform::Identity,
+    );
+    game.stamp(
+        &patterns::glider(),
+        i32::MIN + 30,
+        i32::MIN + 30,
+        Transform::Rotate180,
+    );
+    assert_backends_agree(&game, 400);
+
+    // A single long jump honors the edge as well as single generations do.
+    let mut hashlife = Backend::HashLife.build(&game).unwrap();
+    hashlife.advance(400);
+    game.advance(400);
+    assert_eq!(hashlife.live_cells(), Engine::live_cells(&game));
+}
+
+#[test]
+fn every_backend_culls_cells_beyond_the_limit() {
+    let mut game = GameOfLife::new();
+    game.set_coord_limit(Some(CoordLimit::new(40)));
+    game.stamp(&patterns::glider(), 10, 10, Transform::Identity);
+    game.stamp(&patterns::glider(), -10, -10, Transform::Rotate180);
+    assert_backends_agree(&game, 200);
+    let mut hashlife = Backend::HashLife.build(&game).unwrap();
+    hashlife.advance(1 << 40);
+    game.advance(200);
+    assert_eq!(hashlife.live_cells(), Engine::live_cells(&game));
+
+    let mut bounded = GameOfLife::with_topology(Topology::Bounded {
+        width: 100,
+        height: 100,
+    });
+    bounded.set_coord_limit(Some(CoordLimit::new(70)));
+    bounded.stamp(&patterns::glider(), 50, 50, Transform::Identity);
+    bounded.stamp(&patterns::r_pentomino(), 20, 60, Transform::Identity);
+    assert_backends_agree(&bounded, 200);
+}
+
+#[test]
+fn canonical_phases_spanning_the_plane_do_not_overflow() {
+    let mut game = GameOfLife::new();
+    game.set_cells([(i32::MIN, i32::MIN), (i32::MAX, i32::MAX)]);
+    let phase = game.canonical_phase(1);
+    assert_eq!(phase.iter_live().collect::<Vec<_>>(), [(0, 0)]);
+}
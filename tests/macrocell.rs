@@ -0,0 +1,85 @@
+use gameoflife::{Engine, Format, HashLife, Pattern, PatternError, Rule, patterns};
+
+/// A glider in the south-east quadrant of a level-4 root, as Golly would save it.
+const GLIDER_MC: &str = "[M2] (golly 4.2)
+#R B3/S23
+#N Glider
+.*$..*$***$
+4 0 0 0 1
+";
+
+#[test]
+fn parses_golly_files() {
+    let pattern = Pattern::parse(GLIDER_MC, Format::Macrocell).unwrap();
+    assert_eq!(pattern.name(), Some("Glider"));
+    assert_eq!(pattern.rule(), Some(Rule::conway()));
+    assert_eq!(pattern.cells(), &[(0, 2), (1, 0), (1, 2), (2, 1), (2, 2)]);
+}
+
+#[test]
+fn round_trips_patterns() {
+    for pattern in [
+        patterns::glider(),
+        patterns::gosper_glider_gun(),
+        patterns::acorn().with_name("Acorn"),
+        Pattern::new([(-100, 40), (3000, -7)]).with_rule(Rule::highlife()),
+    ] {
+        let text = pattern.write(Format::Macrocell);
+        assert_eq!(Pattern::parse(&text, Format::Macrocell).unwrap(), pattern);
+    }
+}
+
+#[test]
+fn shares_repeated_subtrees() {
+    // Four identical blocks far apart collapse to one leaf referenced four times.
+    let blocks = Pattern::new(
+        [(0, 0), (1000, 0), (0, 1000), (1000, 1000)]
+            .into_iter()
+            .flat_map(|(x, y)| [(x, y), (x + 1, y), (x, y + 1), (x + 1, y + 1)]),
+    );
+    let text = blocks.write(Format::Macrocell);
+    assert_eq!(text.matches("**$**$").count(), 1);
+    assert_eq!(Pattern::parse(&text, Format::Macrocell).unwrap(), blocks);
+}
+
+#[test]
+fn hashlife_keeps_rule_and_generation() {
+    let mut universe = HashLife::from_game(&patterns::gosper_glider_gun().to_game());
+    universe.advance(1000);
+    let restored = HashLife::from_macrocell(&universe.to_macrocell()).unwrap();
+    assert_eq!(restored.generation(), 1000);
+    assert_eq!(restored.rule(), universe.rule());
+    assert_eq!(restored.live_cells(), universe.live_cells());
+}
+
+#[test]
+fn parses_files_with_more_than_u64_max_cells() {
+    // One cell per 8x8 leaf, tiled up to level 40: 2^68 cells before clipping to the plane.
+    let mut text = String::from("[M2]\n*$\n");
+    for level in 4..=40 {
+        let child = level - 3;
+        text.push_str(&format!("{level} {child} {child} {child} {child}\n"));
+    }
+    let universe = HashLife::from_macrocell(&text).unwrap();
+    assert_eq!(universe.population(), 1 << 58);
+}
+
+#[test]
+fn rejects_malformed_files() {
+    assert_eq!(
+        Pattern::parse(".*$\n", Format::Macrocell),
+        Err(PatternError::InvalidHeader { line: 1 })
+    );
+    assert_eq!(
+        HashLife::from_macrocell("[M2]\n#R B03/S23\n").err(),
+        Some(PatternError::InvalidRule { line: 2 })
+    );
+    assert_eq!(
+        Pattern::parse("[M2]\n.*$\n4 0 0 0 2\n", Format::Macrocell),
+        Err(PatternError::InvalidNumber { line: 3 })
+    );
+    assert_eq!(
+        Pattern::parse("[M2]\n.*o$\n", Format::Macrocell),
+        Err(PatternError::UnexpectedCharacter { line: 2, ch: 'o' })
+    );
+}
@@ -0,0 +1,137 @@
+use gameoflife::{Backend, Engine, GameOfLife, Neighborhood, Rule, Transform, patterns};
+use std::collections::BTreeSet;
+
+/// Steps a set of live cells by brute force, counting each cell's neighborhood under `rule_at`.
+fn reference_step(
+    cells: &BTreeSet<(i32, i32)>,
+    rule_at: impl Fn(i32, i32) -> Rule,
+) -> BTreeSet<(i32, i32)> {
+    let radius = Neighborhood::MAX_RADIUS as i32;
+    let candidates: BTreeSet<(i32, i32)> = cells
+        .iter()
+        .flat_map(|&(x, y)| {
+            (-radius..=radius)
+                .flat_map(move |dy| (-radius..=radius).map(move |dx| (x + dx, y + dy)))
+        })
+        .collect();
+    candidates
+        .into_iter()
+        .filter(|&(x, y)| {
+            let rule = rule_at(x, y);
+            let count = rule
+                .neighborhood()
+                .offsets()
+                .filter(|(dx, dy)| cells.contains(&(x + dx, y + dy)))
+                .count();
+            rule.next_state(cells.contains(&(x, y)), count as u8)
+        })
+        .collect()
+}
+
+fn assert_matches_reference(
+    mut game: GameOfLife,
+    generations: u64,
+    rule_at: impl Fn(i32, i32) -> Rule,
+) {
+    let mut cells: BTreeSet<(i32, i32)> = game.iter_live().collect();
+    for generation in 1..=generations {
+        game.tick();
+        cells = reference_step(&cells, &rule_at);
+        assert_eq!(
+            game.iter_live().collect::<BTreeSet<_>>(),
+            cells,
+            "generation {generation}"
+        );
+    }
+}
+
+#[test]
+fn neighborhood_sizes_and_offsets() {
+    assert_eq!(Neighborhood::default(), Neighborhood::moore(1));
+    for (neighborhood, size) in [
+        (Neighborhood::moore(1), 8),
+        (Neighborhood::von_neumann(1), 4),
+        (Neighborhood::moore(2), 24),
+        (Neighborhood::von_neumann(2), 12),
+        (Neighborhood::moore(5), 120),
+    ] {
+        assert_eq!(neighborhood.size(), size);
+        assert_eq!(neighborhood.offsets().count(), usize::from(size));
+    }
+    assert!(Neighborhood::von_neumann(2).contains(1, -1));
+    assert!(!Neighborhood::von_neumann(2).contains(2, 1));
+    assert!(!Neighborhood::moore(3).contains(0, 0));
+}
+
+#[test]
+fn von_neumann_rules_ignore_diagonals() {
+    let mut moore = GameOfLife::with_rule(Rule::new(&[1], &[]));
+    let mut von_neumann =
+        GameOfLife::with_rule(Rule::custom(&[1], &[], 2, Neighborhood::von_neumann(1)));
+    moore.set(0, 0);
+    von_neumann.set(0, 0);
+    assert_eq!(von_neumann.live_neighbors(1, 1), 0);
+    assert_eq!(von_neumann.live_neighbors(0, 1), 1);
+
+    moore.tick();
+    von_neumann.tick();
+    assert_eq!(moore.population(), 8);
+    assert_eq!(
+        Engine::live_cells(&von_neumann),
+        vec![(-1, 0), (0, -1), (0, 1), (1, 0)]
+    );
+}
+
+#[test]
+fn larger_than_life_rules_match_a_brute_force_count() {
+    let rule: Rule = "R2,C0,S6-11,B8-10,NM".parse().unwrap();
+    let mut game = GameOfLife::with_rule(rule);
+    game.stamp(
+        &patterns::soup(24, 24, 0.45, 11),
+        -12,
+        -12,
+        Transform::Identity,
+    );
+    assert_matches_reference(game, 15, |_, _| rule);
+
+    let rule: Rule = "R3,C0,S4-9,B5-7,NN".parse().unwrap();
+    let mut game = GameOfLife::with_rule(rule);
+    game.stamp(&patterns::soup(20, 20, 0.4, 5), 0, 0, Transform::Identity);
+    assert_matches_reference(game, 15, |_, _| rule);
+}
+
+#[test]
+fn regions_can_mix_neighborhoods() {
+    let wide: Rule = "R2,C0,S5-9,B6-8,NN".parse().unwrap();
+    let mut game = GameOfLife::new();
+    game.add_rule_region(0..=100, -100..=100, wide);
+    game.stamp(
+        &patterns::soup(30, 30, 0.5, 2),
+        -15,
+        -15,
+        Transform::Identity,
+    );
+    assert_matches_reference(game, 20, |x, _| if x >= 0 { wide } else { Rule::conway() });
+}
+
+#[test]
+fn bosco_rule_counts_survivors_without_themselves() {
+    // S34..58 with M1 counts the cell itself, so a survivor needs 33 to 57 neighbors.
+    let bosco = Rule::bosco();
+    assert!(bosco.next_state(true, 33) && bosco.next_state(true, 57));
+    assert!(!bosco.next_state(true, 32) && !bosco.next_state(true, 58));
+    assert!(bosco.next_state(false, 34) && !bosco.next_state(false, 33));
+
+    let mut game = GameOfLife::with_rule(Rule::bosco());
+    game.stamp(&patterns::soup(24, 24, 0.5, 9), 0, 0, Transform::Identity);
+    assert_matches_reference(game, 8, |_, _| Rule::bosco());
+}
+
+#[test]
+fn bit_parallel_engines_need_the_moore_neighborhood() {
+    let game = GameOfLife::with_rule("B2/S013V".parse().unwrap());
+    for backend in [Backend::Dense, Backend::HashLife, Backend::Chunked] {
+        assert!(backend.build(&game).is_none(), "{backend:?}");
+    }
+    assert!(Backend::Sparse.build(&game).is_some());
+}
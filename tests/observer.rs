@@ -0,0 +1,62 @@
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+
+use gameoflife::{GameOfLife, SimulationEvent, Statistics, patterns};
+
+#[test]
+fn channels_receive_births_deaths_and_ticks() {
+    let mut game = GameOfLife::new();
+    game.set_cells([(0, 1), (1, 1), (2, 1)]);
+    let (sender, receiver) = mpsc::channel();
+    game.add_observer(sender);
+
+    game.tick();
+    let events: Vec<SimulationEvent> = receiver.try_iter().collect();
+    assert_eq!(
+        events,
+        [
+            SimulationEvent::CellBorn(1, 0),
+            SimulationEvent::CellBorn(1, 2),
+            SimulationEvent::CellDied(0, 1),
+            SimulationEvent::CellDied(2, 1),
+            SimulationEvent::Tick(game.stats()),
+        ]
+    );
+}
+
+#[test]
+fn stabilizing_fires_once_and_skipped_generations_tick_once() {
+    // The pre-block settles into a block after one generation.
+    let mut game = GameOfLife::new();
+    game.set_cells([(0, 0), (1, 0), (0, 1)]);
+    let stabilized = Arc::new(Mutex::new(Vec::new()));
+    let ticks = Arc::new(Mutex::new(Vec::new()));
+    let seen = Arc::clone(&stabilized);
+    game.on_stabilized(move |generation| seen.lock().unwrap().push(generation));
+    let seen = Arc::clone(&ticks);
+    game.on_tick(move |stats: &Statistics| seen.lock().unwrap().push(stats.generation));
+
+    game.advance(1000);
+    assert_eq!(*stabilized.lock().unwrap(), [2]);
+    assert_eq!(*ticks.lock().unwrap(), [1, 2, 1000]);
+}
+
+#[test]
+fn removed_observers_and_clones_stay_quiet() {
+    let mut game = patterns::glider().to_game();
+    let born = Arc::new(Mutex::new(0));
+    let counter = Arc::clone(&born);
+    let id = game.on_cell_born(move |_, _| *counter.lock().unwrap() += 1);
+
+    let mut copy = game.clone();
+    copy.advance(4);
+    assert!(game.detect_cycle(8).is_none());
+    assert_eq!(*born.lock().unwrap(), 0);
+
+    game.tick();
+    assert_eq!(*born.lock().unwrap(), 2);
+    assert!(game.remove_observer(id));
+    assert!(!game.remove_observer(id));
+    game.tick();
+    assert_eq!(*born.lock().unwrap(), 2);
+}
@@ -0,0 +1,185 @@
+use gameoflife::{Format, GameOfLife, Pattern, PatternError, Transform};
+
+const GLIDER_RLE: &str = "#N Glider
+#C A comment line
+x = 3, y = 3, rule = B3/S23
+bob$2bo$3o!
+";
+
+const GOSPER_GUN_RLE: &str = "#N Gosper glider gun
+x = 36, y = 9, rule = B3/S23
+24bo$22bobo$12b2o6b2o12b2o$11bo3bo4b2o12b2o$2o8bo5bo3b2o$2o8bo3bob2o4b
+obo$10bo5bo7bo$11bo3bo$12b2o!
+";
+
+#[test]
+fn rle_parses_header_name_and_cells() {
+    let pattern = Pattern::parse(GLIDER_RLE, Format::Rle).unwrap();
+    assert_eq!(pattern.name(), Some("Glider"));
+    assert_eq!(pattern.rule(), Some(gameoflife::Rule::conway()));
+    assert_eq!(pattern.cells(), &[(0, 2), (1, 0), (1, 2), (2, 1), (2, 2)]);
+}
+
+#[test]
+fn rle_handles_multi_line_runs() {
+    let game = GameOfLife::from_rle(GOSPER_GUN_RLE).unwrap();
+    assert_eq!(Pattern::from_game(&game).cells().len(), 36);
+    assert!(game.get(24, 0));
+    assert!(game.get(0, 4) && game.get(1, 5));
+    assert!(game.get(35, 3));
+}
+
+#[test]
+fn rle_round_trips_boards() {
+    let gun = GameOfLife::from_rle(GOSPER_GUN_RLE).unwrap();
+    assert_eq!(GameOfLife::from_rle(&gun.to_rle()).unwrap(), gun);
+    assert!(gun.to_rle().lines().all(|line| line.len() <= 70));
+
+    let mut offset = GameOfLife::new();
+    offset.set(-5, 12);
+    offset.set(-3, 14);
+    assert_eq!(GameOfLife::from_rle(&offset.to_rle()).unwrap(), offset);
+}
+
+#[test]
+fn rle_reports_malformed_input() {
+    assert_eq!(
+        GameOfLife::from_rle("bo$ob!"),
+        Err(PatternError::InvalidHeader { line: 1 })
+    );
+    assert_eq!(
+        GameOfLife::from_rle("x = 2, y = 1\no?!"),
+        Err(PatternError::UnexpectedCharacter { line: 2, ch: '?' })
+    );
+}
+
+#[test]
+fn rle_rejects_runs_past_the_edge_of_the_plane() {
+    assert_eq!(
+        Pattern::parse("x = 1, y = 1\n2147483647b2147483647bo!", Format::Rle),
+        Err(PatternError::InvalidNumber { line: 2 })
+    );
+    assert_eq!(
+        Pattern::parse("x = 1, y = 1\n2147483647$2147483647$o!", Format::Rle),
+        Err(PatternError::InvalidNumber { line: 2 })
+    );
+    let edge = Pattern::parse("x = 1, y = 1\n2147483647bo!", Format::Rle).unwrap();
+    assert_eq!(edge.cells(), [(i32::MAX, 0)]);
+}
+
+#[test]
+fn rle_rejects_runs_of_too_many_cells() {
+    assert_eq!(
+        Pattern::parse("x = 1, y = 1\n2000000000o!", Format::Rle),
+        Err(PatternError::TooManyCells { line: 2 })
+    );
+}
+
+#[test]
+fn rle_round_trips_boards_spanning_the_whole_plane() {
+    let mut wide = GameOfLife::new();
+    wide.set(i32::MIN, 0);
+    wide.set(i32::MAX, 0);
+    let rle = wide.to_rle();
+    assert!(rle.contains("x = 4294967296, y = 1"));
+    assert_eq!(GameOfLife::from_rle(&rle).unwrap(), wide);
+
+    let mut tall = GameOfLife::new();
+    tall.set(0, i32::MIN);
+    tall.set(0, i32::MAX);
+    assert_eq!(GameOfLife::from_rle(&tall.to_rle()).unwrap(), tall);
+}
+
+#[test]
+fn plaintext_round_trips() {
+    let text = "!Name: Glider\n!\n.O\n..O\nOOO\n";
+    let pattern = Pattern::parse(text, Format::Plaintext).unwrap();
+    assert_eq!(pattern.name(), Some("Glider"));
+    assert_eq!(pattern.cells(), &[(0, 2), (1, 0), (1, 2), (2, 1), (2, 2)]);
+    assert_eq!(
+        pattern.write(Format::Plaintext),
+        "!Name: Glider\n.O\n..O\nOOO\n"
+    );
+}
+
+#[test]
+fn life106_round_trips() {
+    let text = "#Life 1.06\n0 -1\n1 0\n-1 1\n0 1\n1 1\n";
+    let pattern = Pattern::parse(text, Format::Life106).unwrap();
+    assert_eq!(pattern.cells().len(), 5);
+    assert_eq!(
+        Pattern::parse(&pattern.write(Format::Life106), Format::Life106).unwrap(),
+        pattern
+    );
+    assert_eq!(
+        Pattern::parse("0 0\n", Format::Life106),
+        Err(PatternError::InvalidHeader { line: 1 })
+    );
+}
+
+#[test]
+fn formats_describe_the_same_board() {
+    let rle = Pattern::parse(GLIDER_RLE, Format::Rle).unwrap().to_game();
+    let plaintext = Pattern::parse(".O\n..O\nOOO\n", Format::Plaintext)
+        .unwrap()
+        .to_game();
+    assert_eq!(rle, plaintext);
+}
+
+#[test]
+fn formats_are_detected_from_headers() {
+    assert_eq!(Format::detect(GLIDER_RLE), Format::Rle);
+    assert_eq!(Format::detect("x=3,y=3\nbo$2bo$3o!"), Format::Rle);
+    assert_eq!(Format::detect("#Life 1.06\n0 0\n"), Format::Life106);
+    assert_eq!(
+        Format::detect("[M2] (golly 4.2)\n#R B3/S23\n"),
+        Format::Macrocell
+    );
+    assert_eq!(
+        Format::detect("!Name: Glider\n.O.\n..O\nOOO\n"),
+        Format::Plaintext
+    );
+    assert_eq!(Format::detect(""), Format::Plaintext);
+
+    let glider = Pattern::parse(GLIDER_RLE, Format::Rle).unwrap();
+    for format in [
+        Format::Rle,
+        Format::Plaintext,
+        Format::Life106,
+        Format::Macrocell,
+    ] {
+        let text = glider.write(format);
+        assert_eq!(Format::detect(&text), format);
+    }
+}
+
+#[test]
+fn transforms_at_the_edge_of_the_plane_do_not_overflow() {
+    let wide = Pattern::new([(i32::MIN, 0), (i32::MAX, 1)]);
+    assert_eq!(
+        wide.transformed(Transform::FlipHorizontal).cells(),
+        [(i32::MIN, 1), (i32::MAX, 0)]
+    );
+    // Turned upright, the far cell would lie 2^32 - 1 rows down.
+    assert_eq!(
+        wide.transformed(Transform::Rotate90).cells(),
+        [(i32::MIN + 1, 0)]
+    );
+
+    let mut game = GameOfLife::new();
+    let row = Pattern::new([(0, 0), (1, 0), (2, 0)]);
+    game.stamp(&row, i32::MAX - 1, i32::MAX, Transform::Identity);
+    game.stamp(&row, i32::MIN, i32::MIN, Transform::Rotate180);
+    let mut cells: Vec<_> = game.iter_live().collect();
+    cells.sort_unstable();
+    assert_eq!(
+        cells,
+        [
+            (i32::MIN, i32::MIN),
+            (i32::MIN + 1, i32::MIN),
+            (i32::MIN + 2, i32::MIN),
+            (i32::MAX - 1, i32::MAX),
+            (i32::MAX, i32::MAX),
+        ]
+    );
+}
@@ -0,0 +1,90 @@
+use gameoflife::{GameOfLife, Pattern, Transform, patterns};
+
+#[test]
+fn glider_translates_every_four_generations() {
+    let mut game = patterns::glider().to_game();
+    for _ in 0..4 {
+        game.tick();
+    }
+
+    let mut expected = GameOfLife::new();
+    expected.stamp(&patterns::glider(), 1, 1, Transform::Identity);
+    assert_eq!(game, expected);
+}
+
+#[test]
+fn lwss_travels_left() {
+    let mut game = GameOfLife::new();
+    game.stamp(&patterns::lwss(), 10, 0, Transform::Identity);
+    for _ in 0..4 {
+        game.tick();
+    }
+
+    let mut expected = GameOfLife::new();
+    expected.stamp(&patterns::lwss(), 8, 0, Transform::Identity);
+    assert_eq!(game, expected);
+}
+
+#[test]
+fn gosper_gun_emits_a_glider_every_thirty_generations() {
+    let mut game = patterns::gosper_glider_gun().to_game();
+    assert_eq!(game.population(), 36);
+    for _ in 0..30 {
+        game.tick();
+    }
+    assert_eq!(game.population(), 36 + 5);
+}
+
+#[test]
+fn stamp_applies_transform_at_the_anchor() {
+    let mut game = GameOfLife::new();
+    game.stamp(&patterns::glider(), 5, 5, Transform::Rotate180);
+    assert_eq!(
+        Pattern::from_game(&game).cells(),
+        &[(5, 5), (5, 6), (6, 5), (6, 7), (7, 5)]
+    );
+    assert_eq!(game.history_len(), 1);
+}
+
+#[test]
+fn transforms_of_an_asymmetric_pattern_are_distinct() {
+    let r = patterns::r_pentomino();
+    let mut images: Vec<Pattern> = Transform::ALL.iter().map(|&t| r.transformed(t)).collect();
+    images.sort_by(|a, b| a.cells().cmp(b.cells()));
+    images.dedup();
+    assert_eq!(images.len(), 8);
+
+    assert_eq!(
+        r.transformed(Transform::Rotate90)
+            .transformed(Transform::Rotate270),
+        r
+    );
+}
+
+#[test]
+fn methuselahs_have_known_lifespans() {
+    let mut diehard = patterns::diehard().to_game();
+    for _ in 0..129 {
+        diehard.tick();
+    }
+    assert!(diehard.population() > 0);
+    diehard.tick();
+    assert_eq!(diehard.population(), 0);
+}
+
+#[test]
+fn soups_are_reproducible_from_their_seed() {
+    let soup = patterns::soup(64, 48, 0.35, 42);
+    assert_eq!(soup, patterns::soup(64, 48, 0.35, 42));
+    assert_ne!(soup.cells(), patterns::soup(64, 48, 0.35, 43).cells());
+    assert_eq!(GameOfLife::random_soup(64, 48, 0.35, 42), soup.to_game());
+
+    let bounds = soup.bounding_box().unwrap();
+    assert!(bounds.min_x >= 0 && bounds.max_x < 64);
+    assert!(bounds.min_y >= 0 && bounds.max_y < 48);
+    let density = soup.cells().len() as f64 / (64.0 * 48.0);
+    assert!((density - 0.35).abs() < 0.05, "density {density}");
+
+    assert!(patterns::soup(10, 10, 0.0, 1).cells().is_empty());
+    assert_eq!(patterns::soup(10, 10, 1.0, 1).cells().len(), 100);
+}
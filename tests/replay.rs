@@ -0,0 +1,90 @@
+use std::time::Duration;
+
+use gameoflife::{GameOfLife, Recorder, Replay, ReplayError, ReplayEvent, Topology, patterns};
+
+fn record_session() -> (Replay, GameOfLife) {
+    let mut game = GameOfLife::with_topology(Topology::Torus {
+        width: 32,
+        height: 32,
+    });
+    game.set_cells(patterns::glider().cells().iter().copied());
+    let mut recorder = Recorder::new(&game);
+
+    recorder.tick_at(&mut game, Duration::from_millis(100));
+    game.set(20, 20);
+    game.toggle(2, 1);
+    recorder.tick_at(&mut game, Duration::from_millis(200));
+    game.set_rule("B36/S23".parse().unwrap());
+    game.set_cells([(10, 10), (11, 10), (12, 10)]);
+    for at in 3..=10 {
+        recorder.tick_at(&mut game, Duration::from_millis(at * 100));
+    }
+    game.unset(11, 10);
+    (recorder.finish(&game), game)
+}
+
+#[test]
+fn playback_reproduces_the_recorded_session() {
+    let (replay, game) = record_session();
+    assert_eq!(replay.ticks(), 10);
+    assert_eq!(replay.duration(), Duration::from_millis(1000));
+    assert_eq!(replay.initial(), patterns::glider().to_game());
+
+    let mut player = replay.play();
+    assert_eq!(player.next(), Some(Duration::from_millis(100)));
+    assert_eq!(player.game().generation(), 1);
+    let stamps: Vec<Duration> = player.by_ref().collect();
+    assert_eq!(stamps.len(), 9);
+
+    let played = replay.play().finish();
+    assert_eq!(played, game);
+    assert_eq!(played.rule(), game.rule());
+    assert_eq!(played.topology(), game.topology());
+    assert_eq!(played.generation(), 10);
+}
+
+#[test]
+fn replays_round_trip_through_text() {
+    let (replay, _) = record_session();
+    let text = replay.save();
+    assert!(text.starts_with("#Replay 1\nrule B3/S23\ntopology torus 32x32\ncells "));
+    assert!(text.contains("\nr B36/S23\n"));
+    assert_eq!(Replay::load(&text).unwrap(), replay);
+}
+
+#[test]
+fn edits_are_recorded_as_diffs() {
+    let text = "#Replay 1\nrule B3/S23\ntopology infinite\ncells 0,0\ne +1,1 -0,0\nt 5\n";
+    let replay = Replay::load(text).unwrap();
+    let ReplayEvent::Edit(edit) = &replay.events()[0] else {
+        panic!("expected an edit");
+    };
+    assert_eq!(edit.births(), &[(1, 1)]);
+    assert_eq!(edit.deaths(), &[(0, 0)]);
+    assert_eq!(
+        replay.events()[1],
+        ReplayEvent::Tick(Duration::from_millis(5))
+    );
+    assert_eq!(replay.save(), text);
+}
+
+#[test]
+fn malformed_replays_are_rejected() {
+    assert_eq!(Replay::load("rule B3/S23"), Err(ReplayError::MissingHeader));
+    assert_eq!(
+        Replay::load("#Replay 1\nrule B3/Q"),
+        Err(ReplayError::InvalidRule { line: 2 })
+    );
+    assert_eq!(
+        Replay::load("#Replay 1\ncells 1;2"),
+        Err(ReplayError::InvalidNumber { line: 2 })
+    );
+    assert_eq!(
+        Replay::load("#Replay 1\nt 1\nrule B3/S23"),
+        Err(ReplayError::UnexpectedLine { line: 3 })
+    );
+    assert_eq!(
+        Replay::load("#Replay 1\ntopology torus 0x4"),
+        Err(ReplayError::InvalidNumber { line: 2 })
+    );
+}
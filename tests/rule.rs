@@ -0,0 +1,200 @@
+use gameoflife::{Format, GameOfLife, Neighborhood, Pattern, Rule, RuleParseError};
+
+#[test]
+fn parses_rulestring_notations() {
+    assert_eq!("B3/S23".parse(), Ok(Rule::conway()));
+    assert_eq!("b36/s23".parse(), Ok(Rule::highlife()));
+    assert_eq!("B3678S34678".parse(), Ok(Rule::day_and_night()));
+    assert_eq!("S23/B3".parse(), Ok(Rule::conway()));
+    assert_eq!("23/3".parse(), Ok(Rule::conway()));
+    assert_eq!("B2/S".parse(), Ok(Rule::seeds()));
+
+    assert_eq!(
+        "B9/S23".parse::<Rule>(),
+        Err(RuleParseError::InvalidCount('9'))
+    );
+    assert!(matches!(
+        "Life".parse::<Rule>(),
+        Err(RuleParseError::InvalidFormat(_))
+    ));
+}
+
+#[test]
+fn displays_canonical_rulestring() {
+    assert_eq!(Rule::day_and_night().to_string(), "B3678/S34678");
+    assert_eq!(Rule::seeds().to_string(), "B2/S");
+    let round_trip: Rule = Rule::highlife().to_string().parse().unwrap();
+    assert_eq!(round_trip, Rule::highlife());
+}
+
+#[test]
+fn with_rule_changes_births() {
+    // A cell with exactly six live neighbors is born under HighLife but not Conway.
+    let cells = [(0, 0), (1, 0), (2, 0), (0, 2), (1, 2), (2, 2)];
+    let mut conway = GameOfLife::new();
+    let mut highlife = GameOfLife::with_rule(Rule::highlife());
+    for (x, y) in cells {
+        conway.set(x, y);
+        highlife.set(x, y);
+    }
+
+    conway.tick();
+    highlife.tick();
+    assert!(!conway.get(1, 1));
+    assert!(highlife.get(1, 1));
+    assert_eq!(highlife.rule(), Rule::highlife());
+}
+
+#[test]
+fn rle_header_rule_is_applied() {
+    let game = GameOfLife::from_rle("x = 3, y = 1, rule = B36/S23:T10,10\n3o!").unwrap();
+    assert_eq!(game.rule(), Rule::highlife());
+    assert!(game.to_rle().contains("rule = B36/S23"));
+
+    let pattern = Pattern::parse("x = 1, y = 1\no!", Format::Rle).unwrap();
+    assert_eq!(pattern.rule(), None);
+}
+
+#[test]
+fn parses_generations_notations() {
+    assert_eq!("B2/S/C3".parse(), Ok(Rule::brians_brain()));
+    assert_eq!("b2s/c3".parse(), Ok(Rule::brians_brain()));
+    assert_eq!("/2/3".parse(), Ok(Rule::brians_brain()));
+    assert_eq!("345/2/4".parse(), Ok(Rule::star_wars()));
+    assert_eq!("B3/S23/C2".parse(), Ok(Rule::conway()));
+    assert_eq!(Rule::star_wars().to_string(), "B2/S345/C4");
+    assert_eq!(Rule::star_wars().states(), 4);
+    assert_eq!(Rule::conway().states(), 2);
+
+    assert_eq!(
+        "B2/S/C1".parse::<Rule>(),
+        Err(RuleParseError::InvalidStates("1".to_string()))
+    );
+    assert_eq!(
+        "B2/S/Cx".parse::<Rule>(),
+        Err(RuleParseError::InvalidStates("X".to_string()))
+    );
+}
+
+#[test]
+fn generations_cells_decay_before_dying() {
+    // Under Brian's Brain a domino fires a pair of cells on each side, then its own cells spend
+    // one generation refractory.
+    let mut game = GameOfLife::with_rule(Rule::brians_brain());
+    game.set_cells([(0, 0), (1, 0)]);
+
+    game.tick();
+    assert_eq!(game.cell_state(0, 0), 2);
+    assert!(!game.get(0, 0));
+    assert_eq!(game.population(), 4);
+    assert_eq!(game.decaying_population(), 2);
+    assert!(game.get(0, -1) && game.get(1, 1));
+
+    game.tick();
+    assert_eq!(game.cell_state(0, 0), 0);
+    assert_eq!(game.cell_state(0, -1), 2);
+    assert_eq!(game.states_at(2, 1, 0, -1), vec![2, 2]);
+
+    assert!(game.undo());
+    assert_eq!(game.cell_state(0, 0), 2);
+    game.unset(0, 0);
+    assert_eq!(game.cell_state(0, 0), 0);
+}
+
+#[test]
+fn written_cells_replace_decay_under_generations_rules() {
+    let mut game = GameOfLife::with_rule(Rule::brians_brain());
+    game.set_cells([(0, 0), (1, 0)]);
+    game.tick();
+    assert_eq!(game.states_at(2, 1, 0, 0), vec![2, 2]);
+
+    // Writing the row back as read leaves the decaying cells alone; a 1 revives one.
+    game.write_cells_at(2, 1, 0, 0, &[1, 0]);
+    assert_eq!(game.states_at(2, 1, 0, 0), vec![1, 2]);
+    assert_eq!(game.decaying_population(), 1);
+    assert_eq!(game.population(), 5);
+
+    // Killed cells are gone outright rather than left decaying.
+    game.write_cells_at(2, 1, 0, 0, &[0, 0]);
+    assert_eq!(game.states_at(2, 1, 0, 0), vec![0, 2]);
+    assert_eq!(game.population(), 4);
+
+    assert!(game.undo());
+    assert_eq!(game.states_at(2, 1, 0, 0), vec![1, 2]);
+}
+
+#[test]
+fn decaying_cells_block_births_and_render_with_their_own_glyph() {
+    // (1, 1) has exactly two live neighbors next tick but is still refractory.
+    let mut game = GameOfLife::with_rule(Rule::star_wars());
+    game.set_cells([(1, 1)]);
+    game.tick();
+    assert_eq!(game.cell_state(1, 1), 2);
+    game.set_cells([(0, 0), (2, 2)]);
+    assert!(!game.next_state(1, 1));
+    game.tick();
+    assert_eq!(game.cell_state(1, 1), 3);
+
+    let style = gameoflife::RenderStyle::ascii().with_decay(['+', '-']);
+    let mut board = GameOfLife::with_rule(Rule::star_wars());
+    board.set_cells([(0, 0), (1, 0)]);
+    board.tick();
+    let viewport = gameoflife::Viewport::new(0..=1, 0..=0);
+    assert_eq!(viewport.render_styled(&board, &style).to_string(), "++\n");
+    board.tick();
+    assert_eq!(viewport.render_styled(&board, &style).to_string(), "--\n");
+    assert_eq!(viewport.render(&board).to_string(), "◻◻\n");
+}
+
+#[test]
+fn parses_von_neumann_rules() {
+    let rule: Rule = "B2/S013V".parse().unwrap();
+    assert_eq!(
+        rule,
+        Rule::custom(&[2], &[0, 1, 3], 2, Neighborhood::von_neumann(1))
+    );
+    assert_eq!(rule.to_string(), "B2/S013V");
+    assert_eq!("b2/s/c3v".parse::<Rule>().unwrap().states(), 3);
+    assert_eq!(
+        "B5/SV".parse::<Rule>(),
+        Err(RuleParseError::InvalidCount('5'))
+    );
+}
+
+#[test]
+fn parses_larger_than_life_notations() {
+    let bosco: Rule = "R5,C0,M1,S34..58,B34..45,NM".parse().unwrap();
+    assert_eq!(bosco, Rule::bosco());
+    assert_eq!(bosco.neighborhood(), Neighborhood::moore(5));
+    // Counts that included the cell itself are stored, and shown, without it.
+    assert_eq!(bosco.to_string(), "R5,C0,S33-57,B34-45,NM");
+    assert_eq!(bosco.to_string().parse::<Rule>(), Ok(bosco));
+
+    let hrot: Rule = "R2,C3,S2-3,5,B3,NN".parse().unwrap();
+    assert_eq!(
+        hrot,
+        Rule::custom(&[3], &[2, 3, 5], 3, Neighborhood::von_neumann(2))
+    );
+    assert_eq!(hrot.to_string(), "R2,C3,S2-3,5,B3,NN");
+    assert_eq!("r1,c0,s2..3,b3,nm".parse(), Ok(Rule::conway()));
+
+    assert_eq!(
+        "R9,C0,S1,B1,NM".parse::<Rule>(),
+        Err(RuleParseError::InvalidNeighborhood("R9".to_string()))
+    );
+    assert_eq!(
+        "R2,C0,S1,B1,NX".parse::<Rule>(),
+        Err(RuleParseError::InvalidNeighborhood("X".to_string()))
+    );
+    assert_eq!(
+        "R2,C0,S30,B1,NN".parse::<Rule>(),
+        Err(RuleParseError::InvalidRange("30".to_string()))
+    );
+}
+
+#[test]
+fn rle_headers_keep_larger_than_life_rules_whole() {
+    let game = GameOfLife::from_rle("x = 1, y = 1, rule = R2,C0,S2-3,B3,NM\no!").unwrap();
+    assert_eq!(game.rule().to_string(), "R2,C0,S2-3,B3,NM");
+    assert!(game.to_rle().contains("rule = R2,C0,S2-3,B3,NM"));
+}
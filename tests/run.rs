@@ -0,0 +1,70 @@
+use gameoflife::{GameOfLife, RunReport, StopCondition, StopReason, patterns};
+
+#[test]
+fn stops_when_a_methuselah_stabilizes() {
+    // The pre-block becomes a block, which the next generation confirms as stable.
+    let mut game = GameOfLife::new();
+    game.set_cells([(0, 0), (1, 0), (0, 1)]);
+    let report = game.run_until(StopCondition::Stable);
+    assert_eq!(
+        report,
+        RunReport {
+            reason: StopReason::Stable,
+            generations: 2,
+            generation: 2,
+        }
+    );
+    assert_eq!(game.history_len(), 2);
+}
+
+#[test]
+fn reports_extinction_and_generation_limits() {
+    let mut diehard = patterns::diehard().to_game();
+    let report = diehard.run_until(StopCondition::Any(vec![
+        StopCondition::Extinct,
+        StopCondition::GenerationLimit(1000),
+    ]));
+    assert_eq!(report.reason, StopReason::Extinct);
+    assert_eq!(report.generations, 130);
+
+    let mut glider = patterns::glider().to_game();
+    let report = glider.run_until(StopCondition::Any(vec![
+        StopCondition::Periodic(10),
+        StopCondition::GenerationLimit(40),
+    ]));
+    assert_eq!(report.reason, StopReason::GenerationLimit);
+    assert_eq!(glider.generation(), 40);
+
+    let mut empty = GameOfLife::new();
+    assert_eq!(
+        empty.run_until(StopCondition::Extinct).generations,
+        0,
+        "conditions that already hold stop before the first generation"
+    );
+    assert_eq!(empty.history_len(), 0);
+}
+
+#[test]
+fn detects_oscillator_periods() {
+    let mut pulsar = patterns::pulsar().to_game();
+    let report = pulsar.run_until(StopCondition::Periodic(5));
+    assert_eq!(report.reason, StopReason::Periodic { period: 3 });
+    assert_eq!(report.generations, 3);
+
+    let mut blinker = patterns::blinker().to_game();
+    blinker.tick();
+    let report = blinker.run_until(StopCondition::Any(vec![
+        StopCondition::Periodic(1),
+        StopCondition::GenerationLimit(10),
+    ]));
+    assert_eq!(report.reason, StopReason::GenerationLimit);
+}
+
+#[test]
+fn custom_conditions_see_the_board() {
+    let mut game = patterns::r_pentomino().to_game();
+    let report = game.run_until(StopCondition::when(|game| game.population() >= 20));
+    assert_eq!(report.reason, StopReason::Custom);
+    assert!(game.population() >= 20);
+    assert_eq!(report.generation, game.generation());
+}
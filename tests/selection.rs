@@ -0,0 +1,72 @@
+use gameoflife::{GameOfLife, Pattern, Selection, patterns};
+
+#[test]
+fn copy_keeps_offsets_from_the_selection_corner() {
+    let mut game = GameOfLife::new();
+    game.set_cells([(2, 3), (4, 4), (9, 9)]);
+    let selection = Selection::new(1..=5, 2..=5).with_name("Corner");
+
+    let pattern = selection.copy(&game);
+    assert_eq!(pattern.cells(), &[(1, 1), (3, 2)]);
+    assert_eq!(pattern.name(), Some("Corner"));
+    assert_eq!(game.population(), 3);
+}
+
+#[test]
+fn cut_and_paste_move_cells_as_single_undo_steps() {
+    let mut game = GameOfLife::new();
+    game.set_cells([(0, 0), (1, 0), (5, 5)]);
+    game.clear_history();
+    let mut selection = Selection::new(0..=2, 0..=2);
+
+    let clipboard = selection.cut(&mut game);
+    assert_eq!(game.iter_live().collect::<Vec<_>>(), [(5, 5)]);
+    selection.paste(&mut game, &clipboard, 10, -4);
+    let mut live: Vec<_> = game.iter_live().collect();
+    live.sort_unstable();
+    assert_eq!(live, [(5, 5), (10, -4), (11, -4)]);
+    assert_eq!(
+        selection.bounds(),
+        Selection::new(10..=11, -4..=-4).bounds()
+    );
+    assert_eq!(game.history_len(), 2);
+
+    game.undo();
+    game.undo();
+    assert_eq!(game.population(), 3);
+}
+
+#[test]
+fn fill_and_clear_cover_the_whole_rectangle() {
+    let mut game = GameOfLife::new();
+    let selection = Selection::new(-1..=1, 0..=1);
+    selection.fill(&mut game);
+    assert_eq!(game.population(), 6);
+    assert_eq!(game.history_len(), 1);
+
+    game.set_cells([(3, 3)]);
+    selection.clear(&mut game);
+    assert_eq!(game.iter_live().collect::<Vec<_>>(), [(3, 3)]);
+
+    // Pasting an empty pattern changes nothing.
+    let nothing = Selection::new(10..=12, 10..=12).copy(&game);
+    let mut empty = Selection::new(0..=0, 0..=0);
+    empty.paste(&mut game, &nothing, 0, 0);
+    assert_eq!(empty.bounds(), Selection::new(0..=0, 0..=0).bounds());
+    assert_eq!(game.population(), 1);
+
+    let mut glider = Selection::new(0..=0, 0..=0);
+    glider.paste(&mut game, &patterns::glider(), 20, 20);
+    assert_eq!(glider.bounds(), Selection::new(20..=22, 20..=22).bounds());
+}
+
+#[test]
+fn pasting_past_the_edge_of_the_plane_drops_those_cells() {
+    let mut game = GameOfLife::new();
+    let mut selection = Selection::new(0..=0, 0..=0);
+    let row = Pattern::new([(0, 0), (1, 0), (2, 0)]);
+    selection.paste(&mut game, &row, i32::MAX - 1, 0);
+    let mut cells: Vec<_> = game.iter_live().collect();
+    cells.sort_unstable();
+    assert_eq!(cells, [(i32::MAX - 1, 0), (i32::MAX, 0)]);
+}
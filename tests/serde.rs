@@ -0,0 +1,99 @@
+#![cfg(feature = "serde")]
+
+use gameoflife::{Engine, GameOfLife, Pattern, Rule, Topology, Transform, Viewport, patterns};
+
+#[test]
+fn games_round_trip_through_json_with_history() {
+    let mut game = GameOfLife::with_topology(Topology::Torus {
+        width: 12,
+        height: 12,
+    });
+    game.set_rule(Rule::highlife());
+    game.add_rule_region(0..=3, 0..=3, Rule::seeds());
+    game.stamp(&patterns::glider(), 4, 4, Transform::Identity);
+    game.advance(3);
+
+    let json = serde_json::to_string(&game).unwrap();
+    let mut restored: GameOfLife = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(restored, game);
+    assert_eq!(restored.generation(), 3);
+    assert_eq!(restored.rule(), Rule::highlife());
+    assert_eq!(restored.topology(), game.topology());
+    assert_eq!(restored.stats(), game.stats());
+    assert_eq!(restored.history_len(), game.history_len());
+
+    restored.advance(5);
+    game.advance(5);
+    assert_eq!(restored, game);
+
+    restored.undo();
+    game.undo();
+    assert_eq!(restored, game);
+    assert_eq!(restored.generation(), game.generation());
+}
+
+#[test]
+fn cleared_history_is_left_out() {
+    let mut game = patterns::r_pentomino().to_game();
+    game.advance(10);
+    game.clear_history();
+    assert!(!game.can_undo());
+
+    let json = serde_json::to_string(&game).unwrap();
+    assert!(!json.contains("history\":["));
+    let restored: GameOfLife = serde_json::from_str(&json).unwrap();
+    assert_eq!(Engine::live_cells(&restored), Engine::live_cells(&game));
+    assert!(!restored.can_undo());
+}
+
+#[test]
+fn missing_fields_fall_back_to_defaults() {
+    let restored: GameOfLife = serde_json::from_str(r#"{"state":[[0,0],[1,0],[2,0]]}"#).unwrap();
+    assert_eq!(restored.population(), 3);
+    assert_eq!(restored.rule(), Rule::conway());
+    assert_eq!(restored.history_limit(), GameOfLife::new().history_limit());
+}
+
+#[test]
+fn rules_serialize_as_notation() {
+    assert_eq!(
+        serde_json::to_string(&Rule::day_and_night()).unwrap(),
+        r#""B3678/S34678""#
+    );
+    assert_eq!(
+        serde_json::from_str::<Rule>(r#""B36/S23""#).unwrap(),
+        Rule::highlife()
+    );
+    assert!(serde_json::from_str::<Rule>(r#""B9/S2""#).is_err());
+}
+
+#[test]
+fn patterns_and_viewports_round_trip() {
+    let pattern = patterns::pulsar().with_rule(Rule::conway());
+    let json = serde_json::to_string(&pattern).unwrap();
+    assert_eq!(serde_json::from_str::<Pattern>(&json).unwrap(), pattern);
+
+    let game = patterns::blinker().to_game();
+    let viewport = Viewport::new(-2..=4, -1..=3);
+    let restored: Viewport =
+        serde_json::from_str(&serde_json::to_string(&viewport).unwrap()).unwrap();
+    assert_eq!(
+        restored.render(&game).to_string(),
+        viewport.render(&game).to_string()
+    );
+}
+
+#[test]
+fn generations_decay_states_round_trip() {
+    let mut game = GameOfLife::with_rule(Rule::star_wars());
+    game.set_cells([(0, 0), (1, 0)]);
+    game.advance(2);
+    assert!(game.decaying_population() > 0);
+
+    let json = serde_json::to_string(&game).unwrap();
+    assert!(json.contains("B2/S345/C4"));
+    let restored: GameOfLife = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored, game);
+    assert_eq!(restored.cell_state(0, 0), 3);
+}
@@ -0,0 +1,237 @@
+#![cfg(feature = "server")]
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::thread;
+use std::time::Duration;
+
+use gameoflife::server::{Command, CommandError, MAX_CLIENTS, Server};
+use gameoflife::{BoundingBox, Viewport, patterns};
+
+/// Sends `text` as a single masked text frame, as browsers do.
+fn send_text(stream: &mut TcpStream, text: &str) {
+    let mask = [0x12, 0x34, 0x56, 0x78];
+    let mut frame = vec![0x81, 0x80 | text.len() as u8];
+    frame.extend_from_slice(&mask);
+    frame.extend(text.bytes().enumerate().map(|(i, b)| b ^ mask[i % 4]));
+    stream.write_all(&frame).unwrap();
+}
+
+/// Reads one unmasked server frame, returning its opcode and payload.
+fn read_frame(reader: &mut impl Read) -> (u8, Vec<u8>) {
+    let mut header = [0; 2];
+    reader.read_exact(&mut header).unwrap();
+    let len = match header[1] {
+        126 => {
+            let mut len = [0; 2];
+            reader.read_exact(&mut len).unwrap();
+            u16::from_be_bytes(len) as usize
+        }
+        127 => {
+            let mut len = [0; 8];
+            reader.read_exact(&mut len).unwrap();
+            u64::from_be_bytes(len) as usize
+        }
+        len => len as usize,
+    };
+    let mut payload = vec![0; len];
+    reader.read_exact(&mut payload).unwrap();
+    (header[0] & 0x0f, payload)
+}
+
+/// Opens a WebSocket connection, returning the stream and a reader past the handshake response.
+fn connect(addr: SocketAddr) -> (TcpStream, BufReader<TcpStream>, String) {
+    let mut stream = TcpStream::connect(addr).unwrap();
+    stream
+        .write_all(
+            b"GET / HTTP/1.1\r\nHost: localhost\r\nUpgrade: websocket\r\nConnection: Upgrade\r\n\
+              Sec-WebSocket-Key: dGhlIHNhbXBsZSBub25jZQ==\r\nSec-WebSocket-Version: 13\r\n\r\n",
+        )
+        .unwrap();
+    let mut reader = BufReader::new(stream.try_clone().unwrap());
+    let mut response = String::new();
+    while !response.ends_with("\r\n\r\n") {
+        reader.read_line(&mut response).unwrap();
+    }
+    (stream, reader, response)
+}
+
+fn generation(frame: &[u8]) -> u64 {
+    u64::from_le_bytes(frame[..8].try_into().unwrap())
+}
+
+fn population(frame: &[u8]) -> u64 {
+    u64::from_le_bytes(frame[8..16].try_into().unwrap())
+}
+
+#[test]
+fn parses_commands() {
+    assert_eq!("toggle 3 -4".parse(), Ok(Command::Toggle(3, -4)));
+    assert_eq!(" pause ".parse(), Ok(Command::Pause));
+    assert_eq!(
+        "speed 250".parse(),
+        Ok(Command::Speed(Duration::from_millis(250)))
+    );
+    assert_eq!(
+        "view 5 0 -5 10".parse(),
+        Ok(Command::View(BoundingBox {
+            min_x: -5,
+            min_y: 0,
+            max_x: 5,
+            max_y: 10,
+        }))
+    );
+    let Ok(Command::Load(pattern)) = "load\n#N Glider\nx = 3, y = 3\nbo$2bo$3o!".parse() else {
+        panic!("load did not parse");
+    };
+    assert_eq!(pattern, patterns::glider());
+
+    assert_eq!(
+        "jump".parse::<Command>(),
+        Err(CommandError::Unknown("jump".to_string()))
+    );
+    assert_eq!(
+        "toggle 1".parse::<Command>(),
+        Err(CommandError::InvalidArguments)
+    );
+    assert_eq!(
+        "speed -1".parse::<Command>(),
+        Err(CommandError::InvalidArguments)
+    );
+}
+
+#[test]
+fn rejects_views_over_the_cell_budget() {
+    assert_eq!(
+        "view 0 0 60000 60000".parse::<Command>(),
+        Err(CommandError::ViewTooLarge)
+    );
+    assert_eq!(
+        "view -2000000000 0 2000000000 0".parse::<Command>(),
+        Err(CommandError::ViewTooLarge)
+    );
+    assert_eq!(
+        "view -2147483648 -2147483648 2147483647 2147483647".parse::<Command>(),
+        Err(CommandError::ViewTooLarge)
+    );
+    assert!("view 0 0 2047 2047".parse::<Command>().is_ok());
+
+    let game = patterns::glider().to_game();
+    let result = Server::bind("127.0.0.1:0", game, Viewport::new(0..=100_000, 0..=100_000));
+    assert!(result.is_err());
+}
+
+#[test]
+fn streams_frames_and_applies_commands() {
+    let server = Server::bind(
+        "127.0.0.1:0",
+        patterns::blinker().to_game(),
+        Viewport::new(-2..=2, -1..=3),
+    )
+    .unwrap()
+    .paused();
+    let addr = server.local_addr().unwrap();
+    thread::spawn(move || server.run());
+
+    let (mut stream, mut reader, response) = connect(addr);
+    assert!(response.starts_with("HTTP/1.1 101"));
+    assert!(response.contains("Sec-WebSocket-Accept: s3pPLMBiTxaQ9kYGzzhZRbK+xOo=\r\n"));
+
+    let (opcode, frame) = read_frame(&mut reader);
+    assert_eq!(opcode, 2);
+    assert_eq!(population(&frame), 3);
+    assert_eq!(&frame[24..32], &[5, 0, 0, 0, 5, 0, 0, 0]);
+    let column: Vec<u8> = (0..5).map(|row| frame[32 + row * 5 + 2]).collect();
+    assert_eq!(column, [0, 1, 1, 1, 0]);
+
+    send_text(&mut stream, "step");
+    let (_, frame) = read_frame(&mut reader);
+    assert_eq!(generation(&frame), 1);
+    assert_eq!(&frame[32 + 2 * 5..][..5], &[0, 1, 1, 1, 0]);
+
+    send_text(&mut stream, "toggle -2 -1");
+    let (_, frame) = read_frame(&mut reader);
+    assert_eq!(population(&frame), 4);
+    assert_eq!(frame[32], 1);
+
+    send_text(&mut stream, "fly");
+    let (opcode, message) = read_frame(&mut reader);
+    assert_eq!(opcode, 1);
+    assert_eq!(message, b"unknown command \"fly\"");
+}
+
+#[test]
+fn slow_clients_do_not_stall_the_others() {
+    let server = Server::bind(
+        "127.0.0.1:0",
+        patterns::blinker().to_game(),
+        Viewport::new(0..=1023, 0..=1023),
+    )
+    .unwrap()
+    .paused();
+    let addr = server.local_addr().unwrap();
+    thread::spawn(move || server.run());
+
+    // Never reads, so its socket buffers fill after a few multi-megabyte frames.
+    let _stalled = connect(addr);
+    let (mut stream, mut reader, _) = connect(addr);
+    stream
+        .set_read_timeout(Some(Duration::from_secs(30)))
+        .unwrap();
+    read_frame(&mut reader);
+    for expected in 1..=20 {
+        send_text(&mut stream, "step");
+        let (_, frame) = read_frame(&mut reader);
+        assert_eq!(generation(&frame), expected);
+    }
+}
+
+#[test]
+fn refuses_oversized_handshakes() {
+    let server = Server::bind(
+        "127.0.0.1:0",
+        patterns::blinker().to_game(),
+        Viewport::new(-1..=1, -1..=1),
+    )
+    .unwrap()
+    .paused();
+    let addr = server.local_addr().unwrap();
+    thread::spawn(move || server.run());
+
+    // One endless header line, cut off exactly at the limit so the server has read everything sent.
+    let mut stream = TcpStream::connect(addr).unwrap();
+    let mut request = b"GET / HTTP/1.1\r\nX-Padding: ".to_vec();
+    request.resize(16 << 10, b'a');
+    stream.write_all(&request).unwrap();
+    let mut response = String::new();
+    BufReader::new(stream).read_line(&mut response).unwrap();
+    assert!(response.starts_with("HTTP/1.1 431"));
+}
+
+#[test]
+fn refuses_clients_past_the_limit() {
+    let server = Server::bind(
+        "127.0.0.1:0",
+        patterns::blinker().to_game(),
+        Viewport::new(-1..=1, -1..=1),
+    )
+    .unwrap()
+    .paused();
+    let addr = server.local_addr().unwrap();
+    thread::spawn(move || server.run());
+
+    let mut clients: Vec<_> = (0..MAX_CLIENTS).map(|_| connect(addr)).collect();
+    let (_, _, response) = connect(addr);
+    assert!(response.starts_with("HTTP/1.1 503"));
+
+    // A client leaving frees its slot once the server notices the closed connection.
+    clients.pop();
+    let (_, _, response) = (0..100)
+        .map(|_| {
+            thread::sleep(Duration::from_millis(50));
+            connect(addr)
+        })
+        .find(|(_, _, response)| response.starts_with("HTTP/1.1 101"))
+        .expect("a slot frees up after a client leaves");
+    assert!(response.starts_with("HTTP/1.1 101"));
+}
@@ -0,0 +1,82 @@
+use std::time::Duration;
+
+use gameoflife::{GameOfLife, Simulation, patterns};
+
+fn ms(millis: u64) -> Duration {
+    Duration::from_millis(millis)
+}
+
+fn glider_sim() -> Simulation {
+    let mut sim = Simulation::new(patterns::glider().to_game());
+    sim.set_interval(ms(100));
+    sim
+}
+
+#[test]
+fn updates_run_the_generations_that_came_due() {
+    let mut sim = glider_sim();
+    assert_eq!(sim.update(ms(500)), 0);
+    assert_eq!(sim.until_next(), None);
+
+    sim.resume();
+    assert_eq!(sim.update(ms(250)), 2);
+    assert_eq!(sim.until_next(), Some(ms(50)));
+    assert_eq!(sim.update(ms(40)), 0);
+    assert_eq!(sim.update(ms(10)), 1);
+    assert_eq!(sim.game().generation(), 3);
+
+    sim.set_rate(20.0);
+    assert_eq!(sim.interval(), ms(50));
+    assert_eq!(sim.rate(), 20.0);
+}
+
+#[test]
+fn stalls_catch_up_a_bounded_number_of_generations() {
+    let mut sim = glider_sim();
+    sim.resume();
+    assert_eq!(sim.update(ms(10_000)), sim.max_catch_up());
+    assert_eq!(sim.until_next(), Some(ms(100)));
+
+    sim.set_max_catch_up(0);
+    assert_eq!(sim.max_catch_up(), 1);
+    sim.set_interval(Duration::ZERO);
+    assert_eq!(sim.update(Duration::ZERO), 1);
+}
+
+#[test]
+fn pausing_stepping_and_fast_forwarding() {
+    let mut sim = glider_sim();
+    sim.run_for(3);
+    assert!(sim.is_running());
+    assert_eq!(sim.update(ms(250)), 2);
+    assert_eq!(sim.update(ms(250)), 1);
+    assert!(!sim.is_running());
+    assert_eq!(sim.game().generation(), 3);
+
+    sim.toggle();
+    assert!(sim.is_running());
+    sim.step();
+    assert!(!sim.is_running());
+    assert_eq!(sim.game().generation(), 4);
+
+    sim.fast_forward(16);
+    assert_eq!(sim.game().generation(), 20);
+    let mut expected = patterns::glider().to_game();
+    expected.advance(20);
+    assert!(sim.game_mut().undo());
+    assert_eq!(sim.game().generation(), 4);
+    sim.game_mut().redo();
+    assert_eq!(sim.into_game(), expected);
+}
+
+#[test]
+fn paused_time_does_not_count() {
+    let mut sim = Simulation::new(GameOfLife::new());
+    sim.set_interval(ms(100));
+    sim.resume();
+    assert_eq!(sim.update(ms(90)), 0);
+    sim.pause();
+    sim.resume();
+    assert_eq!(sim.update(ms(90)), 0);
+    assert_eq!(sim.update_now(), 0);
+}
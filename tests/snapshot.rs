@@ -0,0 +1,135 @@
+use gameoflife::{CoordLimit, GameOfLife, Rule, SnapshotError, Topology, patterns};
+
+#[test]
+fn snapshots_restore_the_whole_session() {
+    let mut game = patterns::glider().to_game();
+    game.set_topology(Topology::Torus {
+        width: 16,
+        height: 12,
+    });
+    game.set_coord_limit(Some(CoordLimit::new(100)));
+    game.set_history_limit(8);
+    game.add_rule_region(10..=15, 0..=11, Rule::new(&[3, 6], &[2, 3]));
+    game.advance(5);
+    game.tick();
+    game.undo();
+
+    let restored = GameOfLife::from_snapshot(&game.to_snapshot()).unwrap();
+    assert_eq!(restored, game);
+    assert_eq!(restored.generation(), game.generation());
+    assert_eq!(restored.rule(), game.rule());
+    assert_eq!(restored.topology(), game.topology());
+    assert_eq!(restored.coord_limit(), game.coord_limit());
+    assert_eq!(restored.history_limit(), 8);
+    assert_eq!(restored.history().count(), game.history().count());
+    assert_eq!(restored.redo_len(), 1);
+    assert_eq!(restored.to_snapshot(), game.to_snapshot());
+
+    let (mut resumed, mut original) = (restored, game);
+    resumed.advance(20);
+    original.advance(20);
+    assert_eq!(resumed, original);
+}
+
+#[test]
+fn generations_rules_keep_their_decaying_cells() {
+    let mut game = GameOfLife::new();
+    game.set_rule("B2/S/C4".parse().unwrap());
+    game.set_cells([(0, 0), (1, 0)]);
+    game.advance(2);
+    game.clear_history();
+    assert!(game.decaying_population() > 0);
+
+    let restored = GameOfLife::from_snapshot(&game.to_snapshot()).unwrap();
+    assert_eq!(restored, game);
+    assert_eq!(restored.history().count(), 0);
+}
+
+#[test]
+fn sessions_round_trip_through_files() {
+    let path = std::env::temp_dir().join(format!("gol-session-{}.bin", std::process::id()));
+    let mut game = patterns::r_pentomino().to_game();
+    game.advance(30);
+    game.save(&path).unwrap();
+    let restored = GameOfLife::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(restored, game);
+    assert_eq!(restored.generation(), 30);
+
+    assert!(matches!(GameOfLife::load(&path), Err(SnapshotError::Io(_))));
+}
+
+#[test]
+fn malformed_snapshots_are_rejected() {
+    let bytes = patterns::glider().to_game().to_snapshot();
+    assert!(matches!(
+        GameOfLife::from_snapshot(b"#Life 1.06\n0 0\n"),
+        Err(SnapshotError::NotASnapshot)
+    ));
+    let mut newer = bytes.clone();
+    newer[4] = 9;
+    assert!(matches!(
+        GameOfLife::from_snapshot(&newer),
+        Err(SnapshotError::UnsupportedVersion(9))
+    ));
+    assert!(matches!(
+        GameOfLife::from_snapshot(&bytes[..bytes.len() - 3]),
+        Err(SnapshotError::Truncated)
+    ));
+}
+
+#[test]
+fn corrupted_snapshots_are_rejected() {
+    // A glider snapshot starts with the magic, version, `B3/S23` rule, topology, coordinate
+    // limit, color mode, rule region count, and history limit.
+    let bytes = patterns::glider().to_game().to_snapshot();
+    assert_eq!(&bytes[5..12], b"\x06B3/S23");
+    assert_eq!(bytes[12..17], [0, 0, 0, 0, 255]);
+
+    let mut color_mode = bytes.clone();
+    color_mode[14] = 7;
+    assert!(matches!(
+        GameOfLife::from_snapshot(&color_mode),
+        Err(SnapshotError::InvalidColorMode)
+    ));
+
+    let mut bounded = patterns::glider().to_game();
+    bounded.set_topology(Topology::Bounded {
+        width: 16,
+        height: 12,
+    });
+    let mut narrowed = bounded.to_snapshot();
+    assert_eq!(narrowed[12..15], [1, 32, 24]);
+    narrowed[13] = 4;
+    assert!(matches!(
+        GameOfLife::from_snapshot(&narrowed),
+        Err(SnapshotError::InvalidCell)
+    ));
+
+    let mut limited = patterns::glider().to_game();
+    limited.set_coord_limit(Some(CoordLimit::new(5)));
+    limited.tick();
+    let mut shrunk = limited.to_snapshot();
+    assert_eq!(shrunk[12..15], [0, 1, 5]);
+    shrunk[14] = 1;
+    assert!(matches!(
+        GameOfLife::from_snapshot(&shrunk),
+        Err(SnapshotError::InvalidCell)
+    ));
+}
+
+#[test]
+fn restored_history_is_trimmed_to_its_limit() {
+    let mut game = patterns::glider().to_game();
+    for _ in 0..5 {
+        game.tick();
+    }
+    let mut bytes = game.to_snapshot();
+    assert_eq!(bytes[16..18], [255, 1]);
+    bytes.splice(16..18, [2]);
+
+    let restored = GameOfLife::from_snapshot(&bytes).unwrap();
+    assert_eq!(restored.history_limit(), 2);
+    let generations: Vec<u64> = restored.history().map(|entry| entry.generation()).collect();
+    assert_eq!(generations, [4, 3]);
+}
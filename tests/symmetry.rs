@@ -0,0 +1,83 @@
+use gameoflife::{GameOfLife, Pattern, Transform, patterns};
+
+#[test]
+fn translated_copies_are_equivalent_but_not_equal() {
+    let glider = patterns::glider();
+    let moved = Pattern::new(glider.cells().iter().map(|&(x, y)| (x - 40, y + 7)));
+    assert_ne!(glider, moved);
+    assert!(glider.equivalent_up_to_translation(&moved));
+    assert!(moved.equivalent_up_to_translation(&glider));
+    assert!(!glider.equivalent_up_to_translation(&patterns::r_pentomino()));
+    assert!(!glider.equivalent_up_to_translation(&patterns::block()));
+    assert!(Pattern::default().equivalent_up_to_translation(&Pattern::default()));
+}
+
+#[test]
+fn boards_compare_shapes_across_generations() {
+    let start = patterns::glider().to_game();
+    let mut game = start.clone();
+
+    // Two generations on, the glider is its own mirror image; four on, it has only moved.
+    game.advance(2);
+    assert!(!game.equivalent_up_to_translation(&start));
+    assert!(game.equivalent_up_to_symmetry(&start));
+    game.advance(2);
+    assert_ne!(game, start);
+    assert!(game.equivalent_up_to_translation(&start));
+}
+
+#[test]
+fn rotated_and_reflected_copies_are_equivalent_up_to_symmetry() {
+    let r = patterns::r_pentomino();
+    for transform in Transform::ALL {
+        let mut game = GameOfLife::new();
+        game.stamp(&r, -9, 13, transform);
+        assert!(Pattern::from_game(&game).equivalent_up_to_symmetry(&r));
+        assert_eq!(
+            Pattern::from_game(&game).equivalent_up_to_translation(&r),
+            transform == Transform::Identity
+        );
+    }
+    assert!(!r.equivalent_up_to_symmetry(&patterns::glider()));
+}
+
+#[test]
+fn reports_dihedral_symmetries() {
+    assert_eq!(patterns::block().symmetries(), Transform::ALL);
+    assert_eq!(patterns::pulsar().symmetries(), Transform::ALL);
+    assert_eq!(Pattern::default().symmetries(), Transform::ALL);
+    assert_eq!(
+        patterns::blinker().symmetries(),
+        [
+            Transform::Identity,
+            Transform::Rotate180,
+            Transform::FlipHorizontal,
+            Transform::FlipVertical,
+        ]
+    );
+    assert_eq!(patterns::r_pentomino().symmetries(), [Transform::Identity]);
+
+    // The glider reflects itself only by moving, so no phase is symmetric on its own.
+    let mut glider = patterns::glider().to_game();
+    for _ in 0..4 {
+        assert_eq!(glider.symmetries(), [Transform::Identity]);
+        glider.tick();
+    }
+}
+
+#[test]
+fn patterns_spanning_the_plane_compare_without_overflow() {
+    let wide = Pattern::new([(i32::MIN, 0), (i32::MAX, 0)]);
+    assert_eq!(
+        wide.symmetries(),
+        [
+            Transform::Identity,
+            Transform::Rotate180,
+            Transform::FlipHorizontal,
+            Transform::FlipVertical,
+        ]
+    );
+    let tall = Pattern::new([(0, i32::MIN), (0, i32::MAX)]);
+    assert!(wide.equivalent_up_to_symmetry(&tall));
+    assert!(!wide.equivalent_up_to_translation(&tall));
+}
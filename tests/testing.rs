@@ -0,0 +1,87 @@
+#![cfg(feature = "testing")]
+
+use std::panic;
+
+use gameoflife::testing::{
+    BoardGen, assert_golden, assert_golden_render, assert_period, pattern_from_ascii,
+};
+use gameoflife::{Engine, Rule, Topology, Viewport, patterns};
+
+fn golden(name: &str) -> String {
+    format!("{}/tests/golden/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+#[test]
+fn ascii_fixtures_accept_every_live_glyph() {
+    let game = pattern_from_ascii(&[".#.", "O.o", "X.."]);
+    assert_eq!(
+        Engine::live_cells(&game),
+        vec![(0, 1), (0, 2), (1, 0), (2, 1)]
+    );
+}
+
+#[test]
+fn assert_period_checks_the_exact_period() {
+    assert_period(patterns::pulsar().to_game(), 3, "pulsar");
+    let result = panic::catch_unwind(|| assert_period(patterns::blinker().to_game(), 4, "blinker"));
+    assert!(result.is_err());
+}
+
+#[test]
+fn renders_match_golden_files() {
+    let mut game = patterns::glider().to_game();
+    let viewport = Viewport::new(-1..=4, -1..=4);
+    assert_golden_render(&game, &viewport, golden("glider_0.txt"));
+    game.advance(4);
+    assert_golden_render(&game, &viewport, golden("glider_4.txt"));
+}
+
+#[test]
+fn golden_mismatches_name_the_line() {
+    let result = panic::catch_unwind(|| assert_golden(golden("glider_0.txt"), "nope\n"));
+    let message = *result.unwrap_err().downcast::<String>().unwrap();
+    assert!(message.contains("at line 1"), "{message}");
+
+    let result = panic::catch_unwind(|| assert_golden(golden("missing.txt"), ""));
+    let message = *result.unwrap_err().downcast::<String>().unwrap();
+    assert!(message.contains("missing"), "{message}");
+}
+
+#[test]
+fn generated_boards_are_reproducible() {
+    let boards = BoardGen::new(12, 12)
+        .with_density(0.3)
+        .with_rule(Rule::highlife())
+        .with_topology(Topology::Torus {
+            width: 12,
+            height: 12,
+        });
+    assert_eq!(boards.board(5), boards.board(5));
+    assert_ne!(boards.board(5), boards.board(6));
+    assert_eq!(boards.board(5).rule(), Rule::highlife());
+}
+
+#[test]
+fn properties_run_over_many_boards() {
+    let mut runs = 0;
+    BoardGen::new(16, 16).check(20, |mut game| {
+        let population = game.population();
+        game.tick();
+        game.undo();
+        assert_eq!(game.population(), population);
+        runs += 1;
+    });
+    assert_eq!(runs, 20);
+}
+
+#[test]
+fn failing_properties_report_the_seed() {
+    let result = panic::catch_unwind(|| {
+        BoardGen::new(8, 8).check(10, |game| {
+            assert!(game.population() < 30, "too crowded");
+        })
+    });
+    let message = *result.unwrap_err().downcast::<String>().unwrap();
+    assert!(message.contains("board seed"), "{message}");
+    assert!(message.contains("too crowded"), "{message}");
+}
@@ -0,0 +1,103 @@
+use std::ops::RangeInclusive;
+
+use gameoflife::{GameOfLife, Rule, TILE_SIZE, Topology, Transform, patterns};
+
+/// Cells `live_cells_in` should report, found by probing every cell of the view.
+fn probe(game: &GameOfLife, x0: i32, x1: i32, y0: i32, y1: i32) -> Vec<(i32, i32)> {
+    let mut cells: Vec<(i32, i32)> = (x0..=x1)
+        .flat_map(|x| (y0..=y1).map(move |y| (x, y)))
+        .filter(|&(x, y)| game.get(x, y))
+        .collect();
+    cells.sort_unstable();
+    cells
+}
+
+#[test]
+fn live_cells_in_matches_the_dense_buffer() {
+    let mut game = GameOfLife::new();
+    game.stamp(
+        &patterns::soup(40, 40, 0.4, 1),
+        -20,
+        -20,
+        Transform::Identity,
+    );
+    // Views both smaller and larger than the population take different paths.
+    for (x0, x1, y0, y1) in [(-5, 5, -3, 2), (-30, 30, -30, 30), (0, 0, 0, 0)] {
+        assert_eq!(
+            game.live_cells_in(x0..=x1, y0..=y1),
+            probe(&game, x0, x1, y0, y1)
+        );
+    }
+    assert!(
+        game.live_cells_in(RangeInclusive::new(5, 4), 0..=10)
+            .is_empty()
+    );
+}
+
+#[test]
+fn live_cells_in_sees_wrapped_copies_on_a_torus() {
+    let mut game = GameOfLife::with_topology(Topology::Torus {
+        width: 8,
+        height: 6,
+    });
+    game.set_cells([(0, 0), (7, 5), (3, 2)]);
+    assert_eq!(
+        game.live_cells_in(-10..=20, -7..=13),
+        probe(&game, -10, 20, -7, 13)
+    );
+    assert_eq!(game.live_cells_in(8..=8, 6..=6), [(8, 6)]);
+}
+
+#[test]
+fn reports_tiles_changed_by_steps() {
+    let mut game = GameOfLife::new();
+    assert_eq!(game.changed_tiles_since(0), None);
+
+    // A blinker straddling a tile corner, and a block that never changes far away.
+    game.set_cells([(TILE_SIZE - 1, -1), (TILE_SIZE - 1, 0), (TILE_SIZE - 1, 1)]);
+    game.stamp(&patterns::block(), 1000, 1000, Transform::Identity);
+    game.set_tile_tracking(16);
+    assert_eq!(game.changed_tiles_since(0), Some(Vec::new()));
+
+    game.tick();
+    assert_eq!(
+        game.changed_tiles_since(0),
+        Some(vec![(0, -1), (0, 0), (1, 0)])
+    );
+    assert_eq!(game.changed_tiles_since(1), Some(Vec::new()));
+    assert_eq!(game.changed_tiles_since(2), None);
+}
+
+#[test]
+fn edits_and_old_generations_need_a_full_redraw() {
+    let mut game = patterns::glider().to_game();
+    game.set_tile_tracking(3);
+    game.advance(5);
+    // Only the last three steps are kept.
+    assert_eq!(game.changed_tiles_since(1), None);
+    assert_eq!(game.changed_tiles_since(2), Some(vec![(0, 0)]));
+
+    game.set(500, 500);
+    assert_eq!(game.changed_tiles_since(5), None);
+    game.tick();
+    assert_eq!(game.changed_tiles_since(5), None);
+    assert_eq!(game.changed_tiles_since(6), Some(Vec::new()));
+
+    game.undo();
+    assert_eq!(game.changed_tiles_since(game.generation()), None);
+
+    game.set_tile_tracking(0);
+    game.tick();
+    assert_eq!(game.changed_tiles_since(game.generation()), None);
+}
+
+#[test]
+fn decaying_cells_mark_their_tiles() {
+    // A lone cell under Brian's Brain dies into its refractory state, then fades out.
+    let mut game = GameOfLife::with_rule(Rule::brians_brain());
+    game.set(-1, -1);
+    game.set_tile_tracking(8);
+    game.advance(3);
+    assert_eq!(game.changed_tiles_since(1), Some(vec![(-1, -1)]));
+    assert_eq!(game.changed_tiles_since(2), Some(Vec::new()));
+}
@@ -0,0 +1,88 @@
+use gameoflife::{GameOfLife, Topology};
+
+fn glider(game: &mut GameOfLife) {
+    for (x, y) in [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)] {
+        game.set(x, y);
+    }
+}
+
+#[test]
+fn torus_wraps_coordinates_and_neighbors() {
+    let mut game = GameOfLife::with_topology(Topology::Torus {
+        width: 5,
+        height: 5,
+    });
+    game.set(-1, 7);
+    assert!(game.get(4, 2));
+    assert!(game.get(9, -3));
+
+    // A blinker straddling the seam still oscillates.
+    game.clear();
+    for x in [4, 5, 6] {
+        game.set(x, 0);
+    }
+    game.tick();
+    assert!(game.get(0, -1) && game.get(0, 0) && game.get(0, 1));
+    assert!(!game.get(4, 0) && !game.get(1, 0));
+}
+
+#[test]
+fn glider_returns_home_on_a_torus() {
+    let mut game = GameOfLife::with_topology(Topology::Torus {
+        width: 8,
+        height: 8,
+    });
+    glider(&mut game);
+    let start = game.clone();
+
+    // A glider moves one cell diagonally every 4 generations.
+    for _ in 0..4 * 8 {
+        game.tick();
+    }
+    assert_eq!(game, start);
+}
+
+#[test]
+fn bounded_board_has_dead_edges() {
+    let mut game = GameOfLife::with_topology(Topology::Bounded {
+        width: 3,
+        height: 3,
+    });
+    game.set(3, 0);
+    game.set(-1, 1);
+    assert!(!game.get(3, 0));
+    assert!(!game.get(-1, 1));
+
+    // A blinker along the edge cannot grow past it.
+    for y in 0..3 {
+        game.set(0, y);
+    }
+    game.tick();
+    assert!(game.get(0, 1) && game.get(1, 1));
+    assert!(!game.get(-1, 1));
+    assert!(
+        game.tick_candidates()
+            .iter()
+            .all(|&(x, y)| (0..3).contains(&x) && (0..3).contains(&y))
+    );
+}
+
+#[test]
+fn set_topology_normalizes_existing_cells() {
+    let mut game = GameOfLife::new();
+    game.set(12, 3);
+    game.set(1, 1);
+    game.set_topology(Topology::Torus {
+        width: 10,
+        height: 10,
+    });
+    assert!(game.get(2, 3));
+
+    game.set_topology(Topology::Bounded {
+        width: 2,
+        height: 2,
+    });
+    assert_eq!(game.tick_candidates().len(), 4);
+    assert!(game.get(1, 1));
+    assert!(!game.get(2, 3));
+}
@@ -0,0 +1,259 @@
+use gameoflife::{
+    Color, GameOfLife, ImageStyle, Packing, RenderStyle, Topology, Transform, Viewport, patterns,
+};
+
+fn glyphs(viewport: &Viewport, game: &GameOfLife) -> (usize, usize) {
+    let text = viewport.render(game).to_string();
+    let rows: Vec<&str> = text.lines().collect();
+    (rows[0].chars().count(), rows.len())
+}
+
+#[test]
+fn pan_shifts_bounds() {
+    let mut viewport = Viewport::new(0..=4, -2..=2);
+    viewport.pan(3, -1);
+    assert_eq!(viewport.x_range(), 3..=7);
+    assert_eq!(viewport.y_range(), -3..=1);
+}
+
+#[test]
+fn center_on_keeps_size() {
+    let mut viewport = Viewport::new(0..=9, 0..=4);
+    viewport.center_on(100, -50);
+    assert_eq!(viewport.x_range(), 96..=105);
+    assert_eq!(viewport.y_range(), -52..=-48);
+}
+
+#[test]
+fn fit_to_uses_the_bounding_box() {
+    let mut game = GameOfLife::new();
+    game.stamp(&patterns::glider(), -3, 7, Transform::Identity);
+
+    let mut viewport = Viewport::new(0..=1, 0..=1);
+    viewport.fit_to(&game);
+    assert_eq!(viewport.x_range(), -3..=-1);
+    assert_eq!(viewport.y_range(), 7..=9);
+    assert_eq!(
+        viewport
+            .render(&game)
+            .cells()
+            .filter(|&(_, _, alive)| alive)
+            .count(),
+        5
+    );
+
+    viewport.fit_to(&GameOfLife::new());
+    assert_eq!(viewport.x_range(), -3..=-1);
+}
+
+#[test]
+fn zooming_out_renders_blocks_at_the_same_size() {
+    let game = patterns::pulsar().to_game();
+    let mut viewport = Viewport::new(-4..=15, -4..=15);
+    assert_eq!(glyphs(&viewport, &game), (20, 20));
+
+    viewport.zoom(0.5);
+    assert_eq!(viewport.scale(), 2);
+    assert_eq!(viewport.x_range(), -14..=25);
+    assert_eq!(glyphs(&viewport, &game), (20, 20));
+
+    viewport.zoom(2.0);
+    assert_eq!(viewport.scale(), 1);
+    assert_eq!(viewport.x_range(), -4..=15);
+
+    // Past one cell per glyph, zooming in shows fewer cells.
+    viewport.zoom(2.0);
+    assert_eq!(viewport.scale(), 1);
+    assert_eq!(glyphs(&viewport, &game), (10, 10));
+}
+
+#[test]
+fn blocks_are_alive_if_any_cell_is() {
+    let mut game = GameOfLife::new();
+    game.set(1, 0);
+    game.set(2, 3);
+
+    let mut viewport = Viewport::new(0..=3, 0..=3);
+    viewport.set_scale(2);
+    let cells: Vec<(i32, i32, bool)> = viewport.render(&game).cells().collect();
+    assert_eq!(
+        cells,
+        vec![(0, 3, false), (2, 3, true), (0, 1, true), (2, 1, false)]
+    );
+    assert_eq!(viewport.render(&game).to_string(), "◻◼\n◼◻\n");
+}
+
+#[test]
+fn styles_change_glyphs() {
+    let game = patterns::blinker().to_game();
+    let viewport = Viewport::new(0..=1, 1..=2);
+    assert_eq!(
+        viewport
+            .render_styled(&game, &RenderStyle::ascii())
+            .to_string(),
+        "#.\n#.\n"
+    );
+    let style = RenderStyle::new().with_live('@').with_dead(' ');
+    assert_eq!(
+        viewport.render_styled(&game, &style).to_string(),
+        "@ \n@ \n"
+    );
+    assert_eq!(
+        viewport
+            .render_styled(&game, &RenderStyle::default())
+            .to_string(),
+        viewport.render(&game).to_string()
+    );
+}
+
+#[test]
+fn styles_add_ansi_colors() {
+    let game = patterns::blinker().to_game();
+    let viewport = Viewport::new(0..=1, 0..=0);
+    let style = RenderStyle::ascii()
+        .with_live_color(Color::Green)
+        .with_dead_color(Color::Fixed(240));
+    assert_eq!(
+        viewport.render_styled(&game, &style).to_string(),
+        "\x1b[32m#\x1b[0m\x1b[38;5;240m.\x1b[0m\n"
+    );
+
+    let style = RenderStyle::ascii().with_live_color(Color::Rgb(255, 128, 0));
+    assert_eq!(
+        viewport.render_styled(&game, &style).to_string(),
+        "\x1b[38;2;255;128;0m#\x1b[0m.\n"
+    );
+}
+
+#[test]
+fn packed_styles_fit_several_cells_per_character() {
+    let game = patterns::glider().to_game();
+    let viewport = Viewport::new(0..=3, 0..=3);
+    assert_eq!(
+        viewport
+            .render_styled(&game, &RenderStyle::braille())
+            .to_string(),
+        "\u{2892}\u{2806}\n"
+    );
+    assert_eq!(
+        viewport
+            .render_styled(&game, &RenderStyle::half_block())
+            .to_string(),
+        "▄▄▄ \n ▄▀ \n"
+    );
+
+    // Partial blocks at the right and bottom edges are padded with dead cells.
+    let viewport = Viewport::new(0..=2, 0..=2);
+    let style = RenderStyle::new()
+        .with_packing(Packing::Braille)
+        .with_live_color(Color::Green);
+    assert_eq!(style.packing().cell_size(), (2, 4));
+    assert_eq!(
+        viewport.render_styled(&game, &style).to_string(),
+        "\x1b[32m\u{2829}\x1b[0m\x1b[32m\u{2803}\x1b[0m\n"
+    );
+}
+
+#[test]
+fn heat_maps_color_live_cells_by_age() {
+    let mut game = GameOfLife::new();
+    game.set_cells([(0, 0), (1, 0), (0, 1), (1, 1)]);
+    game.tick();
+    game.set(6, 0);
+
+    let style = RenderStyle::ascii()
+        .with_live_color(Color::White)
+        .with_heat_map([Color::Red, Color::Yellow]);
+    let viewport = Viewport::new(1..=6, 0..=0);
+    assert_eq!(
+        viewport.render_styled(&game, &style).to_string(),
+        "\x1b[33m#\x1b[0m....\x1b[31m#\x1b[0m\n"
+    );
+
+    // Blocks take the age of their oldest cell.
+    game.advance(5);
+    let mut viewport = Viewport::new(0..=3, 0..=1);
+    viewport.set_scale(2);
+    let style = RenderStyle::ascii().with_heat_map([Color::Red, Color::Green, Color::Blue]);
+    assert_eq!(
+        viewport.render_styled(&game, &style).to_string(),
+        "\x1b[34m#\x1b[0m.\n"
+    );
+}
+
+#[test]
+fn svg_merges_live_runs_and_draws_the_grid() {
+    let mut game = patterns::blinker().to_game();
+    game.tick();
+    let viewport = Viewport::new(-1..=1, 1..=1);
+    let svg = viewport.render_svg_styled(
+        &game,
+        &ImageStyle::new()
+            .with_cell_size(10)
+            .with_live(Color::Rgb(0x12, 0x34, 0x56))
+            .with_grid(Color::Fixed(244)),
+    );
+    assert!(
+        svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"34\" height=\"12\"")
+    );
+    assert!(svg.contains(
+        "<g fill=\"#123456\">\n<rect x=\"1\" y=\"1\" width=\"32\" height=\"10\"/>\n</g>"
+    ));
+    assert!(svg.contains("stroke=\"#808080\""));
+
+    let plain = viewport.render_svg(&GameOfLife::new());
+    assert!(plain.contains("width=\"12\" height=\"4\""));
+    assert!(!plain.contains("<rect x="));
+    assert!(!plain.contains("<path"));
+}
+
+#[test]
+fn marks_cells_past_a_bounded_edge() {
+    let mut game = GameOfLife::with_topology(Topology::Bounded {
+        width: 3,
+        height: 2,
+    });
+    game.set(0, 0);
+    game.set(2, 1);
+
+    let viewport = Viewport::new(-1..=3, 0..=2);
+    let text = viewport
+        .render_styled(&game, &RenderStyle::ascii())
+        .to_string();
+    assert_eq!(text, "~~~~~\n~..#~\n~#..~\n");
+
+    let mut clamped = viewport.clone();
+    clamped.clamp_to(&game);
+    assert_eq!(clamped.x_range(), 0..=2);
+    assert_eq!(clamped.y_range(), 0..=1);
+    assert_eq!(
+        clamped
+            .render_styled(&game, &RenderStyle::ascii())
+            .to_string(),
+        "..#\n#..\n"
+    );
+}
+
+#[test]
+fn wrapping_tiles_a_torus() {
+    let mut game = GameOfLife::with_topology(Topology::Torus {
+        width: 2,
+        height: 1,
+    });
+    game.set(0, 0);
+
+    let mut viewport = Viewport::new(-2..=3, 0..=0);
+    let style = RenderStyle::ascii().with_off_board(' ');
+    assert_eq!(
+        viewport.render_styled(&game, &style).to_string(),
+        "  #.  \n"
+    );
+    assert_eq!(viewport.render(&game).cells().filter(|c| c.2).count(), 1);
+
+    viewport.set_wrap(true);
+    assert_eq!(
+        viewport.render_styled(&game, &style).to_string(),
+        "#.#.#.\n"
+    );
+    assert_eq!(viewport.render(&game).cells().filter(|c| c.2).count(), 3);
+}
@@ -1,4 +1,4 @@
-import init, { GameOfLife } from "./pkg/gameoflife.js";
+import init, { CellBuffer, GameOfLife } from "./pkg/gameoflife.js";
 
 const CELL_SPACING = 1;
 const MIN_CELL_SIZE = 4;
@@ -12,6 +12,7 @@ const stepButton = document.getElementById("step");
 const clearButton = document.getElementById("clear");
 const randomButton = document.getElementById("random");
 const undoButton = document.getElementById("undo");
+const redoButton = document.getElementById("redo");
 const tickSlider = document.getElementById("tickRate");
 const tickLabel = document.getElementById("tickRateLabel");
 const viewportInfo = document.getElementById("viewportInfo");
@@ -19,6 +20,8 @@ const themeToggle = document.getElementById("themeToggle");
 const THEME_STORAGE_KEY = "gol-theme";
 
 let game;
+let cellBuffer;
+let wasmMemory;
 let tickInterval = Number(tickSlider.value);
 let intervalId = null;
 let currentTheme = "light";
@@ -91,10 +94,12 @@ const updateThemeColors = () => {
 };
 
 const updateUndoState = () => {
-  if (!undoButton) {
-    return;
+  if (undoButton) {
+    undoButton.disabled = !game || !game.can_undo();
+  }
+  if (redoButton) {
+    redoButton.disabled = !game || !game.can_redo();
   }
-  undoButton.disabled = !game || !game.can_undo();
 };
 
 const drawGrid = ({ width, height, pitch }) => {
@@ -118,7 +123,9 @@ const drawGrid = ({ width, height, pitch }) => {
 };
 
 const drawCells = ({ width, height, pitch }) => {
-  const cells = game.cells_at(width, height, viewport.originX, viewport.originY);
+  game.fill_cells_at(cellBuffer, width, height, viewport.originX, viewport.originY);
+  // View the cells in place; the view must be rebuilt each frame in case wasm memory grew.
+  const cells = new Uint8Array(wasmMemory.buffer, cellBuffer.ptr(), cellBuffer.len());
   ctx.beginPath();
 
   for (let y = 0; y < height; y += 1) {
@@ -172,13 +179,12 @@ const stop = () => {
 const randomizeBoard = () => {
   stop();
   const { width, height } = getBoardDimensions();
-  for (let y = 0; y < height; y += 1) {
-    for (let x = 0; x < width; x += 1) {
-      const worldX = viewport.originX + x;
-      const worldY = viewport.originY + y;
-      Math.random() < 0.3 ? game.set(worldX, worldY) : game.unset(worldX, worldY);
-    }
+  const cells = new Uint8Array(width * height);
+  for (let idx = 0; idx < cells.length; idx += 1) {
+    cells[idx] = Math.random() < 0.3 ? 1 : 0;
   }
+  // One bulk write keeps the whole randomization a single undo step.
+  game.write_cells_at(width, height, viewport.originX, viewport.originY, cells);
   render();
 };
 
@@ -498,6 +504,17 @@ const wireEvents = () => {
     });
   }
 
+  if (redoButton) {
+    redoButton.addEventListener("click", () => {
+      stop();
+      if (game && game.redo()) {
+        render();
+      } else {
+        updateUndoState();
+      }
+    });
+  }
+
   if (themeToggle) {
     themeToggle.addEventListener("click", () => {
       const nextTheme = currentTheme === "dark" ? "light" : "dark";
@@ -507,7 +524,9 @@ const wireEvents = () => {
 };
 
 const boot = async () => {
-  await init();
+  const wasm = await init();
+  wasmMemory = wasm.memory;
+  cellBuffer = new CellBuffer();
   game = GameOfLife.new();
   configureCanvas();
   initializeViewport();